@@ -0,0 +1,136 @@
+//! An [`http_body::Body`] serving shared buffers.
+//!
+//! # Example
+//! ```
+//! use http_body::Body;
+//! use pared::{body::ParcBody, sync::Parc};
+//!
+//! let cached: Parc<[u8]> = Parc::from(b"<html></html>".to_vec());
+//! // Every response reuses the cached allocation
+//! let body = ParcBody::new(cached.clone());
+//! assert_eq!(body.size_hint().exact(), Some(13));
+//! ```
+
+use core::{
+    convert::{From, Infallible},
+    option::{Option, Option::None, Option::Some},
+    pin::Pin,
+    result::{Result, Result::Ok},
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+
+use crate::sync::Parc;
+
+/// A [`Body`] consisting of a single data frame with the contents of a [`Parc<[u8]>`].
+///
+/// The data frame is a [`Bytes`] owning the `Parc`, so the body never copies the buffer.
+#[derive(Debug, Clone)]
+pub struct ParcBody {
+    data: Option<Parc<[u8]>>,
+}
+
+impl ParcBody {
+    /// Creates a body with the contents of `data`.
+    #[inline]
+    pub fn new(data: Parc<[u8]>) -> Self {
+        Self { data: Some(data) }
+    }
+
+    /// Creates a body without any data.
+    #[inline]
+    pub fn empty() -> Self {
+        Self { data: None }
+    }
+}
+
+impl From<Parc<[u8]>> for ParcBody {
+    #[inline]
+    fn from(data: Parc<[u8]>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl Body for ParcBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let frame = self
+            .get_mut()
+            .data
+            .take()
+            .filter(|data| !data.is_empty())
+            .map(|data| Ok(Frame::data(Bytes::from(data))));
+        Poll::Ready(frame)
+    }
+
+    #[inline]
+    fn is_end_stream(&self) -> bool {
+        self.data.as_ref().is_none_or(|data| data.is_empty())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        let len = self.data.as_ref().map_or(0, |data| data.len());
+        SizeHint::with_exact(len as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{sync::Arc, task::Wake, vec};
+    use core::task::Waker;
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn poll(body: &mut ParcBody) -> Option<Bytes> {
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        match Pin::new(body).poll_frame(&mut cx) {
+            Poll::Ready(frame) => frame.map(|frame| frame.unwrap().into_data().unwrap()),
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn single_frame() {
+        let cached: Parc<[u8]> = Parc::from(vec![1, 2, 3]);
+        let mut body = ParcBody::from(cached.project(|data| &data[1..]));
+        assert!(!body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(2));
+
+        let frame = poll(&mut body).unwrap();
+        assert_eq!(&frame[..], &[2, 3]);
+        assert_eq!(frame.as_ptr(), cached[1..].as_ptr());
+        assert!(body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(0));
+        assert!(poll(&mut body).is_none());
+
+        drop(frame);
+        assert_eq!(Parc::strong_count(&cached), 1);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn empty() {
+        let mut body = ParcBody::empty();
+        assert!(body.is_end_stream());
+        assert!(poll(&mut body).is_none());
+
+        let mut body = ParcBody::new(Parc::from(vec![]));
+        assert!(body.is_end_stream());
+        assert!(poll(&mut body).is_none());
+    }
+}
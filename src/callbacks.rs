@@ -0,0 +1,256 @@
+//! Registries of shared callbacks.
+
+use alloc::vec::Vec;
+use core::fmt;
+use std::sync::Mutex;
+
+use crate::{
+    poison::lock_unpoisoned,
+    sync::{Parc, Weak},
+};
+
+/// A callback stored in a [`Registry`].
+pub type Callback<Args> = dyn Fn(&Args) + Send + Sync;
+
+/// A set of callbacks that are all called with the same arguments by [`Registry::emit`].
+///
+/// Handlers can be registered either strongly, keeping them alive for as long as they're
+/// registered, or weakly, in which case they're removed automatically once the last `Parc`
+/// pointing to them is dropped.
+/// This makes it easy to tie a subscription to the lifetime of whatever listens to it:
+/// [`Registry::subscribe`] returns the only strong pointer to the handler, and dropping it
+/// unsubscribes.
+///
+/// Handlers may be projected from larger values, e.g. a method on a widget that's
+/// stored along with the widget's state.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use pared::{callbacks::Registry, sync::Parc};
+///
+/// struct Resized {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// let on_resize = Registry::<Resized>::new();
+/// let calls = Parc::new(AtomicUsize::new(0));
+///
+/// let counter = calls.clone();
+/// let subscription = on_resize.subscribe(move |_| {
+///     counter.fetch_add(1, Ordering::Relaxed);
+/// });
+///
+/// on_resize.emit(&Resized { width: 800, height: 600 });
+/// // Dropping the subscription removes the handler
+/// drop(subscription);
+/// on_resize.emit(&Resized { width: 1024, height: 768 });
+///
+/// assert_eq!(calls.load(Ordering::Relaxed), 1);
+/// assert!(on_resize.is_empty());
+/// ```
+pub struct Registry<Args: ?Sized> {
+    handlers: Mutex<Vec<Handler<Args>>>,
+}
+
+enum Handler<Args: ?Sized> {
+    Strong(Parc<Callback<Args>>),
+    Weak(Weak<Callback<Args>>),
+}
+
+impl<Args: ?Sized> Handler<Args> {
+    #[inline]
+    fn get(&self) -> Option<Parc<Callback<Args>>> {
+        match self {
+            Handler::Strong(handler) => Some(handler.clone()),
+            Handler::Weak(handler) => handler.upgrade(),
+        }
+    }
+
+    #[inline]
+    fn is_alive(&self) -> bool {
+        match self {
+            Handler::Strong(_) => true,
+            Handler::Weak(handler) => handler.is_alive(),
+        }
+    }
+}
+
+impl<Args: ?Sized + 'static> Registry<Args> {
+    /// Creates an empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            handlers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers `handler`, keeping it alive until the registry is [cleared](Registry::clear)
+    /// or dropped.
+    #[inline]
+    pub fn register(&self, handler: Parc<Callback<Args>>) {
+        lock_unpoisoned(&self.handlers).push(Handler::Strong(handler));
+    }
+
+    /// Registers `handler` without keeping it alive.
+    ///
+    /// The handler is removed once the last `Parc` pointing to it is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Mutex;
+    /// use pared::{callbacks::{Callback, Registry}, sync::Parc};
+    ///
+    /// struct Log {
+    ///     lines: Mutex<Vec<String>>,
+    /// }
+    ///
+    /// impl Log {
+    ///     fn push(&self, line: &str) {
+    ///         self.lines.lock().unwrap().push(line.to_owned());
+    ///     }
+    /// }
+    ///
+    /// let on_message = Registry::<str>::new();
+    /// let log = Parc::new(Log { lines: Mutex::new(Vec::new()) });
+    ///
+    /// // The handler shares its root with the `Log` it appends to
+    /// let handler: Parc<Callback<str>> = Parc::new(move |line: &str| log.push(line))
+    ///     .project(|handler| handler as &Callback<str>);
+    /// on_message.register_weak(&handler);
+    ///
+    /// assert_eq!(on_message.emit("first"), 1);
+    /// drop(handler);
+    /// assert_eq!(on_message.emit("second"), 0);
+    /// ```
+    #[inline]
+    pub fn register_weak(&self, handler: &Parc<Callback<Args>>) {
+        lock_unpoisoned(&self.handlers).push(Handler::Weak(Parc::downgrade(handler)));
+    }
+
+    /// Registers `handler` weakly, returning the only strong pointer to it.
+    ///
+    /// Dropping the returned `Parc` unsubscribes the handler.
+    pub fn subscribe<F>(&self, handler: F) -> Parc<Callback<Args>>
+    where
+        F: Fn(&Args) + Send + Sync + 'static,
+    {
+        let handler = Parc::new(handler).project(|handler| handler as &Callback<Args>);
+        self.register_weak(&handler);
+        handler
+    }
+
+    /// Calls all live handlers with `args` in the order they were registered,
+    /// and returns the number of called handlers.
+    ///
+    /// Handlers are called without holding the registry's lock, so they're free to register
+    /// or unregister other handlers; such changes take effect from the next `emit`.
+    /// Dead weak handlers are removed along the way.
+    pub fn emit(&self, args: &Args) -> usize {
+        let live: Vec<_> = {
+            let mut handlers = lock_unpoisoned(&self.handlers);
+            handlers.retain(Handler::is_alive);
+            handlers.iter().filter_map(Handler::get).collect()
+        };
+        for handler in &live {
+            handler(args);
+        }
+        live.len()
+    }
+
+    /// Returns the number of registered handlers, including weak handlers that are dead
+    /// but haven't been removed yet.
+    #[inline]
+    pub fn len(&self) -> usize {
+        lock_unpoisoned(&self.handlers).len()
+    }
+
+    /// Returns `true` if there are no live handlers, removing any dead ones.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        let mut handlers = lock_unpoisoned(&self.handlers);
+        handlers.retain(Handler::is_alive);
+        handlers.is_empty()
+    }
+
+    /// Unregisters all handlers.
+    #[inline]
+    pub fn clear(&self) {
+        // Drop the handlers outside of the lock
+        let handlers = core::mem::take(&mut *lock_unpoisoned(&self.handlers));
+        drop(handlers);
+    }
+}
+
+impl<Args: ?Sized + 'static> Default for Registry<Args> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Args: ?Sized> fmt::Debug for Registry<Args> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = lock_unpoisoned(&self.handlers).len();
+        f.debug_struct("Registry")
+            .field("len", &len)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn strong_and_weak_handlers() {
+        let registry = Registry::<usize>::default();
+        let total = Parc::new(AtomicUsize::new(0));
+
+        let sum = total.clone();
+        registry.register(
+            Parc::new(move |x: &usize| {
+                sum.fetch_add(*x, Ordering::Relaxed);
+            })
+            .project(|f| f as &Callback<usize>),
+        );
+        let sum = total.clone();
+        let weak = registry.subscribe(move |x| {
+            sum.fetch_add(*x * 10, Ordering::Relaxed);
+        });
+
+        assert_eq!(registry.emit(&1), 2);
+        assert_eq!(total.load(Ordering::Relaxed), 11);
+
+        drop(weak);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.emit(&1), 1);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(total.load(Ordering::Relaxed), 12);
+        assert_eq!(format!("{:?}", registry), "Registry { len: 1, .. }");
+
+        registry.clear();
+        assert!(registry.is_empty());
+        assert_eq!(Parc::strong_count(&total), 1);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn reentrant_registration() {
+        let registry = Parc::new(Registry::<()>::new());
+        let inner = registry.clone();
+        let nested = Parc::new(Mutex::new(Vec::new()));
+        let subscriptions = nested.clone();
+        let _outer = registry.subscribe(move |_| {
+            subscriptions.lock().unwrap().push(inner.subscribe(|_| {}));
+        });
+
+        assert_eq!(registry.emit(&()), 1);
+        assert_eq!(registry.emit(&()), 2);
+        assert_eq!(nested.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,16 @@
+//! Reference counts of the allocation backing projected pointers.
+
+/// Strong and weak reference counts of the allocation backing a projected pointer.
+///
+/// Returned by [`Parc::counts`], [`Prc::counts`] and the `counts` methods of their
+/// respective `Weak` pointers.
+///
+/// [`Parc::counts`]: crate::sync::Parc::counts
+/// [`Prc::counts`]: crate::prc::Prc::counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Counts {
+    /// The number of strong pointers to the allocation.
+    pub strong: usize,
+    /// The number of weak pointers to the allocation.
+    pub weak: usize,
+}
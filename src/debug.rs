@@ -0,0 +1,171 @@
+//! Tracking which roots keep each other alive, for finding reference cycles.
+//!
+//! With the `debug-cycles` feature, every root `Arc` backing a [`Parc`] is recorded when it's
+//! created. Whenever a `Parc` is projected to a value outside of its own root, e.g. through
+//! a `Parc` stored in its root, and that value lies in another live root, an edge from
+//! the projected root to the other one is recorded: the former holds a pointer into,
+//! and usually keeps alive, the latter.
+//!
+//! [`dump_graph`] writes the recorded roots and edges in the Graphviz DOT format. A root that
+//! should have been freed, but is still part of the graph, is kept alive by its strong count;
+//! a cycle of edges leading back to it is a likely reason.
+//!
+//! Recording takes a global lock on every root construction, projection, and drop of
+//! a pointer that may be the last one to a root, so this feature is only meant for debugging.
+//! The graph is approximate: a root whose last pointers are dropped concurrently, or whose
+//! value is moved out of its `Arc`, may stay in the graph until its address is reused.
+//! `Prc`s aren't tracked.
+//!
+//! [`Parc`]: crate::sync::Parc
+
+use alloc::{collections::BTreeMap, collections::BTreeSet, string::String};
+use core::fmt::Write;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{poison::lock_unpoisoned, sync::erased_arc::TypeErasedArc};
+
+// The reference counts stored in front of the value of an `Arc`; see `rc_allocation_layout`
+const COUNTS_SIZE: usize = core::mem::size_of::<[usize; 2]>();
+
+struct Root {
+    // The end of the root's allocation
+    end: usize,
+    type_name: &'static str,
+    // The roots this one holds pointers into
+    edges: BTreeSet<usize>,
+}
+
+// Live roots by the address of their value
+static ROOTS: Mutex<BTreeMap<usize, Root>> = Mutex::new(BTreeMap::new());
+
+fn roots() -> MutexGuard<'static, BTreeMap<usize, Root>> {
+    lock_unpoisoned(&ROOTS)
+}
+
+/// Records a newly constructed root.
+pub(crate) fn register(arc: &TypeErasedArc) {
+    let size = arc.root_layout().size();
+    // Static data isn't reference counted, so it can't be part of a cycle
+    if size == 0 {
+        return;
+    }
+    let start = arc.as_ptr() as usize;
+    let mut roots = roots();
+    match roots.get(&start) {
+        // Other pointers to an existing root, e.g. from `Parc::from_arc`, are only recorded once
+        Some(root) if root.type_name == arc.type_name() => return,
+        // A stale root that was freed without being unregistered
+        Some(_) => unregister_locked(&mut roots, start),
+        None => {}
+    }
+    roots.insert(
+        start,
+        Root {
+            end: start - COUNTS_SIZE + size,
+            type_name: arc.type_name(),
+            edges: BTreeSet::new(),
+        },
+    );
+}
+
+/// Removes the root of `arc`, whose last strong pointer is about to be dropped.
+pub(crate) fn unregister(arc: &TypeErasedArc) {
+    unregister_locked(&mut roots(), arc.as_ptr() as usize);
+}
+
+fn unregister_locked(roots: &mut BTreeMap<usize, Root>, start: usize) {
+    if roots.remove(&start).is_some() {
+        for root in roots.values_mut() {
+            root.edges.remove(&start);
+        }
+    }
+}
+
+/// Records a projection of a `Parc` backed by `arc` to `target`.
+pub(crate) fn record_projection(arc: &TypeErasedArc, target: *const ()) {
+    let start = arc.as_ptr() as usize;
+    let target = target as usize;
+    let mut roots = roots();
+    match roots.get(&start) {
+        Some(root) if !(start..root.end).contains(&target) => {}
+        _ => return,
+    }
+    let other = match roots.range(..=target).next_back() {
+        Some((&other, root)) if target < root.end => other,
+        _ => return,
+    };
+    if let Some(root) = roots.get_mut(&start) {
+        root.edges.insert(other);
+    }
+}
+
+/// Returns the roots recorded with the `debug-cycles` feature that are still alive,
+/// and the edges between them, in the Graphviz DOT format.
+///
+/// Every root is labeled with the name of its type and its address.
+/// An edge from one root to another means that a `Parc` backed by the first one was projected
+/// into the second one, usually through a pointer stored in the first one.
+///
+/// # Example
+/// ```
+/// use std::sync::OnceLock;
+/// use pared::{debug::dump_graph, sync::Parc};
+///
+/// struct Parent {
+///     child: Parc<Child>,
+/// }
+///
+/// struct Child {
+///     name: String,
+///     parent: OnceLock<Parc<Parent>>,
+/// }
+///
+/// let child = Parc::new(Child { name: "child".to_owned(), parent: OnceLock::new() });
+/// let parent = Parc::new(Parent { child: child.clone() });
+/// let _ = child.parent.set(parent.clone());
+///
+/// // Projecting through the stored pointers records which root points into which
+/// let name = parent.project(|parent| &parent.child.name);
+/// let back = child.project(|child| &**child.parent.get().unwrap());
+/// drop((parent, child, name, back));
+///
+/// // Both roots are still alive, since they keep each other alive
+/// let graph = dump_graph();
+/// assert!(graph.contains("::Parent") && graph.contains("::Child"));
+/// assert_eq!(graph.matches(" -> ").count(), 2);
+/// ```
+pub fn dump_graph() -> String {
+    let mut dot = String::from("digraph pared {\n");
+    for (&address, root) in roots().iter() {
+        let _ = writeln!(
+            dot,
+            "    \"{address:#x}\" [label=\"{}\\n{address:#x}\"];",
+            root.type_name.escape_default(),
+        );
+        for edge in &root.edges {
+            let _ = writeln!(dot, "    \"{address:#x}\" -> \"{edge:#x}\";");
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::Parc;
+    use alloc::format;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn dropped_roots_leave_the_graph() {
+        let inner = Parc::new(5u64);
+        let outer = Parc::new((inner.clone(), 1u8));
+        let address = format!("\"{:#x}\"", Parc::as_ptr(&inner) as usize);
+        let projected = outer.project(|outer| &*outer.0);
+        assert!(dump_graph().contains(&format!(" -> {address};")));
+
+        drop((inner, outer, projected));
+        assert!(!dump_graph().contains(&address));
+    }
+}
@@ -0,0 +1,60 @@
+//! Diagnostic snapshots of projected pointers.
+
+use core::{any::TypeId, mem::size_of};
+
+use crate::Counts;
+
+/// A snapshot of the state of a projected pointer, meant for logs and debug dumps.
+///
+/// Returned by [`Parc::debug_info`] and [`Prc::debug_info`].
+///
+/// [`Parc::debug_info`]: crate::sync::Parc::debug_info
+/// [`Prc::debug_info`]: crate::prc::Prc::debug_info
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DebugInfo {
+    /// The address of the value stored in the root allocation.
+    pub root_addr: usize,
+    /// The address of the projected value.
+    pub projected_addr: usize,
+    /// The offset of the projected value from the start of the root value,
+    /// or `None` if the projected value isn't stored inline in the root value,
+    /// e.g. when it's behind a `Box` owned by the root.
+    pub offset: Option<usize>,
+    /// The estimated size in bytes of the root allocation.
+    pub root_allocation_size: usize,
+    /// The reference counts of the root allocation.
+    pub counts: Counts,
+    /// The type of the root value, if it's known.
+    pub root_type_id: Option<TypeId>,
+    /// The name of the type of the root value.
+    #[cfg(feature = "type-names")]
+    pub root_type_name: &'static str,
+}
+
+impl DebugInfo {
+    pub(crate) fn new(
+        root_addr: usize,
+        projected_addr: usize,
+        root_allocation_size: usize,
+        counts: Counts,
+        root_type_id: Option<TypeId>,
+        #[cfg(feature = "type-names")] root_type_name: &'static str,
+    ) -> Self {
+        // The root value follows the strong and weak counts in its allocation
+        let root_size = root_allocation_size.saturating_sub(size_of::<[usize; 2]>());
+        let offset = projected_addr
+            .checked_sub(root_addr)
+            .filter(|&offset| offset == 0 || offset < root_size);
+        Self {
+            root_addr,
+            projected_addr,
+            offset,
+            root_allocation_size,
+            counts,
+            root_type_id,
+            #[cfg(feature = "type-names")]
+            root_type_name,
+        }
+    }
+}
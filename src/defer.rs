@@ -0,0 +1,167 @@
+//! Deferred dropping of projected pointers.
+//!
+//! Dropping the last pointer to an allocation frees it, which is unacceptable on threads
+//! with real-time constraints (e.g. audio or render threads). [`DropQueue`] lets these threads
+//! hand their [`Parc`]s over to a queue that gets emptied on a thread where freeing is fine.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use pared::{defer::DropQueue, sync::Parc};
+//!
+//! let queue = Arc::new(DropQueue::with_capacity(64));
+//! let samples = Parc::new(vec![0.0f32; 4096]);
+//!
+//! let rt_queue = queue.clone();
+//! std::thread::spawn(move || {
+//!     // The real-time thread never frees the buffer
+//!     Parc::defer_drop(samples, &rt_queue);
+//! })
+//! .join()
+//! .unwrap();
+//!
+//! assert_eq!(queue.collect(), 1);
+//! ```
+
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+use crate::{
+    poison::lock_unpoisoned,
+    sync::{erased_arc::TypeErasedArc, Parc},
+};
+
+/// A queue of [`Parc`]s whose drop is deferred until [`DropQueue::collect`] is called.
+///
+/// Pushing into the queue never frees memory, and doesn't allocate as long as the queue's
+/// capacity isn't exceeded, which can be avoided by creating the queue with
+/// [`DropQueue::with_capacity`] and collecting it regularly.
+///
+/// The queue isn't lock-free: pushing takes a lock, so it may block while another thread
+/// is pushing or while [`DropQueue::collect`] moves the pending `Parc`s out of the queue.
+/// That lock is never held while running destructors.
+///
+/// Any `Parc`s still in the queue are dropped along with it.
+#[derive(Default)]
+pub struct DropQueue {
+    pending: Mutex<Vec<TypeErasedArc>>,
+}
+
+impl DropQueue {
+    /// Creates a new, empty `DropQueue`.
+    ///
+    /// The queue doesn't allocate until the first `Parc` is pushed into it.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Creates a new, empty `DropQueue` with space for at least `capacity` pending `Parc`s.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            pending: Mutex::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Moves `parc` into the queue, deferring the drop of the allocation backing it.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{defer::DropQueue, sync::Parc};
+    ///
+    /// let queue = DropQueue::new();
+    /// queue.push(Parc::new(5));
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[inline]
+    pub fn push<T: ?Sized>(&self, parc: Parc<T>) {
+        lock_unpoisoned(&self.pending).push(Parc::into_erased(parc));
+    }
+
+    /// Returns the number of `Parc`s waiting to be dropped.
+    #[inline]
+    pub fn len(&self) -> usize {
+        lock_unpoisoned(&self.pending).len()
+    }
+
+    /// Returns `true` if there are no `Parc`s waiting to be dropped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        lock_unpoisoned(&self.pending).is_empty()
+    }
+
+    /// Drops all `Parc`s in the queue on the current thread, returning how many were dropped.
+    ///
+    /// The `Parc`s are moved out of the queue before they're dropped, so the queue isn't locked
+    /// while running any destructors, and the queue keeps its capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{defer::DropQueue, sync::Parc};
+    ///
+    /// let queue = DropQueue::new();
+    /// let parc = Parc::new(5);
+    /// let weak = Parc::downgrade(&parc);
+    ///
+    /// queue.push(parc);
+    /// assert!(weak.upgrade().is_some());
+    ///
+    /// assert_eq!(queue.collect(), 1);
+    /// assert!(weak.upgrade().is_none());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn collect(&self) -> usize {
+        let collected: Vec<TypeErasedArc> = lock_unpoisoned(&self.pending).drain(..).collect();
+        collected.len()
+    }
+}
+
+impl core::fmt::Debug for DropQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DropQueue")
+            .field("pending", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "weak"))]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn collects_on_collecting_thread() {
+        let queue = DropQueue::with_capacity(4);
+        let parc = Parc::new((1, String::from("one")));
+        let weak = Parc::downgrade(&parc);
+
+        Parc::defer_drop(parc.project(|x| &x.1), &queue);
+        Parc::defer_drop(parc, &queue);
+        assert_eq!(queue.len(), 2);
+        assert!(weak.upgrade().is_some());
+
+        std::thread::scope(|s| {
+            s.spawn(|| assert_eq!(queue.collect(), 2));
+        });
+        assert!(weak.upgrade().is_none());
+        assert!(queue.is_empty());
+        assert_eq!(queue.collect(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn drops_pending_with_queue() {
+        let queue = DropQueue::default();
+        let parc = Parc::new(5);
+        let weak = Parc::downgrade(&parc);
+        queue.push(parc);
+        assert_eq!(format!("{:?}", queue), "DropQueue { pending: 1 }");
+
+        drop(queue);
+        assert!(weak.upgrade().is_none());
+    }
+}
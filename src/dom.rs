@@ -0,0 +1,184 @@
+//! Path projections into shared document trees, such as parsed JSON.
+//!
+//! Any document type implementing [`Index`] gets [`Parc::project_path`] and related methods.
+//! The crate implements it for [`serde_json::Value`] with the `json` feature, and for
+//! [`simd_json::OwnedValue`] with the `simd-json` feature.
+//!
+//! [`Parc::project_path`]: crate::sync::Parc::project_path
+//! [`serde_json::Value`]: https://docs.rs/serde_json/latest/serde_json/enum.Value.html
+//! [`simd_json::OwnedValue`]: https://docs.rs/simd-json/latest/simd_json/value/owned/enum.Value.html
+
+/// A node of a document tree that can be indexed by object keys and array positions.
+///
+/// # Example
+/// ```
+/// use pared::{dom::Index, sync::Parc};
+///
+/// enum Node {
+///     Text(String),
+///     List(Vec<Node>),
+/// }
+///
+/// impl Index for Node {
+///     fn get_key(&self, _key: &str) -> Option<&Self> {
+///         None
+///     }
+///
+///     fn get_index(&self, index: usize) -> Option<&Self> {
+///         self.as_slice()?.get(index)
+///     }
+///
+///     fn as_str(&self) -> Option<&str> {
+///         match self {
+///             Node::Text(text) => Some(text),
+///             Node::List(_) => None,
+///         }
+///     }
+///
+///     fn as_slice(&self) -> Option<&[Self]> {
+///         match self {
+///             Node::Text(_) => None,
+///             Node::List(nodes) => Some(nodes),
+///         }
+///     }
+/// }
+///
+/// let doc = Parc::new(Node::List(vec![Node::Text("first".to_owned())]));
+/// assert_eq!(&*doc.project_str("[0]").unwrap(), "first");
+/// ```
+pub trait Index: Sized {
+    /// Returns the value stored under `key`, if this is an object containing it.
+    fn get_key(&self, key: &str) -> Option<&Self>;
+
+    /// Returns the value at `index`, if this is an array containing it.
+    fn get_index(&self, index: usize) -> Option<&Self>;
+
+    /// Returns the contents of this value, if it's a string.
+    fn as_str(&self) -> Option<&str>;
+
+    /// Returns the elements of this value, if it's an array.
+    fn as_slice(&self) -> Option<&[Self]>;
+}
+
+/// Looks up the value at `path` in `value`.
+///
+/// `path` consists of object keys separated by `.`, each optionally followed by
+/// any number of `[index]` array accesses.
+pub(crate) fn lookup<'v, D: Index>(mut value: &'v D, path: &str) -> Option<&'v D> {
+    if path.is_empty() {
+        return Some(value);
+    }
+    for segment in path.split('.') {
+        let (key, mut indices) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+        if !key.is_empty() {
+            value = value.get_key(key)?;
+        } else if indices.is_empty() {
+            // empty segments such as in `a..b` aren't valid
+            return None;
+        }
+        while !indices.is_empty() {
+            let (index, rest) = indices.strip_prefix('[')?.split_once(']')?;
+            value = value.get_index(index.parse::<usize>().ok()?)?;
+            indices = rest;
+        }
+    }
+    Some(value)
+}
+
+#[cfg(feature = "json")]
+impl Index for serde_json::Value {
+    #[inline]
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        self.as_object()?.get(key)
+    }
+
+    #[inline]
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        self.as_array()?.get(index)
+    }
+
+    #[inline]
+    fn as_str(&self) -> Option<&str> {
+        serde_json::Value::as_str(self)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> Option<&[Self]> {
+        self.as_array().map(alloc::vec::Vec::as_slice)
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl Index for simd_json::OwnedValue {
+    #[inline]
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            simd_json::OwnedValue::Object(object) => object.get(key),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn get_index(&self, index: usize) -> Option<&Self> {
+        Index::as_slice(self)?.get(index)
+    }
+
+    #[inline]
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            simd_json::OwnedValue::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> Option<&[Self]> {
+        match self {
+            simd_json::OwnedValue::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "json")]
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn lookup_paths() {
+        use super::*;
+        use serde_json::json;
+
+        let doc = json!({ "a": { "b": [1, [2, 3], { "c": "d" }] }, "": 5 });
+        assert_eq!(lookup(&doc, ""), Some(&doc));
+        assert_eq!(lookup(&doc, "a.b[0]"), Some(&json!(1)));
+        assert_eq!(lookup(&doc, "a.b[1][1]"), Some(&json!(3)));
+        assert_eq!(lookup(&doc, "a.b[2].c"), Some(&json!("d")));
+        assert_eq!(lookup(&json!([[1]]), "[0][0]"), Some(&json!(1)));
+
+        for invalid in [
+            "x", "a.", "a..b", "a.b[", "a.b[3]", "a.b[x]", "a.b]", "a[0]", "a.b.c",
+        ] {
+            assert_eq!(lookup(&doc, invalid), None, "{invalid}");
+        }
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn simd_json_lookup_paths() {
+        use super::*;
+
+        let mut text = br#"{ "a": { "b": [1, "two"] } }"#.to_vec();
+        let doc: simd_json::OwnedValue = simd_json::to_owned_value(&mut text).unwrap();
+        assert_eq!(lookup(&doc, "a.b[1]").and_then(Index::as_str), Some("two"));
+        assert_eq!(
+            lookup(&doc, "a.b")
+                .and_then(Index::as_slice)
+                .map(<[_]>::len),
+            Some(2)
+        );
+        assert!(lookup(&doc, "a.b[2]").is_none());
+        assert!(lookup(&doc, "a[0]").is_none());
+    }
+}
@@ -22,24 +22,58 @@ impl TypeErasedPtr {
     /// Type-erase a possibly-unsized pointer,
     /// only preserving the bit-representation of its pointer.
     #[inline]
-    pub(crate) fn new<T: ?Sized>(ptr: *const T) -> Self {
-        let mut res = Self(MaybeUninit::zeroed());
+    pub(crate) const fn new<T: ?Sized>(ptr: *const T) -> Self {
+        // Overlays the pointer onto our representation, which also works in `const` contexts
+        union Erase<T: ?Sized> {
+            ptr: *const T,
+            erased: MaybeUninit<[*const (); 2]>,
+        }
+
+        assert!(size_of::<*const T>() <= size_of::<[*const (); 2]>());
 
-        let len = size_of::<*const T>();
+        let mut erase = Erase {
+            erased: MaybeUninit::zeroed(),
+        };
+        erase.ptr = ptr;
+        // SAFETY: `erased` is a `MaybeUninit`, which is valid for any bytes,
+        // and we asserted that our pointer fits into this representation.
+        Self(unsafe { erase.erased })
+    }
 
-        assert!(len <= size_of::<[*const (); 2]>());
+    /// Type-erase a pointer to a sized value together with an `extra` value,
+    /// which is stored in place of the pointer's (nonexistent) metadata.
+    ///
+    /// `extra` must fit into a single pointer; this is checked at compile time.
+    #[cfg(feature = "allocator-api")]
+    #[inline]
+    pub(crate) fn with_extra<T, E: Copy>(ptr: *const T, extra: E) -> Self {
+        const {
+            assert!(size_of::<E>() <= size_of::<*const ()>());
+            assert!(core::mem::align_of::<E>() <= core::mem::align_of::<*const ()>());
+        }
+        let mut res = Self::new(ptr);
 
-        // SAFETY: The target is valid for at least `len` bytes, and has no
-        // requirements on the value.
-        // We asserted that our pointer fits into this representation.
+        // SAFETY: the second pointer is unused by sized pointers,
+        // and we asserted that `E` fits into it.
         unsafe {
-            let ptr_val = (&ptr) as *const *const T as *const u8;
-            let target = res.0.as_mut_ptr() as *mut u8;
-            core::ptr::copy_nonoverlapping(ptr_val, target, len);
+            let words = res.0.as_mut_ptr() as *mut *const ();
+            (words.add(1) as *mut E).write(extra);
         }
         res
     }
 
+    /// Obtain the extra value stored with [`TypeErasedPtr::with_extra`].
+    ///
+    /// # Safety
+    /// This can only be called with `Self` that has been created by `with_extra` with the exact
+    /// same `E`.
+    #[cfg(feature = "allocator-api")]
+    #[inline]
+    pub(crate) unsafe fn extra<E: Copy>(self) -> E {
+        let words = self.0.as_ptr() as *const *const ();
+        (words.add(1) as *const E).read()
+    }
+
     /// Obtain the original pointer from the type-erased representation.
     ///
     /// # Safety
@@ -81,7 +115,7 @@ mod tests {
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn dyn_ptr() {
         // We want to check that the pointers actually ARE compatible
-        #![allow(clippy::vtable_address_comparisons)]
+        #![allow(ambiguous_wide_pointer_comparisons)]
 
         let debug: &dyn core::fmt::Debug = &"Hello!";
         let ptr = TypeErasedPtr::new(debug as *const dyn core::fmt::Debug);
@@ -91,6 +125,17 @@ mod tests {
         assert_eq!(format!("{:?}", r), "\"Hello!\"");
     }
 
+    #[test]
+    #[cfg(feature = "allocator-api")]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn with_extra() {
+        let value = 5u64;
+        let ptr = TypeErasedPtr::with_extra(&value, 7u32);
+
+        assert_eq!(unsafe { ptr.as_ptr::<u64>() }, &value as *const u64);
+        assert_eq!(unsafe { ptr.extra::<u32>() }, 7);
+    }
+
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn clone() {
@@ -103,6 +148,6 @@ mod tests {
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn debug() {
         let ptr = TypeErasedPtr::new(&1);
-        format!("{:?}", ptr);
+        let _ = format!("{:?}", ptr);
     }
 }
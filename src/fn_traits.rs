@@ -0,0 +1,114 @@
+//! `Fn` trait implementations forwarding calls to projected callables.
+
+use core::marker::{Sized, Tuple};
+
+use crate::prc::Prc;
+#[cfg(feature = "sync")]
+use crate::sync::Parc;
+
+#[cfg(feature = "sync")]
+impl<Args: Tuple, F: ?Sized + Fn<Args>> FnOnce<Args> for Parc<F> {
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> F::Output {
+        (*self).call(args)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<Args: Tuple, F: ?Sized + Fn<Args>> FnMut<Args> for Parc<F> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> F::Output {
+        (**self).call(args)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<Args: Tuple, F: ?Sized + Fn<Args>> Fn<Args> for Parc<F> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> F::Output {
+        (**self).call(args)
+    }
+}
+
+impl<Args: Tuple, F: ?Sized + Fn<Args>> FnOnce<Args> for Prc<F> {
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> F::Output {
+        (*self).call(args)
+    }
+}
+
+impl<Args: Tuple, F: ?Sized + Fn<Args>> FnMut<Args> for Prc<F> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> F::Output {
+        (**self).call(args)
+    }
+}
+
+impl<Args: Tuple, F: ?Sized + Fn<Args>> Fn<Args> for Prc<F> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> F::Output {
+        (**self).call(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::String, vec::Vec};
+    use core::cell::Cell;
+
+    fn apply_all<F: Fn(u32) -> u32>(callbacks: &[F], value: u32) -> Vec<u32> {
+        callbacks.iter().map(|f| f(value)).collect()
+    }
+
+    fn call_twice<F: FnMut(u32) -> u32>(mut f: F) -> u32 {
+        f(1) + f(2)
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn parcs_are_callable() {
+        struct Handlers {
+            double: fn(u32) -> u32,
+            offset: u32,
+        }
+        let handlers = Parc::new(Handlers {
+            double: |x| x * 2,
+            offset: 3,
+        });
+        let double = handlers.project(|h| &h.double);
+        assert_eq!(double(4), 8);
+
+        let offset = handlers.offset;
+        let add: Parc<dyn Fn(u32) -> u32 + Send + Sync> =
+            Parc::new(move |x| x + offset).project(|f| f as &(dyn Fn(u32) -> u32 + Send + Sync));
+        assert_eq!(apply_all(&[add.clone(), add], 1), [4, 4]);
+
+        assert_eq!(call_twice(double.clone()), 6);
+        let deferred = move || double(5);
+        assert_eq!(deferred(), 10);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn prcs_are_callable() {
+        let calls = Prc::new(Cell::new(0));
+        let counter = calls.clone();
+        let greet: Prc<dyn Fn(&str) -> String> = Prc::new(move |name: &str| {
+            counter.set(counter.get() + 1);
+            alloc::format!("hello, {name}")
+        })
+        .project(|f| f as &dyn Fn(&str) -> String);
+
+        assert_eq!(greet("prc"), "hello, prc");
+        assert_eq!(apply_all(&[Prc::new(|x| x + 1)], 1), [2]);
+        let once = greet.clone();
+        assert_eq!(["once"].map(once), ["hello, once"]);
+        assert_eq!(calls.get(), 2);
+    }
+}
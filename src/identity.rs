@@ -0,0 +1,182 @@
+//! Identity-based comparisons for projected pointers.
+
+use core::{
+    cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
+    convert::From,
+    hash::{Hash, Hasher},
+    marker::Sized,
+    option::{Option, Option::Some},
+};
+
+use crate::prc::Prc;
+#[cfg(feature = "sync")]
+use crate::sync::Parc;
+
+/// A wrapper that compares, orders and hashes projected pointers by the allocation
+/// they keep alive instead of by the values they point to.
+///
+/// It's implemented for both [`Parc`] and [`Prc`].
+///
+/// Two wrapped pointers are equal if they share the same root allocation, even if they point
+/// to different parts of it. None of the operations read the pointed-to data, which makes
+/// `ByIdentity` suitable for keying caches by "which shared object is this".
+///
+/// # Example
+#[cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#[cfg_attr(feature = "sync", doc = "```")]
+/// use std::collections::HashMap;
+/// use pared::{sync::Parc, ByIdentity};
+///
+/// struct Node {
+///     name: String,
+///     weight: u32,
+/// }
+///
+/// let node = Parc::new(Node { name: "root".to_owned(), weight: 2 });
+/// let heavy = Parc::new(Node { name: "root".to_owned(), weight: 1000 });
+///
+/// let mut meta = HashMap::new();
+/// meta.insert(ByIdentity(node.clone()), "light");
+/// meta.insert(ByIdentity(heavy), "heavy");
+///
+/// // Lookups only compare the allocation, not the `Node`s themselves
+/// assert_eq!(meta.get(&ByIdentity(node)), Some(&"light"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByIdentity<P>(pub P);
+
+impl<P> ByIdentity<P> {
+    /// Unwraps the stored pointer.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P> From<P> for ByIdentity<P> {
+    #[inline]
+    fn from(value: P) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized> PartialEq for ByIdentity<Parc<T>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Parc::same_root(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized> Eq for ByIdentity<Parc<T>> {}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized> PartialOrd for ByIdentity<Parc<T>> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized> Ord for ByIdentity<Parc<T>> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Parc::root_ptr(&self.0).cmp(&Parc::root_ptr(&other.0))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized> Hash for ByIdentity<Parc<T>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Parc::root_ptr(&self.0).hash(state)
+    }
+}
+
+impl<T: ?Sized> PartialEq for ByIdentity<Prc<T>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Prc::same_root(&self.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> Eq for ByIdentity<Prc<T>> {}
+
+impl<T: ?Sized> PartialOrd for ByIdentity<Prc<T>> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for ByIdentity<Prc<T>> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Prc::root_ptr(&self.0).cmp(&Prc::root_ptr(&other.0))
+    }
+}
+
+impl<T: ?Sized> Hash for ByIdentity<Prc<T>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Prc::root_ptr(&self.0).hash(state)
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use alloc::{collections::BTreeSet, string::String};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn equal_by_root() {
+        let tuple = Parc::new((1u8, String::from("one")));
+        let first = ByIdentity(tuple.project(|t| &t.0));
+        let second = ByIdentity(tuple.project(|t| &t.0));
+        let other = ByIdentity(Parc::new((1u8, String::from("one"))).project(|t| &t.0));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(first.cmp(&second), Ordering::Equal);
+        assert_ne!(first.cmp(&other), Ordering::Equal);
+        assert_eq!(first.partial_cmp(&other), Some(first.cmp(&other)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn ordered_collection() {
+        let a = Parc::new(1);
+        let b = Parc::new(1);
+
+        let mut set = BTreeSet::new();
+        assert!(set.insert(ByIdentity::from(a.clone())));
+        assert!(set.insert(ByIdentity::from(b)));
+        assert!(!set.insert(ByIdentity::from(a.project(|x| x))));
+        assert_eq!(set.len(), 2);
+
+        let a = set.into_iter().next().unwrap().into_inner();
+        assert_eq!(*a, 1);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn prc() {
+        let tuple = Prc::new((1u8, String::from("one")));
+        let first = ByIdentity(tuple.project(|t| &t.0));
+        let second = ByIdentity(tuple.project(|t| &t.0));
+        let other = ByIdentity(Prc::new((1u8, String::from("one"))).project(|t| &t.0));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(first.partial_cmp(&other), Some(first.cmp(&other)));
+
+        let mut set = BTreeSet::new();
+        assert!(set.insert(first));
+        assert!(!set.insert(second));
+        assert!(set.insert(other));
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,178 @@
+//! String interning handing out projected pointers.
+
+use alloc::boxed::Box;
+use core::{cell::RefCell, fmt};
+use std::collections::HashMap;
+
+use crate::prc::{Prc, Weak};
+
+// Don't bother purging dead entries before the map has at least this many entries
+const MIN_PURGE_LEN: usize = 32;
+
+/// A single-threaded string interner handing out [`Prc<str>`]s.
+///
+/// Interning the same string twice returns `Prc`s pointing to the same allocation for as long as
+/// any of them is alive. The interner only holds [`Weak`] pointers to the strings it hands out,
+/// so strings are freed as soon as they're no longer used; the entries of freed strings are
+/// evicted the next time the same string is interned, or when enough of them accumulate.
+///
+/// The interner doesn't use any atomics or locks, which makes it a good fit for a
+/// `thread_local!`.
+///
+/// # Example
+/// ```
+/// use pared::{intern::LocalInterner, prc::Prc};
+///
+/// thread_local! {
+///     static SYMBOLS: LocalInterner = LocalInterner::new();
+/// }
+///
+/// let first = SYMBOLS.with(|symbols| symbols.intern("print"));
+/// let second = SYMBOLS.with(|symbols| symbols.intern(&String::from("print")));
+/// assert!(Prc::ptr_eq(&first, &second));
+///
+/// drop(first);
+/// drop(second);
+/// assert!(SYMBOLS.with(|symbols| symbols.get("print")).is_none());
+/// ```
+#[derive(Default)]
+pub struct LocalInterner {
+    inner: RefCell<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    strings: HashMap<Box<str>, Weak<str>>,
+    purge_at: usize,
+}
+
+impl Entries {
+    fn purge(&mut self) -> usize {
+        let before = self.strings.len();
+        self.strings.retain(|_, weak| weak.is_alive());
+        self.purge_at = MIN_PURGE_LEN.max(self.strings.len() * 2);
+        before - self.strings.len()
+    }
+}
+
+impl LocalInterner {
+    /// Creates an empty interner.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a `Prc` pointing to `string`, reusing a previously interned allocation
+    /// if one is still alive.
+    pub fn intern(&self, string: &str) -> Prc<str> {
+        let mut entries = self.inner.borrow_mut();
+        if let Some(prc) = entries.strings.get(string).and_then(Weak::upgrade) {
+            return prc;
+        }
+        if entries.strings.len() >= entries.purge_at {
+            entries.purge();
+        }
+
+        let prc = Prc::from(string);
+        entries.strings.insert(string.into(), Prc::downgrade(&prc));
+        prc
+    }
+
+    /// Returns a `Prc` pointing to `string` if it's currently interned.
+    #[inline]
+    pub fn get(&self, string: &str) -> Option<Prc<str>> {
+        self.inner.borrow().strings.get(string)?.upgrade()
+    }
+
+    /// Returns the number of entries in the interner.
+    ///
+    /// This includes the entries of strings that have already been freed,
+    /// but haven't been evicted yet; see [`LocalInterner::purge`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().strings.len()
+    }
+
+    /// Returns `true` if the interner has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evicts the entries of all strings that have already been freed,
+    /// returning the number of evicted entries.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::intern::LocalInterner;
+    ///
+    /// let interner = LocalInterner::new();
+    /// let kept = interner.intern("kept");
+    /// drop(interner.intern("freed"));
+    ///
+    /// assert_eq!(interner.len(), 2);
+    /// assert_eq!(interner.purge(), 1);
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    #[inline]
+    pub fn purge(&self) -> usize {
+        self.inner.borrow_mut().purge()
+    }
+}
+
+impl fmt::Debug for LocalInterner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalInterner")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString, vec::Vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn interns_by_value() {
+        let interner = LocalInterner::new();
+        assert!(interner.is_empty());
+
+        let a = interner.intern("a");
+        let b = interner.intern("b");
+        let owned = "a".to_string();
+        let also_a = interner.intern(&owned);
+
+        assert!(Prc::ptr_eq(&a, &also_a));
+        assert!(!Prc::ptr_eq(&a, &b));
+        assert_eq!(interner.len(), 2);
+        assert_eq!(&*interner.get("b").unwrap(), "b");
+        assert!(interner.get("c").is_none());
+        assert_eq!(format!("{:?}", interner), "LocalInterner { len: 2, .. }");
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn evicts_freed_strings() {
+        let interner = LocalInterner::new();
+        let first = interner.intern("reused");
+        drop(first);
+        assert!(interner.get("reused").is_none());
+
+        // The dead entry is replaced
+        let second = interner.intern("reused");
+        assert_eq!(interner.len(), 1);
+        assert_eq!(&*second, "reused");
+
+        // Dead entries are purged once enough of them accumulate
+        for i in 0..MIN_PURGE_LEN * 4 {
+            drop(interner.intern(&i.to_string()));
+        }
+        assert!(interner.len() <= MIN_PURGE_LEN + 1);
+
+        let kept: Vec<_> = (0..4).map(|i| interner.intern(&i.to_string())).collect();
+        interner.purge();
+        assert_eq!(interner.len(), kept.len() + 1);
+    }
+}
@@ -0,0 +1,254 @@
+//! Reading shared buffers without copying them.
+//!
+//! [`ParcCursor`] reads from a [`Parc<[u8]>`](Parc) like [`std::io::Cursor`] does from a slice,
+//! but can also hand out the bytes it reads as `Parc`s sharing the cursor's buffer.
+//!
+//! With the `std` feature, the cursor implements `std::io::Read`, `BufRead` and `Seek`.
+//! With the `futures-io` feature, it also implements `futures_io::AsyncRead`, `AsyncBufRead`
+//! and `AsyncSeek`, which never return `Poll::Pending`. With the `embedded-io` feature,
+//! it implements `embedded_io::Read`, `BufRead`, `Seek` and `ReadReady`, even without `std`.
+//!
+//! [`std::io::Cursor`]: https://doc.rust-lang.org/std/io/struct.Cursor.html
+
+use core::cmp::min;
+
+use crate::sync::Parc;
+
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+#[cfg(feature = "futures-io")]
+mod futures_io;
+
+/// A cursor reading from a shared [`Parc<[u8]>`](Parc).
+///
+/// # Example
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+#[cfg_attr(feature = "std", doc = "```")]
+/// use std::io::Read;
+/// use pared::{io::ParcCursor, sync::Parc};
+///
+/// let mut cursor = ParcCursor::new(Parc::from(b"\x03abcrest".to_vec()));
+///
+/// let mut len = [0];
+/// cursor.read_exact(&mut len)?;
+/// // The payload shares the cursor's buffer
+/// let payload = cursor.read_parc(len[0] as usize);
+/// assert_eq!(&*payload, b"abc");
+/// assert_eq!(&*cursor.remaining_parc(), b"rest");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParcCursor {
+    inner: Parc<[u8]>,
+    pos: u64,
+}
+
+impl ParcCursor {
+    /// Creates a new cursor positioned at the start of `inner`.
+    #[inline]
+    pub fn new(inner: Parc<[u8]>) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Returns the buffer this cursor reads from.
+    #[inline]
+    pub fn get_ref(&self) -> &Parc<[u8]> {
+        &self.inner
+    }
+
+    /// Consumes the cursor, returning the buffer it reads from.
+    #[inline]
+    pub fn into_inner(self) -> Parc<[u8]> {
+        self.inner
+    }
+
+    /// Returns the current position of the cursor.
+    ///
+    /// The position may be past the end of the buffer, in which case there's nothing left
+    /// to read.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of the cursor.
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    #[inline]
+    fn start(&self) -> usize {
+        min(self.pos, self.inner.len() as u64) as usize
+    }
+
+    /// Returns the bytes between the current position and the end of the buffer.
+    #[inline]
+    pub fn remaining_slice(&self) -> &[u8] {
+        &self.inner[self.start()..]
+    }
+
+    /// Returns `true` if there are no bytes left to read.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining_slice().is_empty()
+    }
+
+    /// Returns the bytes between the current position and the end of the buffer
+    /// as a `Parc` sharing the buffer.
+    #[inline]
+    pub fn remaining_parc(&self) -> Parc<[u8]> {
+        let start = self.start();
+        self.inner.project(|bytes| &bytes[start..])
+    }
+
+    /// Reads up to `len` bytes as a `Parc` sharing the buffer, advancing the cursor.
+    ///
+    /// Fewer than `len` bytes are returned only if the end of the buffer is reached.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{io::ParcCursor, sync::Parc};
+    ///
+    /// let mut cursor = ParcCursor::new(Parc::from(b"abcde".to_vec()));
+    /// assert_eq!(&*cursor.read_parc(2), b"ab");
+    /// assert_eq!(&*cursor.read_parc(8), b"cde");
+    /// assert!(cursor.read_parc(1).is_empty());
+    /// ```
+    pub fn read_parc(&mut self, len: usize) -> Parc<[u8]> {
+        let start = self.start();
+        let end = start + min(len, self.inner.len() - start);
+        self.pos = end as u64;
+        self.inner.project(|bytes| &bytes[start..end])
+    }
+}
+
+impl From<Parc<[u8]>> for ParcCursor {
+    #[inline]
+    fn from(inner: Parc<[u8]>) -> Self {
+        Self::new(inner)
+    }
+}
+
+// Shared by the I/O trait implementations
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+impl ParcCursor {
+    /// Copies as many bytes as fit into `buf`, advancing the cursor.
+    /// Returns the number of bytes copied.
+    #[inline]
+    fn read_into(&mut self, buf: &mut [u8]) -> usize {
+        let remaining = self.remaining_slice();
+        let len = min(buf.len(), remaining.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.pos = (self.start() + len) as u64;
+        len
+    }
+
+    /// Advances the cursor by `amount` bytes, stopping at the end of the buffer.
+    #[inline]
+    fn consume_bytes(&mut self, amount: usize) {
+        self.pos = (self.start() + min(amount, self.remaining_slice().len())) as u64;
+    }
+
+    /// Moves the cursor `offset` bytes from `base`.
+    ///
+    /// Returns `None` without moving the cursor if the new position would be negative
+    /// or overflow.
+    #[inline]
+    fn seek_relative_to(&mut self, base: u64, offset: i64) -> Option<u64> {
+        self.pos = base.checked_add_signed(offset)?;
+        Some(self.pos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ParcCursor {
+    // `io::ErrorKind` is only unstably re-exported from `core`
+    #[allow(clippy::std_instead_of_core)]
+    fn seek_std(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            std::io::SeekFrom::Start(offset) => {
+                self.pos = offset;
+                Some(offset)
+            }
+            std::io::SeekFrom::End(offset) => {
+                self.seek_relative_to(self.inner.len() as u64, offset)
+            }
+            std::io::SeekFrom::Current(offset) => self.seek_relative_to(self.pos, offset),
+        };
+        pos.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for ParcCursor {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.read_into(buf))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::BufRead for ParcCursor {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amount: usize) {
+        self.consume_bytes(amount);
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Seek for ParcCursor {
+    #[inline]
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.seek_std(pos)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use std::io::{BufRead, Read, Seek, SeekFrom};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn std_io() {
+        let buffer: Parc<[u8]> = Parc::from(b"line one\nline two".to_vec());
+        let mut cursor = ParcCursor::from(buffer.clone());
+
+        let mut line = Vec::new();
+        cursor.read_until(b'\n', &mut line).unwrap();
+        assert_eq!(line, b"line one\n");
+        assert_eq!(cursor.position(), 9);
+
+        let tail = cursor.remaining_parc();
+        assert!(core::ptr::eq(&tail[0], &buffer[9]));
+
+        assert_eq!(cursor.seek(SeekFrom::End(-3)).unwrap(), 14);
+        let mut rest = Vec::new();
+        cursor.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"two");
+        assert!(cursor.is_empty());
+
+        assert!(cursor.seek(SeekFrom::Current(-20)).is_err());
+        assert_eq!(cursor.seek(SeekFrom::Start(100)).unwrap(), 100);
+        assert_eq!(cursor.read(&mut [0; 4]).unwrap(), 0);
+        assert!(cursor.read_parc(4).is_empty());
+        assert_eq!(cursor.position(), 17);
+
+        cursor.set_position(5);
+        assert_eq!(&*cursor.read_parc(3), b"one");
+        assert!(Parc::ptr_eq(cursor.get_ref(), &buffer));
+        assert_eq!(Parc::strong_count(&cursor.into_inner()), 3);
+    }
+}
@@ -0,0 +1,83 @@
+use embedded_io::{BufRead, ErrorKind, ErrorType, Read, ReadReady, Seek, SeekFrom};
+
+use super::ParcCursor;
+
+impl ErrorType for ParcCursor {
+    type Error = ErrorKind;
+}
+
+impl Read for ParcCursor {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, ErrorKind> {
+        Ok(self.read_into(buf))
+    }
+}
+
+impl BufRead for ParcCursor {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], ErrorKind> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amount: usize) {
+        self.consume_bytes(amount);
+    }
+}
+
+impl ReadReady for ParcCursor {
+    /// Always returns `true`, as the whole buffer is available for reading.
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, ErrorKind> {
+        Ok(true)
+    }
+}
+
+impl Seek for ParcCursor {
+    /// Seeks to an offset in the buffer.
+    ///
+    /// Seeking past the end of the buffer is allowed; seeking to a negative
+    /// or overflowing position fails with [`ErrorKind::InvalidInput`].
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ErrorKind> {
+        let pos = match pos {
+            SeekFrom::Start(offset) => {
+                self.pos = offset;
+                Some(offset)
+            }
+            SeekFrom::End(offset) => self.seek_relative_to(self.inner.len() as u64, offset),
+            SeekFrom::Current(offset) => self.seek_relative_to(self.pos, offset),
+        };
+        pos.ok_or(ErrorKind::InvalidInput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::Parc;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn embedded_io() {
+        let image: Parc<[u8]> = Parc::from(alloc::vec![1, 2, 3, 4, 5]);
+        let mut cursor = ParcCursor::new(image);
+
+        let mut header = [0; 2];
+        cursor.read_exact(&mut header).unwrap();
+        assert_eq!(header, [1, 2]);
+        assert!(cursor.read_ready().unwrap());
+
+        assert_eq!(cursor.fill_buf().unwrap(), &[3, 4, 5]);
+        BufRead::consume(&mut cursor, 1);
+        assert_eq!(cursor.seek(SeekFrom::Current(0)).unwrap(), 3);
+
+        assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 4);
+        assert_eq!(Read::read(&mut cursor, &mut [0; 4]).unwrap(), 1);
+        assert_eq!(
+            cursor.seek(SeekFrom::Current(-6)),
+            Err(ErrorKind::InvalidInput)
+        );
+        cursor.rewind().unwrap();
+        assert_eq!(cursor.position(), 0);
+    }
+}
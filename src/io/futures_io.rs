@@ -0,0 +1,75 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::io::{Result, SeekFrom};
+
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek};
+
+use super::ParcCursor;
+
+impl AsyncRead for ParcCursor {
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        Poll::Ready(Ok(self.get_mut().read_into(buf)))
+    }
+}
+
+impl AsyncBufRead for ParcCursor {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().remaining_slice()))
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amount: usize) {
+        self.get_mut().consume_bytes(amount);
+    }
+}
+
+impl AsyncSeek for ParcCursor {
+    #[inline]
+    fn poll_seek(self: Pin<&mut Self>, _cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        Poll::Ready(self.get_mut().seek_std(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::Parc;
+    use alloc::{sync::Arc, task::Wake};
+    use core::task::Waker;
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn async_io() {
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        let mut cursor = ParcCursor::new(Parc::from(b"header:body".to_vec()));
+
+        let mut header = [0; 7];
+        let read = Pin::new(&mut cursor).poll_read(&mut cx, &mut header);
+        assert!(matches!(read, Poll::Ready(Ok(7))));
+        assert_eq!(&header, b"header:");
+
+        let filled = Pin::new(&mut cursor).poll_fill_buf(&mut cx);
+        assert!(matches!(filled, Poll::Ready(Ok(b"body"))));
+        Pin::new(&mut cursor).consume(2);
+        assert_eq!(cursor.remaining_slice(), b"dy");
+
+        let seek = Pin::new(&mut cursor).poll_seek(&mut cx, SeekFrom::Current(-4));
+        assert!(matches!(seek, Poll::Ready(Ok(5))));
+        assert_eq!(&*cursor.remaining_parc(), b"r:body");
+    }
+}
@@ -16,7 +16,8 @@
 //! to the receiver.
 //!
 //! # Example
-//! ```
+#![cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#![cfg_attr(feature = "sync", doc = "```")]
 //! use std::sync::Arc;
 //! use pared::sync::Parc;
 //!
@@ -34,20 +35,161 @@
 //!     accepts_parc(from_u8);
 //! }
 //! ```
+//!
+//! # Features
+//! - `std` (default): enables functionality that requires the standard library.
+//!   Without it, the crate only depends on `core` and `alloc`.
+//! - `sync` (default): adds the `sync` module with `Parc`, and everything built on it.
+//!   Without it, only `Prc` is available and the crate doesn't refer to `alloc::sync` at all,
+//!   so it can be used on targets without atomic pointers, such as `thumbv6m-none-eabi`.
+//!   All features that only extend `Parc` imply `sync`.
+//! - `weak` (default): adds the `Weak` pointers of the `sync` and `prc` modules, `downgrade`
+//!   and `Prc::new_cyclic`, as well as the `callbacks`, `intern`, `observe` and `slab` modules
+//!   that rely on them.
+//!   Without it, the vtable shared by all pointers to a root type has no weak operations,
+//!   so less code is generated for each root type. Note that `Arc` and `Rc` still keep a weak
+//!   count in every allocation, so `weak_count` remains available and dropping a pointer
+//!   costs the same.
+//! - `defmt`: implements [`defmt::Format`] for `Parc`, `Prc` and their `Weak` pointers.
+//! - `zeroize`: adds `Parc::new_zeroizing` and `Prc::new_zeroizing` for values that should be
+//!   wiped from memory when the last pointer to them is dropped.
+//! - `json`: implements [`dom::Index`] for `serde_json::Value`, adding `Parc<serde_json::Value>`
+//!   projections to sub-trees by path, such as `Parc::project_path`.
+//! - `simd-json`: implements [`dom::Index`] for `simd_json::OwnedValue`, adding the same
+//!   path projections as the `json` feature.
+//! - `serde`: implements `Serialize` and `Deserialize` for `Parc`, `Prc` and `prc::Weak`,
+//!   following the semantics of serde's `rc` feature.
+//! - `bytemuck`: adds `Parc::try_cast_slice` and `Parc::as_bytes_parc`, reinterpreting
+//!   `Parc<[u8]>` as slices of [`bytemuck::Pod`] types and back without copying.
+//! - `async-lock`: adds owned guards projected from `Parc<async_lock::Mutex<T>>` and
+//!   `Parc<async_lock::RwLock<T>>` to the `lock` module, for executor-agnostic async code.
+//! - `bytes`: converts `Parc<[u8]>` into [`bytes::Bytes`] with `From`, and back with
+//!   `Parc::from_bytes`, without copying the bytes.
+//! - `embedded-io`: implements the `embedded_io` reader traits for `io::ParcCursor`,
+//!   for streaming shared buffers on `no_std` targets.
+//! - `futures-io`: implements `futures_io::AsyncRead`, `AsyncBufRead` and `AsyncSeek` for
+//!   `io::ParcCursor`, without depending on any particular executor. Implies `std`.
+//! - `http-body`: adds the `body` module with `ParcBody`, an [`http_body::Body`]
+//!   serving a `Parc<[u8]>` without copying it. Implies `bytes`.
+//! - `parking_lot`: adds owned guards projected from `Parc<parking_lot::Mutex<T>>` and
+//!   `Parc<parking_lot::RwLock<T>>` to the `lock` module, such as `Parc::lock_owned_projected`.
+//! - `smallvec`: adds `sync::RootSet`, a compact set of type-erased keep-alives for the roots
+//!   a value depends on, storing a few roots inline with [`smallvec::SmallVec`].
+//! - `type-names`: adds `Parc::root_type_name` and `Prc::root_type_name`, naming the type
+//!   of the allocation a pointer keeps alive, and includes it in `DebugInfo`.
+//!   This stores one more function pointer in the vtable of every root type.
+//! - `debug-cycles`: adds the `debug` module, recording which `Parc` roots hold pointers
+//!   into each other and writing them out with `debug::dump_graph`, to find reference cycles
+//!   that keep roots alive. Takes a global lock whenever a root is constructed, projected
+//!   or possibly dropped. Implies `std` and `type-names`.
+//! - `testing`: implements [`proptest::arbitrary::Arbitrary`] and [`quickcheck::Arbitrary`]
+//!   for `Parc` and `Prc`. The generated pointers are sometimes projected into a larger
+//!   generated root, like the pointers in real programs. Implies `std`.
+//! - `mmap`: adds `Parc::from_mmap`, sharing the bytes of a memory-mapped file
+//!   as a `Parc<[u8]>`. Implies `std`.
+//! - `allocator-api` (nightly only): adds fallible constructors that return
+//!   [`AllocError`] instead of aborting when an allocation fails, such as `Parc::try_new`,
+//!   `Parc::try_from_vec` and `Parc::try_from_iter` (and their `Prc` equivalents).
+//!   It also adds `Prc::new_in`, constructing a `Prc` in a custom allocator.
+//!   Once a pointer is constructed, none of its operations allocate: cloning, dropping,
+//!   projecting, downgrading and upgrading only ever update the reference counts.
+//! - `local-waker` (nightly only): adds the `task` module, creating a `LocalWaker`
+//!   for single-threaded executors from a `Prc`. Creating the waker allocates once to hold
+//!   the `Prc`; cloning it doesn't.
+//! - `fn-traits` (nightly only): implements `Fn`, `FnMut` and `FnOnce` for `Parc<F>` and `Prc<F>`
+//!   where `F: Fn`, so projected callbacks such as `Parc<dyn Fn(Event)>` can be called directly.
+//! - `unique-rc-arc` (nightly only): adds `sync::UniqueParc`, a uniquely owned value that
+//!   hands out `Weak` pointers while it's being built and is then frozen into a `Parc`.
+//!   Implies `weak`.
+//!
+//! [`defmt::Format`]: https://docs.rs/defmt/latest/defmt/trait.Format.html
+//! [`bytemuck::Pod`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html
+//! [`bytes::Bytes`]: https://docs.rs/bytes/latest/bytes/struct.Bytes.html
+//! [`http_body::Body`]: https://docs.rs/http-body/latest/http_body/trait.Body.html
+//! [`proptest::arbitrary::Arbitrary`]: https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html
+//! [`quickcheck::Arbitrary`]: https://docs.rs/quickcheck/latest/quickcheck/trait.Arbitrary.html
+//! [`smallvec::SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
+//! [`AllocError`]: https://doc.rust-lang.org/alloc/alloc/struct.AllocError.html
 
 #![deny(missing_docs)]
 #![deny(clippy::std_instead_of_core)]
 #![deny(clippy::std_instead_of_alloc)]
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
+#![cfg_attr(feature = "local-waker", feature(local_waker))]
+#![cfg_attr(
+    feature = "fn-traits",
+    feature(fn_traits, tuple_trait, unboxed_closures)
+)]
+#![cfg_attr(feature = "unique-rc-arc", feature(unique_rc_arc))]
 
 extern crate alloc;
 extern crate core;
 
-#[cfg(doctest)]
+#[cfg(all(doctest, feature = "sync"))]
 doc_comment::doctest!("../README.md");
 
+#[cfg(feature = "http-body")]
+pub mod body;
+#[cfg(all(feature = "std", feature = "sync", feature = "weak"))]
+pub mod callbacks;
+#[cfg(feature = "debug-cycles")]
+pub mod debug;
+#[cfg(all(feature = "std", feature = "sync"))]
+pub mod defer;
+#[cfg(feature = "sync")]
+pub mod dom;
+#[cfg(all(feature = "std", feature = "weak"))]
+pub mod intern;
+#[cfg(feature = "sync")]
+pub mod io;
+#[cfg(all(
+    feature = "sync",
+    any(feature = "std", feature = "async-lock", feature = "parking_lot")
+))]
+pub mod lock;
+#[cfg(all(feature = "sync", feature = "weak"))]
+pub mod observe;
+#[cfg(all(feature = "std", feature = "sync"))]
+pub mod pool;
 pub mod prc;
+#[cfg(all(feature = "std", feature = "sync"))]
+pub mod registry;
+#[cfg(all(feature = "sync", feature = "weak"))]
+pub mod slab;
+#[cfg(feature = "sync")]
 pub mod sync;
+#[cfg(feature = "local-waker")]
+pub mod task;
+#[cfg(feature = "testing")]
+mod testing;
+#[cfg(feature = "sync")]
+pub mod text;
+#[cfg(all(feature = "std", feature = "sync"))]
+pub mod watch;
 
+mod counts;
+mod debug_info;
 mod erased_ptr;
+#[cfg(feature = "fn-traits")]
+mod fn_traits;
+mod identity;
+#[cfg(feature = "sync")]
+mod loan;
+mod offset;
+#[cfg(all(feature = "std", feature = "sync"))]
+mod poison;
+mod projection;
+mod projector;
+mod slice_error;
 mod vtable;
+
+pub use counts::Counts;
+pub use debug_info::DebugInfo;
+pub use identity::ByIdentity;
+#[cfg(feature = "sync")]
+pub use loan::{scope, Scope};
+pub use offset::FieldOffset;
+pub use projection::Projection;
+pub use projector::Projector;
+pub use slice_error::SliceError;
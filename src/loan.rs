@@ -0,0 +1,157 @@
+//! Scoped loans of `Parc`s that must be returned before their scope ends.
+
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    any::Any,
+    cell::RefCell,
+    fmt,
+    marker::{Send, Sync},
+};
+
+use crate::sync::Parc;
+
+/// Runs `f` with a [`Scope`] for loaning out `Parc`s, and checks that all loans
+/// have been returned once `f` is done.
+///
+/// This is meant for tests of code that receives `Parc`s, to catch tasks that keep
+/// their handles for longer than intended, e.g. by stashing them in a cache.
+///
+/// # Panics
+/// Panics if a `Parc` loaned with [`Scope::loan`], or any clone or projection of it,
+/// is still alive when `f` returns. If `f` itself panics, the loans aren't checked.
+///
+/// # Example
+/// ```
+/// use pared::sync::Parc;
+///
+/// let config = Parc::new((String::from("name"), 8));
+/// let threads = pared::scope(|s| {
+///     s.loan(&config, |config| {
+///         // The loaned `Parc` is moved into the thread and dropped when it's joined
+///         std::thread::spawn(move || config.1).join().unwrap()
+///     })
+/// });
+/// assert_eq!(threads, 8);
+/// assert_eq!(Parc::strong_count(&config), 1);
+/// ```
+///
+/// ```should_panic
+/// use pared::sync::Parc;
+///
+/// let mut cache = Vec::new();
+/// let config = Parc::new(String::from("config"));
+/// pared::scope(|s| {
+///     s.loan(&config, |config| cache.push(config.project(|config| &config[..4])));
+/// }); // panics, since the cache kept a projection of the loan
+/// ```
+pub fn scope<F, R>(f: F) -> R
+where
+    F: FnOnce(&Scope) -> R,
+{
+    let scope = Scope {
+        loans: RefCell::new(Vec::new()),
+    };
+    let result = f(&scope);
+    let outstanding = scope
+        .loans
+        .into_inner()
+        .iter()
+        .filter(|loan| Arc::strong_count(loan) > 1)
+        .count();
+    assert!(
+        outstanding == 0,
+        "{outstanding} loaned Parc(s) outlived their scope"
+    );
+    result
+}
+
+/// A scope for loaning out `Parc`s, created by [`scope`].
+pub struct Scope {
+    // The root of every loaned `Parc`, which is only shared by the loan and its clones
+    loans: RefCell<Vec<Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Scope {
+    /// Loans a clone of `parc` to `f`, returning what `f` returns.
+    ///
+    /// The loaned `Parc` points to the same value, and can be cloned, projected and sent
+    /// to other threads as usual, but all of them must be dropped before the scope ends.
+    /// While they're alive, they keep the value alive and count as one strong pointer
+    /// of `parc`'s allocation.
+    pub fn loan<T, F, R>(&self, parc: &Parc<T>, f: F) -> R
+    where
+        T: ?Sized + Send + Sync + 'static,
+        F: FnOnce(Parc<T>) -> R,
+    {
+        let root = Arc::new(parc.clone());
+        let loan = Parc::from_arc(&root, |parc| &**parc);
+        self.loans.borrow_mut().push(root);
+        f(loan)
+    }
+
+    /// Returns the number of loans that haven't been returned yet.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let value = Parc::new(5);
+    /// pared::scope(|s| {
+    ///     let kept = s.loan(&value, |value| value);
+    ///     s.loan(&value, drop);
+    ///     assert_eq!(s.outstanding(), 1);
+    ///     drop(kept);
+    ///     assert_eq!(s.outstanding(), 0);
+    /// });
+    /// ```
+    pub fn outstanding(&self) -> usize {
+        self.loans
+            .borrow()
+            .iter()
+            .filter(|loan| Arc::strong_count(loan) > 1)
+            .count()
+    }
+}
+
+impl fmt::Debug for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("outstanding", &self.outstanding())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn loans_share_the_original_allocation() {
+        let parc = Parc::new((String::from("name"), 5));
+        let name = parc.project(|pair| pair.0.as_str());
+        scope(|s| {
+            let len = s.loan(&name, |loan| {
+                assert!(Parc::ptr_eq(&loan, &name));
+                assert_eq!(Parc::strong_count(&parc), 3);
+                assert_eq!(format!("{:?}", s), "Scope { outstanding: 1, .. }");
+                loan.project(|name| &name[1..]).len()
+            });
+            assert_eq!(len, 3);
+        });
+        assert_eq!(Parc::strong_count(&parc), 2);
+    }
+
+    #[test]
+    #[should_panic = "1 loaned Parc(s) outlived their scope"]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn panics_on_leaked_loans() {
+        let parc = Parc::new(1);
+        let mut leaked = None;
+        scope(|s| {
+            s.loan(&parc, |loan| leaked = Some(loan));
+            s.loan(&parc, drop);
+        });
+    }
+}
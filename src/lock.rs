@@ -0,0 +1,30 @@
+//! Owned lock guards projected from a [`Parc`](crate::sync::Parc) to a lock.
+//!
+//! The guards hold onto the lock's root allocation, so they're `'static` and can be stored
+//! or returned without borrowing the `Parc` they were created from. Each guard points to
+//! a part of the locked value chosen when locking, keeping the rest of the value private.
+//!
+//! - With the `parking_lot` feature, `Parc<parking_lot::Mutex<T>>` and
+//!   `Parc<parking_lot::RwLock<T>>` get `lock_owned_projected`, `read_owned_projected`
+//!   and `write_owned_projected`.
+//! - With the `async-lock` feature, `Parc<async_lock::Mutex<T>>` and
+//!   `Parc<async_lock::RwLock<T>>` get asynchronous methods with the same names, along with
+//!   `try_` variants that don't wait. This works with any executor.
+//! - With the `std` feature, [`lock_all`] locks several `Parc<std::sync::Mutex<T>>`s
+//!   in a consistent order, avoiding deadlocks between threads locking them in different orders.
+
+#[cfg(feature = "async-lock")]
+mod async_lock;
+#[cfg(feature = "std")]
+mod ordered;
+#[cfg(feature = "parking_lot")]
+mod parking_lot;
+
+#[cfg(feature = "async-lock")]
+pub use self::async_lock::{
+    ParcAsyncMutexGuard, ParcAsyncRwLockReadGuard, ParcAsyncRwLockWriteGuard,
+};
+#[cfg(feature = "std")]
+pub use self::ordered::lock_all;
+#[cfg(feature = "parking_lot")]
+pub use self::parking_lot::{ParcMutexGuard, ParcRwLockReadGuard, ParcRwLockWriteGuard};
@@ -0,0 +1,426 @@
+use core::{
+    fmt,
+    mem::transmute,
+    ops::{Deref, DerefMut, FnOnce},
+    option::{Option, Option::Some},
+    ptr::NonNull,
+};
+
+use ::async_lock::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{sync::Parc, Projection};
+
+impl<T: ?Sized + Send + 'static> Parc<Mutex<T>> {
+    /// Locks the mutex and returns an owned guard to the part of the value
+    /// selected by `project`.
+    ///
+    /// The guard keeps the mutex's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::Mutex;
+    /// use pared::{lock::ParcAsyncMutexGuard, sync::Parc};
+    ///
+    /// struct Session {
+    ///     user: String,
+    ///     requests: u64,
+    /// }
+    ///
+    /// async fn requests(session: &Parc<Mutex<Session>>) -> ParcAsyncMutexGuard<Session, u64> {
+    ///     session.lock_owned_projected(|session| &mut session.requests).await
+    /// }
+    /// ```
+    pub async fn lock_owned_projected<U, F>(&self, project: F) -> ParcAsyncMutexGuard<T, U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let guard = self.lock().await;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard = unsafe { transmute::<MutexGuard<'_, T>, MutexGuard<'static, T>>(guard) };
+        ParcAsyncMutexGuard::new(guard, self.clone(), project)
+    }
+
+    /// Attempts to lock the mutex without waiting, returning an owned guard to the part
+    /// of the value selected by `project` on success.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::Mutex;
+    /// use pared::sync::Parc;
+    ///
+    /// let session = Parc::new(Mutex::new((String::from("user"), 0u64)));
+    /// let mut requests = session.try_lock_owned_projected(|session| &mut session.1).unwrap();
+    /// *requests += 1;
+    ///
+    /// assert!(session.try_lock_owned_projected(|session| &mut session.0).is_none());
+    /// drop(requests);
+    /// assert_eq!(session.try_lock().unwrap().1, 1);
+    /// ```
+    pub fn try_lock_owned_projected<U, F>(&self, project: F) -> Option<ParcAsyncMutexGuard<T, U>>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let guard = self.try_lock()?;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard = unsafe { transmute::<MutexGuard<'_, T>, MutexGuard<'static, T>>(guard) };
+        Some(ParcAsyncMutexGuard::new(guard, self.clone(), project))
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Parc<RwLock<T>> {
+    /// Locks the lock with shared read access and returns an owned guard to the part
+    /// of the value selected by `project`.
+    ///
+    /// The guard keeps the lock's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::RwLock;
+    /// use pared::{lock::ParcAsyncRwLockReadGuard, sync::Parc};
+    ///
+    /// type Config = (String, u16);
+    ///
+    /// async fn name(config: &Parc<RwLock<Config>>) -> ParcAsyncRwLockReadGuard<Config, str> {
+    ///     config.read_owned_projected(|config| config.0.as_str()).await
+    /// }
+    /// ```
+    pub async fn read_owned_projected<U, F>(&self, project: F) -> ParcAsyncRwLockReadGuard<T, U>
+    where
+        U: ?Sized,
+        F: Projection<T, U>,
+    {
+        let guard = self.read().await;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard =
+            unsafe { transmute::<RwLockReadGuard<'_, T>, RwLockReadGuard<'static, T>>(guard) };
+        ParcAsyncRwLockReadGuard::new(guard, self.clone(), project)
+    }
+
+    /// Attempts to lock the lock with shared read access without waiting, returning an owned
+    /// guard to the part of the value selected by `project` on success.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::RwLock;
+    /// use pared::sync::Parc;
+    ///
+    /// let config = Parc::new(RwLock::new((String::from("name"), 8u16)));
+    /// let name = config.try_read_owned_projected(|config| config.0.as_str()).unwrap();
+    /// drop(config);
+    /// assert_eq!(&*name, "name");
+    /// ```
+    pub fn try_read_owned_projected<U, F>(
+        &self,
+        project: F,
+    ) -> Option<ParcAsyncRwLockReadGuard<T, U>>
+    where
+        U: ?Sized,
+        F: Projection<T, U>,
+    {
+        let guard = self.try_read()?;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard =
+            unsafe { transmute::<RwLockReadGuard<'_, T>, RwLockReadGuard<'static, T>>(guard) };
+        Some(ParcAsyncRwLockReadGuard::new(guard, self.clone(), project))
+    }
+
+    /// Locks the lock with exclusive write access and returns an owned guard to the part
+    /// of the value selected by `project`.
+    ///
+    /// The guard keeps the lock's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::RwLock;
+    /// use pared::sync::Parc;
+    ///
+    /// async fn bump_port(config: &Parc<RwLock<(String, u16)>>) {
+    ///     *config.write_owned_projected(|config| &mut config.1).await += 1;
+    /// }
+    /// ```
+    pub async fn write_owned_projected<U, F>(&self, project: F) -> ParcAsyncRwLockWriteGuard<T, U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let guard = self.write().await;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard =
+            unsafe { transmute::<RwLockWriteGuard<'_, T>, RwLockWriteGuard<'static, T>>(guard) };
+        ParcAsyncRwLockWriteGuard::new(guard, self.clone(), project)
+    }
+
+    /// Attempts to lock the lock with exclusive write access without waiting, returning
+    /// an owned guard to the part of the value selected by `project` on success.
+    ///
+    /// # Example
+    /// ```
+    /// use async_lock::RwLock;
+    /// use pared::sync::Parc;
+    ///
+    /// let config = Parc::new(RwLock::new((String::from("name"), 8u16)));
+    /// *config.try_write_owned_projected(|config| &mut config.1).unwrap() += 1;
+    /// assert_eq!(config.try_read().unwrap().1, 9);
+    /// ```
+    pub fn try_write_owned_projected<U, F>(
+        &self,
+        project: F,
+    ) -> Option<ParcAsyncRwLockWriteGuard<T, U>>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let guard = self.try_write()?;
+        // SAFETY: the guard doesn't outlive the `Parc` it's stored with
+        let guard =
+            unsafe { transmute::<RwLockWriteGuard<'_, T>, RwLockWriteGuard<'static, T>>(guard) };
+        Some(ParcAsyncRwLockWriteGuard::new(guard, self.clone(), project))
+    }
+}
+
+/// An owned guard to part of the value in an [`async_lock::Mutex`](::async_lock::Mutex),
+/// created by `Parc::lock_owned_projected`.
+///
+/// The mutex is unlocked when the guard is dropped.
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct ParcAsyncMutexGuard<T: ?Sized + 'static, U: ?Sized> {
+    value: NonNull<U>,
+    // Dropped before `_root`, which keeps the mutex alive
+    _guard: MutexGuard<'static, T>,
+    _root: Parc<Mutex<T>>,
+}
+
+impl<T: ?Sized + Send + 'static, U: ?Sized> ParcAsyncMutexGuard<T, U> {
+    #[inline]
+    fn new<F>(mut guard: MutexGuard<'static, T>, root: Parc<Mutex<T>>, project: F) -> Self
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        Self {
+            value: NonNull::from(project(&mut *guard)),
+            _guard: guard,
+            _root: root,
+        }
+    }
+}
+
+// SAFETY: the guard gives out `&mut U`, and the mutex may be unlocked from any thread
+unsafe impl<T: ?Sized + Send + 'static, U: ?Sized + Send> Send for ParcAsyncMutexGuard<T, U> {}
+// SAFETY: the guard only gives out `&U` through shared references
+unsafe impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + Sync> Sync
+    for ParcAsyncMutexGuard<T, U>
+{
+}
+
+impl<T: ?Sized + 'static, U: ?Sized> Deref for ParcAsyncMutexGuard<T, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the mutex is locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized> DerefMut for ParcAsyncMutexGuard<T, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: the mutex is locked and kept alive by the guard
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized + fmt::Debug> fmt::Debug for ParcAsyncMutexGuard<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// An owned guard to part of the value in an [`async_lock::RwLock`](::async_lock::RwLock)
+/// with shared read access, created by `Parc::read_owned_projected`.
+///
+/// The lock is released when the guard is dropped.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct ParcAsyncRwLockReadGuard<T: ?Sized + 'static, U: ?Sized> {
+    value: NonNull<U>,
+    // Dropped before `_root`, which keeps the lock alive
+    _guard: RwLockReadGuard<'static, T>,
+    _root: Parc<RwLock<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static, U: ?Sized> ParcAsyncRwLockReadGuard<T, U> {
+    #[inline]
+    fn new<F>(guard: RwLockReadGuard<'static, T>, root: Parc<RwLock<T>>, project: F) -> Self
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        Self {
+            value: NonNull::from(project(&*guard)),
+            _guard: guard,
+            _root: root,
+        }
+    }
+}
+
+// SAFETY: the guard only gives out `&U`, and the lock may be released from any thread
+unsafe impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + Sync> Send
+    for ParcAsyncRwLockReadGuard<T, U>
+{
+}
+// SAFETY: the guard only gives out `&U`
+unsafe impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + Sync> Sync
+    for ParcAsyncRwLockReadGuard<T, U>
+{
+}
+
+impl<T: ?Sized + 'static, U: ?Sized> Deref for ParcAsyncRwLockReadGuard<T, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the lock is read-locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized + fmt::Debug> fmt::Debug for ParcAsyncRwLockReadGuard<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// An owned guard to part of the value in an [`async_lock::RwLock`](::async_lock::RwLock)
+/// with exclusive write access, created by `Parc::write_owned_projected`.
+///
+/// The lock is released when the guard is dropped.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct ParcAsyncRwLockWriteGuard<T: ?Sized + 'static, U: ?Sized> {
+    value: NonNull<U>,
+    // Dropped before `_root`, which keeps the lock alive
+    _guard: RwLockWriteGuard<'static, T>,
+    _root: Parc<RwLock<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static, U: ?Sized> ParcAsyncRwLockWriteGuard<T, U> {
+    #[inline]
+    fn new<F>(mut guard: RwLockWriteGuard<'static, T>, root: Parc<RwLock<T>>, project: F) -> Self
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        Self {
+            value: NonNull::from(project(&mut *guard)),
+            _guard: guard,
+            _root: root,
+        }
+    }
+}
+
+// SAFETY: the guard gives out `&mut U`, and the lock may be released from any thread
+unsafe impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + Send> Send
+    for ParcAsyncRwLockWriteGuard<T, U>
+{
+}
+// SAFETY: the guard only gives out `&U` through shared references
+unsafe impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + Sync> Sync
+    for ParcAsyncRwLockWriteGuard<T, U>
+{
+}
+
+impl<T: ?Sized + 'static, U: ?Sized> Deref for ParcAsyncRwLockWriteGuard<T, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the lock is write-locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized> DerefMut for ParcAsyncRwLockWriteGuard<T, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: the lock is write-locked and kept alive by the guard
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: ?Sized + 'static, U: ?Sized + fmt::Debug> fmt::Debug for ParcAsyncRwLockWriteGuard<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, sync::Arc, task::Wake, vec::Vec};
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    // Polls a future that's expected to complete right away
+    fn ready<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Noop));
+        match pin!(future).poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future didn't complete"),
+        }
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn mutex_guard() {
+        let state = Parc::new(Mutex::new((0u32, Vec::<u8>::new())));
+        let mut guard = ready(state.lock_owned_projected(|state| &mut state.1));
+        assert_send(&guard);
+        guard.push(1);
+        assert!(state.try_lock().is_none());
+        assert!(state
+            .try_lock_owned_projected(|state| &mut state.0)
+            .is_none());
+        assert_eq!(format!("{:?}", guard), "[1]");
+
+        drop(guard);
+        assert_eq!(Parc::strong_count(&state), 1);
+        let guard = state
+            .try_lock_owned_projected(|state| &mut state.0)
+            .unwrap();
+        drop(state);
+        assert_eq!(*guard, 0);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn rwlock_guards() {
+        let lock = Parc::new(RwLock::new((String::from("name"), 1u8)));
+        let first = ready(lock.read_owned_projected(|state| state.0.as_str()));
+        let second = lock.try_read_owned_projected(|state| &state.1).unwrap();
+        assert_send(&first);
+        assert!(lock
+            .try_write_owned_projected(|state| &mut state.1)
+            .is_none());
+        assert_eq!((&*first, *second), ("name", 1));
+        assert_eq!(format!("{:?} {:?}", first, second), "\"name\" 1");
+        drop((first, second));
+
+        let mut count = ready(lock.write_owned_projected(|state| &mut state.1));
+        assert!(lock.try_read_owned_projected(|state| &state.1).is_none());
+        *count += 1;
+        assert_eq!(format!("{:?}", count), "2");
+        drop(count);
+        assert_eq!(lock.try_read().unwrap().1, 2);
+        assert_eq!(Parc::strong_count(&lock), 1);
+    }
+}
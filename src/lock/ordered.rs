@@ -0,0 +1,115 @@
+use std::sync::{LockResult, Mutex, MutexGuard, PoisonError};
+
+use crate::sync::Parc;
+
+/// Locks all `mutexes`, always acquiring them in the same order, and returns their guards
+/// in the order the mutexes were passed in.
+///
+/// The mutexes are locked in the order of their addresses, see [`Parc::addr`], so threads
+/// locking overlapping sets of mutexes with this function can't deadlock by waiting on each
+/// other, no matter in which order they pass the mutexes or which roots they reach them from.
+///
+/// # Errors
+/// If any of the mutexes is poisoned, all of them are still locked, and the guards are returned
+/// in a [`PoisonError`], like [`Mutex::lock`] does.
+///
+/// # Panics
+/// Panics if the same mutex is passed more than once, since locking it twice would deadlock.
+///
+/// # Example
+/// ```
+/// use std::sync::Mutex;
+/// use pared::{lock::lock_all, sync::Parc};
+///
+/// fn transfer(from: &Parc<Mutex<u64>>, to: &Parc<Mutex<u64>>, amount: u64) {
+///     let [mut from, mut to] = lock_all([from, to]).unwrap();
+///     *from -= amount;
+///     *to += amount;
+/// }
+///
+/// let accounts = Parc::new([Mutex::new(100), Mutex::new(50)]);
+/// let alice = accounts.project(|accounts| &accounts[0]);
+/// let bob = accounts.project(|accounts| &accounts[1]);
+///
+/// // Transfers in both directions can run concurrently without deadlocking
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| transfer(&alice, &bob, 30));
+///     scope.spawn(|| transfer(&bob, &alice, 10));
+/// });
+/// assert_eq!((*alice.lock().unwrap(), *bob.lock().unwrap()), (80, 70));
+/// ```
+pub fn lock_all<'a, T, const N: usize>(
+    mutexes: [&'a Parc<Mutex<T>>; N],
+) -> LockResult<[MutexGuard<'a, T>; N]>
+where
+    T: ?Sized,
+{
+    let mut order: [usize; N] = core::array::from_fn(|i| i);
+    order.sort_unstable_by_key(|&index| Parc::addr(mutexes[index]));
+    assert!(
+        order
+            .windows(2)
+            .all(|pair| Parc::addr(mutexes[pair[0]]) != Parc::addr(mutexes[pair[1]])),
+        "lock_all was passed the same mutex more than once"
+    );
+
+    let mut poisoned = false;
+    let mut guards: [Option<MutexGuard<'a, T>>; N] = [const { None }; N];
+    for index in order {
+        guards[index] = Some(mutexes[index].lock().unwrap_or_else(|error| {
+            poisoned = true;
+            error.into_inner()
+        }));
+    }
+    let guards = guards.map(|guard| guard.expect("every mutex was locked"));
+    if poisoned {
+        Err(PoisonError::new(guards))
+    } else {
+        Ok(guards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn locks_in_address_order() {
+        let roots: Vec<_> = (0..3).map(|i| Parc::new(Mutex::new(i))).collect();
+        let [c, a] = lock_all([&roots[2], &roots[0]]).unwrap();
+        assert_eq!((*c, *a), (2, 0));
+        drop((c, a));
+
+        let poisoned = roots[1].clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoned.lock().unwrap();
+            panic!("poisoning the mutex");
+        })
+        .join();
+        let error = lock_all([&roots[1], &roots[0]]).unwrap_err();
+        let [b, a] = error.into_inner();
+        assert_eq!((*b, *a), (1, 0));
+    }
+
+    #[test]
+    #[should_panic = "the same mutex more than once"]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn rejects_duplicates() {
+        let mutex = Parc::new(Mutex::new(()));
+        let _ = lock_all([&mutex, &mutex.clone()]);
+    }
+
+    #[test]
+    #[should_panic = "the same mutex more than once"]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn rejects_duplicates_from_other_roots() {
+        static MUTEX: Mutex<()> = Mutex::new(());
+        let other = Parc::new(1);
+        let first = Parc::from_static(&MUTEX);
+        // The same mutex, reached from a different root in between
+        let second = other.project(|_| &MUTEX);
+        let _ = lock_all([&first, &Parc::new(Mutex::new(())), &second]);
+    }
+}
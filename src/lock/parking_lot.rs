@@ -0,0 +1,299 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut, FnOnce},
+    ptr::NonNull,
+};
+
+use ::parking_lot::{
+    lock_api::{RawMutex as _, RawRwLock as _},
+    Mutex, RawMutex, RawRwLock, RwLock,
+};
+
+use crate::{sync::Parc, Projection};
+
+impl<T: ?Sized + Send> Parc<Mutex<T>> {
+    /// Locks the mutex and returns an owned guard to the part of the value
+    /// selected by `project`.
+    ///
+    /// The guard keeps the mutex's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use parking_lot::Mutex;
+    /// use pared::{lock::ParcMutexGuard, sync::Parc};
+    ///
+    /// struct Account {
+    ///     balance: u64,
+    ///     history: Vec<i64>,
+    /// }
+    ///
+    /// fn balance(account: &Parc<Mutex<Account>>) -> ParcMutexGuard<u64> {
+    ///     account.lock_owned_projected(|account| &mut account.balance)
+    /// }
+    ///
+    /// let account = Parc::new(Mutex::new(Account { balance: 0, history: Vec::new() }));
+    /// *balance(&account) += 10;
+    /// assert_eq!(account.lock().balance, 10);
+    /// ```
+    pub fn lock_owned_projected<U, F>(&self, project: F) -> ParcMutexGuard<U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let mut guard = self.lock();
+        let value = NonNull::from(project(&mut *guard));
+        // The lock is released by `ParcMutexGuard`
+        core::mem::forget(guard);
+        ParcMutexGuard {
+            // SAFETY: the raw mutex is only used to unlock the mutex locked above
+            raw: self.project(|mutex| unsafe { mutex.raw() }),
+            value,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized + Send + Sync> Parc<RwLock<T>> {
+    /// Locks the lock with shared read access and returns an owned guard to the part
+    /// of the value selected by `project`.
+    ///
+    /// The guard keeps the lock's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use parking_lot::RwLock;
+    /// use pared::sync::Parc;
+    ///
+    /// let config = Parc::new(RwLock::new((String::from("name"), 8u16)));
+    /// let name = config.read_owned_projected(|config| config.0.as_str());
+    /// drop(config);
+    /// assert_eq!(&*name, "name");
+    /// ```
+    pub fn read_owned_projected<U, F>(&self, project: F) -> ParcRwLockReadGuard<U>
+    where
+        U: ?Sized,
+        F: Projection<T, U>,
+    {
+        let guard = self.read();
+        let value = NonNull::from(project(&*guard));
+        // The lock is released by `ParcRwLockReadGuard`
+        core::mem::forget(guard);
+        ParcRwLockReadGuard {
+            // SAFETY: the raw lock is only used to unlock the lock locked above
+            raw: self.project(|lock| unsafe { lock.raw() }),
+            value,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Locks the lock with exclusive write access and returns an owned guard to the part
+    /// of the value selected by `project`.
+    ///
+    /// The guard keeps the lock's root allocation alive, so it's `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use parking_lot::RwLock;
+    /// use pared::sync::Parc;
+    ///
+    /// let config = Parc::new(RwLock::new((String::from("name"), 8u16)));
+    /// *config.write_owned_projected(|config| &mut config.1) += 1;
+    /// assert_eq!(config.read().1, 9);
+    /// ```
+    pub fn write_owned_projected<U, F>(&self, project: F) -> ParcRwLockWriteGuard<U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let mut guard = self.write();
+        let value = NonNull::from(project(&mut *guard));
+        // The lock is released by `ParcRwLockWriteGuard`
+        core::mem::forget(guard);
+        ParcRwLockWriteGuard {
+            // SAFETY: the raw lock is only used to unlock the lock locked above
+            raw: self.project(|lock| unsafe { lock.raw() }),
+            value,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// An owned guard to part of the value in a [`parking_lot::Mutex`](::parking_lot::Mutex),
+/// created by `Parc::lock_owned_projected`.
+///
+/// The mutex is unlocked when the guard is dropped.
+/// Like `parking_lot`'s own guards, the guard can't be sent to other threads.
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct ParcMutexGuard<U: ?Sized> {
+    raw: Parc<RawMutex>,
+    value: NonNull<U>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: the guard only gives out `&U` through shared references
+unsafe impl<U: ?Sized + Sync> Sync for ParcMutexGuard<U> {}
+
+impl<U: ?Sized> Deref for ParcMutexGuard<U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the mutex is locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<U: ?Sized> DerefMut for ParcMutexGuard<U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: the mutex is locked and kept alive by the guard
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<U: ?Sized> Drop for ParcMutexGuard<U> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the mutex was locked when creating the guard
+        unsafe { self.raw.unlock() }
+    }
+}
+
+impl<U: ?Sized + fmt::Debug> fmt::Debug for ParcMutexGuard<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// An owned guard to part of the value in a [`parking_lot::RwLock`](::parking_lot::RwLock)
+/// with shared read access, created by `Parc::read_owned_projected`.
+///
+/// The lock is released when the guard is dropped.
+/// Like `parking_lot`'s own guards, the guard can't be sent to other threads.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct ParcRwLockReadGuard<U: ?Sized> {
+    raw: Parc<RawRwLock>,
+    value: NonNull<U>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: the guard only gives out `&U` through shared references
+unsafe impl<U: ?Sized + Sync> Sync for ParcRwLockReadGuard<U> {}
+
+impl<U: ?Sized> Deref for ParcRwLockReadGuard<U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the lock is read-locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<U: ?Sized> Drop for ParcRwLockReadGuard<U> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the lock was read-locked when creating the guard
+        unsafe { self.raw.unlock_shared() }
+    }
+}
+
+impl<U: ?Sized + fmt::Debug> fmt::Debug for ParcRwLockReadGuard<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// An owned guard to part of the value in a [`parking_lot::RwLock`](::parking_lot::RwLock)
+/// with exclusive write access, created by `Parc::write_owned_projected`.
+///
+/// The lock is released when the guard is dropped.
+/// Like `parking_lot`'s own guards, the guard can't be sent to other threads.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct ParcRwLockWriteGuard<U: ?Sized> {
+    raw: Parc<RawRwLock>,
+    value: NonNull<U>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: the guard only gives out `&U` through shared references
+unsafe impl<U: ?Sized + Sync> Sync for ParcRwLockWriteGuard<U> {}
+
+impl<U: ?Sized> Deref for ParcRwLockWriteGuard<U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: the lock is write-locked and kept alive by the guard
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<U: ?Sized> DerefMut for ParcRwLockWriteGuard<U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: the lock is write-locked and kept alive by the guard
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<U: ?Sized> Drop for ParcRwLockWriteGuard<U> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the lock was write-locked when creating the guard
+        unsafe { self.raw.unlock_exclusive() }
+    }
+}
+
+impl<U: ?Sized + fmt::Debug> fmt::Debug for ParcRwLockWriteGuard<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, vec::Vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn mutex_guard() {
+        let state = Parc::new(Mutex::new((0u32, Vec::<u8>::new())));
+        let mut guard = state.lock_owned_projected(|state| &mut state.1);
+        guard.push(1);
+        assert!(state.try_lock().is_none());
+        assert_eq!(Parc::strong_count(&state), 2);
+        assert_eq!(format!("{:?}", guard), "[1]");
+
+        drop(guard);
+        assert_eq!(state.lock().1, [1]);
+        assert_eq!(Parc::strong_count(&state), 1);
+
+        // The guard keeps the mutex alive on its own
+        let guard = state.lock_owned_projected(|state| &mut state.0);
+        drop(state);
+        assert_eq!(*guard, 0);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn rwlock_guards() {
+        let lock = Parc::new(RwLock::new((String::from("name"), 1u8)));
+        let first = lock.read_owned_projected(|state| state.0.as_str());
+        let second = lock.read_owned_projected(|state| &state.1);
+        assert!(lock.try_write().is_none());
+        assert_eq!((&*first, *second), ("name", 1));
+        assert_eq!(format!("{:?} {:?}", first, second), "\"name\" 1");
+        drop((first, second));
+
+        let mut count = lock.write_owned_projected(|state| &mut state.1);
+        assert!(lock.try_read().is_none());
+        *count += 1;
+        assert_eq!(format!("{:?}", count), "2");
+        drop(count);
+        assert_eq!(lock.read().1, 2);
+        assert_eq!(Parc::strong_count(&lock), 1);
+    }
+}
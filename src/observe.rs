@@ -0,0 +1,331 @@
+//! Sets of weakly held observers.
+
+use alloc::vec::Vec;
+use core::{fmt, iter::FusedIterator};
+
+use crate::sync::{Parc, Weak};
+
+// Don't bother compacting before the set has at least this many entries
+const MIN_COMPACT_LEN: usize = 32;
+
+/// A set of [`Weak`] pointers to observers, which doesn't keep its observers alive.
+///
+/// Entries of dropped observers are removed while [iterating](WeakSet::iter) over the set,
+/// and when enough of them accumulate between iterations, so a set whose observers come and go
+/// doesn't keep growing.
+///
+/// The number of entries can also be capped with [`WeakSet::with_max_len`]; once the set is full
+/// of live observers, inserting another one evicts the oldest.
+///
+/// Observers are compared by their address, like [`Parc::ptr_eq`], so the same observer
+/// can't be inserted twice.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use pared::{observe::WeakSet, sync::Parc};
+///
+/// struct Listener {
+///     name: &'static str,
+///     events: AtomicU32,
+/// }
+///
+/// let mut listeners = WeakSet::new();
+/// let first = Parc::new(Listener { name: "first", events: AtomicU32::new(0) });
+/// let second = Parc::new(Listener { name: "second", events: AtomicU32::new(0) });
+/// listeners.insert(&first);
+/// listeners.insert(&second);
+///
+/// drop(second);
+/// for listener in listeners.iter() {
+///     listener.events.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// assert_eq!(first.events.load(Ordering::Relaxed), 1);
+/// // The entry of the dropped listener was removed while iterating
+/// assert_eq!(listeners.len(), 1);
+/// ```
+pub struct WeakSet<U: ?Sized> {
+    entries: Vec<Weak<U>>,
+    compact_at: usize,
+    max_len: usize,
+}
+
+impl<U: ?Sized> WeakSet<U> {
+    /// Creates an empty set without a limit on its number of entries.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            compact_at: MIN_COMPACT_LEN,
+            max_len: usize::MAX,
+        }
+    }
+
+    /// Creates an empty set that holds at most `max_len` entries.
+    ///
+    /// When inserting into a full set, the entries of dropped observers are removed first;
+    /// if all of them are still alive, the oldest entry is evicted.
+    ///
+    /// # Panics
+    /// Panics if `max_len` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{observe::WeakSet, sync::Parc};
+    ///
+    /// let mut recent = WeakSet::with_max_len(2);
+    /// let values = [Parc::new(1), Parc::new(2), Parc::new(3)];
+    /// for value in &values {
+    ///     recent.insert(value);
+    /// }
+    ///
+    /// assert!(!recent.contains(&values[0]));
+    /// let live: Vec<i32> = recent.iter().map(|value| *value).collect();
+    /// assert_eq!(live, [2, 3]);
+    /// ```
+    #[inline]
+    pub fn with_max_len(max_len: usize) -> Self {
+        assert!(
+            max_len > 0,
+            "a WeakSet must be able to hold at least one entry"
+        );
+        Self {
+            entries: Vec::new(),
+            compact_at: MIN_COMPACT_LEN.min(max_len),
+            max_len,
+        }
+    }
+
+    /// Inserts a weak pointer to `observer` into the set.
+    ///
+    /// Returns `false` if the set already contained `observer`.
+    pub fn insert(&mut self, observer: &Parc<U>) -> bool {
+        if self.contains(observer) {
+            return false;
+        }
+        if self.entries.len() >= self.compact_at {
+            self.compact();
+        }
+        if self.entries.len() >= self.max_len {
+            self.entries.remove(0);
+        }
+        self.entries.push(Parc::downgrade(observer));
+        true
+    }
+
+    /// Removes `observer` from the set, returning `true` if it was in the set.
+    pub fn remove(&mut self, observer: &Parc<U>) -> bool {
+        let observer = Parc::as_ptr(observer);
+        match self
+            .entries
+            .iter()
+            .position(|entry| core::ptr::eq(entry.as_ptr(), observer))
+        {
+            Some(index) => {
+                self.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the set contains `observer`.
+    #[inline]
+    pub fn contains(&self, observer: &Parc<U>) -> bool {
+        let observer = Parc::as_ptr(observer);
+        self.entries
+            .iter()
+            .any(|entry| core::ptr::eq(entry.as_ptr(), observer))
+    }
+
+    /// Returns an iterator over the live observers in the order they were inserted.
+    ///
+    /// The entries of dropped observers that the iterator passes are removed from the set
+    /// once the iterator is dropped.
+    #[inline]
+    pub fn iter(&mut self) -> Iter<'_, U> {
+        Iter {
+            entries: &mut self.entries,
+            read: 0,
+            write: 0,
+        }
+    }
+
+    /// Returns the number of entries in the set.
+    ///
+    /// This includes the entries of observers that have already been dropped,
+    /// but haven't been removed yet; see [`WeakSet::compact`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the set has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes the entries of all observers that have already been dropped,
+    /// returning the number of removed entries.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{observe::WeakSet, sync::Parc};
+    ///
+    /// let mut set = WeakSet::new();
+    /// let kept = Parc::new("kept");
+    /// set.insert(&kept);
+    /// set.insert(&Parc::new("dropped"));
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert_eq!(set.compact(), 1);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn compact(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.is_alive());
+        self.compact_at = MIN_COMPACT_LEN
+            .max(self.entries.len() * 2)
+            .min(self.max_len);
+        before - self.entries.len()
+    }
+
+    /// Removes all entries from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<U: ?Sized> Default for WeakSet<U> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: ?Sized> fmt::Debug for WeakSet<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakSet")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, U: ?Sized> IntoIterator for &'a mut WeakSet<U> {
+    type Item = Parc<U>;
+    type IntoIter = Iter<'a, U>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, U> {
+        self.iter()
+    }
+}
+
+/// An iterator over the live observers of a [`WeakSet`], returned by [`WeakSet::iter`].
+pub struct Iter<'a, U: ?Sized> {
+    entries: &'a mut Vec<Weak<U>>,
+    // The next entry to upgrade
+    read: usize,
+    // Live entries are moved to the front; `write..read` only holds dead entries
+    write: usize,
+}
+
+impl<U: ?Sized> Iterator for Iter<'_, U> {
+    type Item = Parc<U>;
+
+    fn next(&mut self) -> Option<Parc<U>> {
+        while self.read < self.entries.len() {
+            let upgraded = self.entries[self.read].upgrade();
+            self.read += 1;
+            if let Some(observer) = upgraded {
+                self.entries.swap(self.write, self.read - 1);
+                self.write += 1;
+                return Some(observer);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.entries.len() - self.read))
+    }
+}
+
+impl<U: ?Sized> FusedIterator for Iter<'_, U> {}
+
+impl<U: ?Sized> Drop for Iter<'_, U> {
+    fn drop(&mut self) {
+        self.entries.drain(self.write..self.read);
+    }
+}
+
+impl<U: ?Sized> fmt::Debug for Iter<'_, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Iter")
+            .field("remaining", &(self.entries.len() - self.read))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn compacts_while_iterating() {
+        let mut set = WeakSet::default();
+        let values: Vec<_> = (0..6).map(Parc::new).collect();
+        for value in &values {
+            assert!(set.insert(value));
+        }
+        assert!(!set.insert(&values[0]));
+
+        let [a, b, c, d, e, f] = <[_; 6]>::try_from(values).unwrap();
+        drop((b, d));
+        let mut iter = set.iter();
+        assert_eq!(iter.next().as_deref(), Some(&0));
+        assert_eq!(iter.next().as_deref(), Some(&2));
+        drop(iter);
+        // Only the passed dead entry was removed
+        assert_eq!(set.len(), 5);
+
+        drop(f);
+        let live: Vec<i32> = (&mut set).into_iter().map(|value| *value).collect();
+        assert_eq!(live, vec![0, 2, 4]);
+        assert_eq!(set.len(), 3);
+        assert_eq!(format!("{:?}", set), "WeakSet { len: 3, .. }");
+
+        assert!(set.remove(&c));
+        assert!(!set.remove(&c));
+        assert!(set.contains(&a) && set.contains(&e));
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn compacts_and_evicts_on_insert() {
+        let mut set = WeakSet::new();
+        for i in 0..MIN_COMPACT_LEN * 4 {
+            set.insert(&Parc::new(i));
+        }
+        assert!(set.len() <= MIN_COMPACT_LEN);
+
+        let mut bounded = WeakSet::with_max_len(2);
+        let values: Vec<_> = (0..3).map(Parc::new).collect();
+        bounded.insert(&values[0]);
+        bounded.insert(&Parc::new(10));
+        bounded.insert(&values[1]);
+        // The dead entry was removed, so nothing was evicted
+        assert!(bounded.contains(&values[0]));
+        bounded.insert(&values[2]);
+        assert!(!bounded.contains(&values[0]));
+        assert_eq!(bounded.len(), 2);
+    }
+}
@@ -0,0 +1,215 @@
+//! Field projections described by byte offsets.
+
+use core::marker::{Copy, PhantomData};
+
+/// The offset of a field of type `U` within a `T`.
+///
+/// A `FieldOffset` is plain data rather than a closure: it's `Copy`, it has the same
+/// representation as `usize`, and it can be computed at compile time with the
+/// [`offset_project!`] macro. It can be applied with [`Parc::project_offset`] and
+/// [`Prc::project_offset`].
+///
+/// # Example
+#[cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#[cfg_attr(feature = "sync", doc = "```")]
+/// use pared::{offset_project, sync::Parc, FieldOffset};
+///
+/// struct Server {
+///     port: u16,
+/// }
+///
+/// struct Config {
+///     name: &'static str,
+///     server: Server,
+/// }
+///
+/// const PORT: FieldOffset<Config, u16> = offset_project!(Config, server.port);
+///
+/// let config = Parc::new(Config {
+///     name: "pared",
+///     server: Server { port: 8080 },
+/// });
+/// let port = config.project_offset(PORT);
+/// assert_eq!(*port, 8080);
+/// ```
+///
+/// [`offset_project!`]: crate::offset_project
+/// [`Parc::project_offset`]: crate::sync::Parc::project_offset
+/// [`Prc::project_offset`]: crate::prc::Prc::project_offset
+#[repr(transparent)]
+pub struct FieldOffset<T, U> {
+    offset: usize,
+    _marker: PhantomData<fn(&T) -> &U>,
+}
+
+impl<T, U> FieldOffset<T, U> {
+    /// Creates a new `FieldOffset` from a byte offset.
+    ///
+    /// Prefer the [`offset_project!`] macro, which computes the offset safely.
+    ///
+    /// # Safety
+    /// Every `T` must contain a valid `U` starting `offset` bytes from its start.
+    ///
+    /// [`offset_project!`]: crate::offset_project
+    #[inline]
+    pub const unsafe fn new_unchecked(offset: usize) -> Self {
+        Self {
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub const unsafe fn __new_inferred<F>(offset: usize, infer: F) -> Self
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        // `infer` is only used to infer `U`; a non-capturing closure has nothing to drop
+        core::mem::forget(infer);
+        Self::new_unchecked(offset)
+    }
+
+    /// Returns the offset of the field in bytes.
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.offset
+    }
+
+    /// Applies the projection to a reference.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::offset_project;
+    ///
+    /// let second = offset_project!((u8, u32), 1);
+    /// assert_eq!(*second.apply(&(1, 2)), 2);
+    /// ```
+    #[inline]
+    pub fn apply(self, value: &T) -> &U {
+        // SAFETY: the constructors guarantee that a valid `U` lies at `offset` within every `T`
+        unsafe {
+            &*(value as *const T)
+                .cast::<u8>()
+                .add(self.offset)
+                .cast::<U>()
+        }
+    }
+}
+
+impl<T, U> Clone for FieldOffset<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U> Copy for FieldOffset<T, U> {}
+
+impl<T, U> core::fmt::Debug for FieldOffset<T, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FieldOffset").field(&self.offset).finish()
+    }
+}
+
+/// Creates a [`FieldOffset`] for a (nested) field of a `Sized` type at compile time.
+///
+/// The first argument is the root type and the second one is the path to the field,
+/// as accepted by [`core::mem::offset_of!`].
+///
+/// # Example
+/// ```
+/// use pared::{offset_project, prc::Prc};
+///
+/// struct Outer {
+///     inner: (u8, [u16; 2]),
+/// }
+///
+/// let outer = Prc::new(Outer { inner: (1, [2, 3]) });
+/// let array = outer.project_offset(offset_project!(Outer, inner.1));
+/// assert_eq!(*array, [2, 3]);
+/// ```
+///
+/// Fields behind pointers can't be reached, since they're not part of the root's allocation:
+/// ```compile_fail
+/// use pared::offset_project;
+///
+/// struct Boxed {
+///     inner: Box<(u8,)>,
+/// }
+///
+/// let offset = offset_project!(Boxed, inner.0);
+/// ```
+#[macro_export]
+macro_rules! offset_project {
+    ($root:ty, $($field:tt).+ $(,)?) => {
+        // SAFETY: `offset_of!` returns the offset of exactly the field the closure returns,
+        // and it never looks through pointers
+        unsafe {
+            $crate::FieldOffset::<$root, _>::__new_inferred(
+                ::core::mem::offset_of!($root, $($field).+),
+                |root: &$root| &root.$($field).+,
+            )
+        }
+    };
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use crate::{prc::Prc, sync::Parc};
+    use alloc::{format, string::String};
+
+    struct Inner {
+        _padding: u8,
+        name: String,
+    }
+
+    struct Root {
+        _first: u64,
+        inner: Inner,
+        pair: (u16, u32),
+    }
+
+    fn root() -> Root {
+        Root {
+            _first: 1,
+            inner: Inner {
+                _padding: 2,
+                name: String::from("inner"),
+            },
+            pair: (3, 4),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn nested_offsets() {
+        const NAME: FieldOffset<Root, String> = offset_project!(Root, inner.name);
+        let pair = offset_project!(Root, pair.1);
+
+        let root = root();
+        assert!(core::ptr::eq(NAME.apply(&root), &root.inner.name));
+        assert!(core::ptr::eq(pair.apply(&root), &root.pair.1));
+        assert_eq!(
+            format!("{:?}", NAME),
+            format!("FieldOffset({})", NAME.get())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn project_offset() {
+        let name = offset_project!(Root, inner.name);
+
+        let parc = Parc::new(root());
+        let projected = parc.project_offset(name);
+        assert_eq!(&*projected, "inner");
+        assert!(Parc::same_root(&parc, &projected));
+
+        let prc = Prc::new(root());
+        let projected = prc.project_offset(name);
+        assert_eq!(&*projected, "inner");
+        assert_eq!(Prc::strong_count(&prc), 2);
+    }
+}
@@ -0,0 +1,18 @@
+//! Locking without propagating poisoning.
+
+use std::sync::{LockResult, Mutex, MutexGuard, PoisonError};
+
+/// Locks `mutex`, ignoring whether a thread panicked while holding it.
+///
+/// The locks in this crate never run user code while they're held, so the data behind them
+/// stays valid even if they're poisoned.
+#[inline]
+pub(crate) fn lock_unpoisoned<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    unpoisoned(mutex.lock())
+}
+
+/// Returns the guard of any lock, ignoring whether it's poisoned; see [`lock_unpoisoned`].
+#[inline]
+pub(crate) fn unpoisoned<G>(result: LockResult<G>) -> G {
+    result.unwrap_or_else(PoisonError::into_inner)
+}
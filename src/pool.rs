@@ -0,0 +1,252 @@
+//! Pools of reusable allocations handed out as [`Parc`]s.
+
+use alloc::{
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use core::{marker::PhantomData, ptr::NonNull};
+use std::sync::Mutex;
+
+use crate::{
+    erased_ptr::TypeErasedPtr,
+    poison::lock_unpoisoned,
+    sync::{
+        erased_arc::{ArcErased, TypeErasedArc},
+        Parc,
+    },
+    vtable::RcVTable,
+};
+
+/// A pool of allocations that are handed out as [`Parc`]s and recycled when the last strong
+/// pointer to them is dropped.
+///
+/// This is useful for frequently allocated values like frame or packet buffers:
+/// when the last `Parc` (or any projection of it) pointing into a pooled allocation is dropped,
+/// the value is returned to the pool instead of being freed, and the next call to
+/// [`ParcPool::get_or_create`] reuses it.
+///
+/// Recycling is best-effort: an allocation is only recycled if the dropped pointer is the only
+/// remaining pointer to it, including [`Weak`](crate::sync::Weak) pointers.
+/// If the last two pointers are dropped at the same time on different threads,
+/// or if the pool is already holding [`ParcPool::max_idle`] values, the allocation is freed.
+///
+/// Pooled allocations don't keep the pool alive; values dropped after the pool are freed.
+///
+/// # Example
+/// ```
+/// use pared::{pool::ParcPool, sync::Parc};
+///
+/// let pool = ParcPool::<Vec<u8>>::new();
+///
+/// let packet = pool.get_or_create(Vec::new, |buffer| {
+///     buffer.clear();
+///     buffer.extend_from_slice(b"first");
+/// });
+/// let payload: Parc<[u8]> = packet.project(|buffer| &buffer[1..]);
+/// drop(packet);
+/// assert_eq!(pool.idle(), 0);
+///
+/// // Dropping the last pointer returns the buffer to the pool
+/// drop(payload);
+/// assert_eq!(pool.idle(), 1);
+///
+/// let packet = pool.get_or_create(Vec::new, |buffer| {
+///     assert_eq!(buffer, b"first");
+///     buffer.clear();
+///     buffer.extend_from_slice(b"second");
+/// });
+/// assert_eq!(pool.idle(), 0);
+/// ```
+pub struct ParcPool<T> {
+    inner: Arc<PoolInner<T>>,
+}
+
+struct PoolInner<T> {
+    idle: Mutex<Vec<Arc<Pooled<T>>>>,
+    max_idle: usize,
+}
+
+struct Pooled<T> {
+    value: T,
+    pool: Weak<PoolInner<T>>,
+}
+
+impl<T> ParcPool<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Creates a new, empty pool that keeps any number of idle values.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_max_idle(usize::MAX)
+    }
+
+    /// Creates a new, empty pool that keeps at most `max_idle` idle values.
+    ///
+    /// Values recycled while the pool is full are dropped.
+    #[inline]
+    pub fn with_max_idle(max_idle: usize) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                idle: Mutex::new(Vec::new()),
+                max_idle,
+            }),
+        }
+    }
+
+    /// Returns the maximum number of idle values this pool keeps.
+    #[inline]
+    pub fn max_idle(&self) -> usize {
+        self.inner.max_idle
+    }
+
+    /// Returns the number of idle values in the pool.
+    #[inline]
+    pub fn idle(&self) -> usize {
+        lock_unpoisoned(&self.inner.idle).len()
+    }
+
+    /// Returns a `Parc` pointing to a pooled value.
+    ///
+    /// If the pool has an idle value, it's reused; otherwise, a new value is created with `create`.
+    /// In both cases, `prepare` can modify the value before it's shared.
+    pub fn get_or_create<C, P>(&self, create: C, prepare: P) -> Parc<T>
+    where
+        C: FnOnce() -> T,
+        P: FnOnce(&mut T),
+    {
+        let recycled = lock_unpoisoned(&self.inner.idle).pop();
+        let mut arc = recycled.unwrap_or_else(|| {
+            Arc::new(Pooled {
+                value: create(),
+                pool: Arc::downgrade(&self.inner),
+            })
+        });
+        // Idle values are only ever referenced by the pool
+        let pooled = Arc::get_mut(&mut arc).expect("idle pooled values are unique");
+        prepare(&mut pooled.value);
+
+        let projected = NonNull::from(&arc.value);
+        // SAFETY: the vtable matches the erased Arc<Pooled<T>>,
+        // which owns the strong count we're passing to TypeErasedArc
+        let erased = unsafe {
+            TypeErasedArc::from_raw(
+                TypeErasedPtr::new(Arc::into_raw(arc)),
+                &PooledErased::<T>::VTABLE,
+            )
+        };
+        // SAFETY: projected points to the value owned by the erased Arc
+        unsafe { Parc::from_erased(erased, projected) }
+    }
+
+    /// Drops all idle values in the pool.
+    pub fn clear(&self) {
+        let idle = core::mem::take(&mut *lock_unpoisoned(&self.inner.idle));
+        drop(idle);
+    }
+}
+
+impl<T> Default for ParcPool<T>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for ParcPool<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParcPool")
+            .field("idle", &lock_unpoisoned(&self.inner.idle).len())
+            .field("max_idle", &self.inner.max_idle)
+            .finish()
+    }
+}
+
+struct PooledErased<T>(PhantomData<*const T>);
+
+impl<T: 'static> PooledErased<T> {
+    // A "vtable" for Arc<Pooled<T>> that recycles the allocation when the last pointer drops
+    const VTABLE: RcVTable = RcVTable {
+        drop: Self::drop,
+        ..ArcErased::<Pooled<T>>::VTABLE
+    };
+
+    // Must be called with an erased pointer to Arc<Pooled<T>>
+    unsafe fn drop(ptr: TypeErasedPtr) {
+        let mut arc: Arc<Pooled<T>> = Arc::from_raw(ptr.as_ptr());
+        if Arc::get_mut(&mut arc).is_none() {
+            return;
+        }
+        let Some(pool) = arc.pool.upgrade() else {
+            return;
+        };
+        let mut idle = lock_unpoisoned(&pool.idle);
+        if idle.len() < pool.max_idle {
+            idle.push(arc);
+        } else {
+            // Drop the value without holding the lock
+            drop(idle);
+            drop(arc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "weak")]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn recycles_unique_allocations() {
+        use alloc::{format, string::String};
+
+        let pool = ParcPool::<String>::default();
+        let first = pool.get_or_create(String::new, |s| s.push_str("first"));
+        let weak = Parc::downgrade(&first);
+        drop(first);
+        // The Weak pointer prevents recycling
+        assert_eq!(pool.idle(), 0);
+        assert!(weak.upgrade().is_none());
+
+        let second = pool.get_or_create(String::new, |s| s.push_str("second"));
+        let clone = second.clone();
+        drop(second);
+        assert_eq!(pool.idle(), 0);
+        let second_addr = Parc::as_ptr(&clone);
+        drop(clone);
+        assert_eq!(pool.idle(), 1);
+
+        let third = pool.get_or_create(
+            || unreachable!(),
+            |s| {
+                assert_eq!(s, "second");
+                s.clear();
+            },
+        );
+        assert_eq!(Parc::as_ptr(&third), second_addr);
+        assert_eq!(&*third, "");
+        let _ = format!("{:?}", pool);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn respects_max_idle_and_outlives_pool() {
+        let pool = ParcPool::<u32>::with_max_idle(1);
+        assert_eq!(pool.max_idle(), 1);
+        let a = pool.get_or_create(|| 1, |_| {});
+        let b = pool.get_or_create(|| 2, |_| {});
+        drop(a);
+        drop(b);
+        assert_eq!(pool.idle(), 1);
+        pool.clear();
+        assert_eq!(pool.idle(), 0);
+
+        let outliving = pool.get_or_create(|| 3, |_| {}).project(|x| x);
+        drop(pool);
+        assert_eq!(*outliving, 3);
+    }
+}
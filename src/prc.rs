@@ -4,10 +4,17 @@
 //! - [`Prc`]
 //! - [`Weak`]
 //!
+//! Other types:
+//! - [`IterProjected`]
+//! - [`SplitProjected`]
+//! - [`RawPrc`]
+//! - [`RawWeak`]
+//! - [`SendPrc`]
+//!
 //! # Example
 //! ```
 //! # use std::rc::Rc;
-//! use pared::prc::{Prc, Weak};
+//! use pared::prc::Prc;
 //! fn accepts_prc(prc: Prc<u8>) {}
 //!
 //! // Prc can be created by projecting references from an Rc
@@ -70,23 +77,39 @@
 //! println!("{}", &*z); // printing garbage, accessing `s` after it’s freed
 //! ```
 
+#[cfg(feature = "allocator-api")]
+mod allocator;
 mod erased_rc;
+#[cfg(feature = "allocator-api")]
+mod fallible;
+mod send;
 
-use alloc::rc::Rc;
+use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+#[cfg(feature = "weak")]
+use core::default::Default;
 use core::{
+    any::Any,
     clone::Clone,
     cmp::{Eq, Ord, PartialEq, PartialOrd},
     convert::{AsRef, From, Into},
     hash::Hash,
     iter::{FromIterator, IntoIterator},
-    marker::{Sized, Unpin},
-    ops::Deref,
+    marker::{Copy, Sized, Unpin},
+    mem::MaybeUninit,
     ops::FnOnce,
+    ops::{Deref, RangeBounds},
     option::{Option, Option::Some},
+    pin::Pin,
     ptr::NonNull,
 };
 
-use erased_rc::{TypeErasedRc, TypeErasedWeak};
+use crate::{
+    erased_ptr::TypeErasedPtr, vtable::RcVTable, Counts, DebugInfo, Projection, SliceError,
+};
+use erased_rc::TypeErasedRc;
+#[cfg(feature = "weak")]
+use erased_rc::TypeErasedWeak;
+pub use send::SendPrc;
 
 /// Projected reference counted pointer.
 ///
@@ -96,10 +119,12 @@ use erased_rc::{TypeErasedRc, TypeErasedWeak};
 /// This type implements most of `Rc`'s API surface, with the exception of operations that require
 /// access to the original `Rc`'s type, which is unavailable from this type.
 ///
+/// Like `Rc<T>`, `Prc<T>` is covariant in `T`, and `Option<Prc<T>>` is the same size as `Prc<T>`.
+///
 /// # Example
 /// ```
 /// # use std::rc::Rc;
-/// use pared::prc::{Prc, Weak};
+/// use pared::prc::Prc;
 /// fn accepts_prc(prc: Prc<u8>) {}
 ///
 /// // Prc can be created by projecting references from an Rc
@@ -115,6 +140,31 @@ use erased_rc::{TypeErasedRc, TypeErasedWeak};
 /// }
 /// ```
 ///
+/// # Lifetimes
+///
+/// A `Prc` doesn't know the type of its root, so it can't carry the lifetimes the root
+/// borrows from either. Roots and projected types must therefore be `'static`;
+/// see [`Parc`'s documentation](crate::sync::Parc#lifetimes) for details and for
+/// a pattern replacing borrows between parts of the same root.
+///
+/// ```compile_fail,E0597
+/// use std::rc::Rc;
+/// use pared::prc::Prc;
+///
+/// struct Parser<'a> {
+///     input: &'a str,
+///     depth: u8,
+/// }
+///
+/// let depth: Prc<u8>;
+/// {
+///     let input = String::from("[[]]");
+///     let parser = Rc::new(Parser { input: &input, depth: 2 });
+///     // Error: `input` doesn't live long enough
+///     depth = Prc::from_rc(&parser, |parser| &parser.depth);
+/// }
+/// ```
+///
 /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
 pub struct Prc<T: ?Sized> {
     rc: TypeErasedRc,
@@ -135,11 +185,158 @@ where
     pub fn new(value: T) -> Prc<T> {
         Rc::new(value).into()
     }
+
+    /// Constructs a new `Pin<Prc<T>>`.
+    ///
+    /// The value is never moved while it's stored in the root `Rc`, and a `Pin<Prc<T>>`
+    /// never gives out mutable access to it, so it stays pinned until it's dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use core::{marker::PhantomPinned, pin::Pin};
+    /// use pared::prc::Prc;
+    ///
+    /// struct Intrusive {
+    ///     value: u32,
+    ///     _pinned: PhantomPinned,
+    /// }
+    ///
+    /// let pinned: Pin<Prc<Intrusive>> = Prc::pin(Intrusive {
+    ///     value: 5,
+    ///     _pinned: PhantomPinned,
+    /// });
+    /// assert_eq!(pinned.value, 5);
+    /// ```
+    #[inline]
+    pub fn pin(value: T) -> Pin<Prc<T>> {
+        // SAFETY: the value is never moved out of its allocation and only dropped in place
+        unsafe { Pin::new_unchecked(Prc::new(value)) }
+    }
+
+    /// Constructs a new `Prc<T>` while giving you a [`Weak<T>`] to the allocation,
+    /// to allow you to construct a `T` which holds a weak pointer to itself.
+    ///
+    /// This mirrors [`Rc::new_cyclic`]: calling [`Weak::upgrade`] on the weak pointer
+    /// inside `data_fn` returns `None`, and the weak pointer becomes upgradable only after
+    /// `new_cyclic` returns.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::{Prc, Weak};
+    ///
+    /// struct Node {
+    ///     name: String,
+    ///     this: Weak<Node>,
+    /// }
+    ///
+    /// let node = Prc::new_cyclic(|this| {
+    ///     assert!(this.upgrade().is_none());
+    ///     Node {
+    ///         name: "root".to_owned(),
+    ///         this: this.clone(),
+    ///     }
+    /// });
+    /// let this = node.this.upgrade().unwrap();
+    /// assert!(Prc::ptr_eq(&node, &this));
+    /// ```
+    ///
+    /// [`Rc::new_cyclic`]: alloc::rc::Rc::new_cyclic
+    #[cfg(feature = "weak")]
+    pub fn new_cyclic<F>(data_fn: F) -> Prc<T>
+    where
+        F: FnOnce(&Weak<T>) -> T,
+    {
+        Prc::from_root(Rc::new_cyclic(|weak| {
+            // SAFETY: the allocation already exists, so the pointer to its data isn't null
+            let projected = unsafe { NonNull::new_unchecked(weak.as_ptr() as *mut T) };
+            let weak = Weak {
+                weak: TypeErasedWeak::new(weak.clone()),
+                projected,
+            };
+            data_fn(&weak)
+        }))
+    }
+
+    /// Constructs a new `Prc` with uninitialized contents.
+    ///
+    /// The value can be written in place with [`Prc::get_mut`], and the `Prc` then converted
+    /// with [`Prc::assume_init`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let mut five = Prc::<u32>::new_uninit();
+    /// Prc::get_mut(&mut five).unwrap().write(5);
+    /// let five = unsafe { five.assume_init() };
+    /// assert_eq!(*five, 5);
+    /// ```
+    pub fn new_uninit() -> Prc<MaybeUninit<T>> {
+        Prc::from_root(Rc::new_uninit())
+    }
+
+    /// Constructs a new `Prc` with uninitialized contents, with the memory being filled
+    /// with `0` bytes.
+    ///
+    /// See [`MaybeUninit::zeroed`] for examples of correct and incorrect usage of this method.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let buffer = Prc::<[u8; 4096]>::new_zeroed();
+    /// let buffer = unsafe { buffer.assume_init() };
+    /// assert!(buffer.iter().all(|&byte| byte == 0));
+    /// ```
+    ///
+    /// [`MaybeUninit::zeroed`]: core::mem::MaybeUninit::zeroed
+    pub fn new_zeroed() -> Prc<MaybeUninit<T>> {
+        let mut rc = Rc::<T>::new_uninit();
+        let value = Rc::get_mut(&mut rc).expect("a new Rc is unique");
+        // SAFETY: the pointer comes from a unique reference, so it's valid for writes
+        unsafe { value.as_mut_ptr().write_bytes(0, 1) };
+        Prc::from_root(rc)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Prc<T>
+where
+    T: zeroize::Zeroize + 'static,
+{
+    /// Constructs a new `Prc<T>` that zeroizes `value` when the last pointer to it is dropped.
+    ///
+    /// The value is stored wrapped in [`zeroize::Zeroizing`], so any projections
+    /// from the returned `Prc` keep the wipe-on-drop behavior of the whole allocation.
+    ///
+    /// Note that this only applies to the allocation backing the `Prc`: dropping a single `Prc`
+    /// doesn't wipe anything while other pointers to the same allocation exist. Projections from
+    /// `Rc`s holding a [`zeroize::ZeroizeOnDrop`] type behave the same way, as the allocation is
+    /// always dropped as its original type.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let key = Prc::new_zeroizing([0x42u8; 32]);
+    /// let first_half = key.project(|k| &k[..16]);
+    /// drop(key);
+    /// // The key is wiped only after `first_half` is dropped as well
+    /// assert_eq!(&*first_half, &[0x42u8; 16]);
+    /// ```
+    pub fn new_zeroizing(value: T) -> Prc<T> {
+        Prc::from_root(Rc::new(zeroize::Zeroizing::new(value))).project(|value| &**value)
+    }
 }
 
 impl<T: ?Sized> Prc<T> {
     /// Constructs a new `Prc<T>` from an existing `Rc<T>` by projecting a field.
     ///
+    /// The `Rc` is only borrowed and cloned internally, so code holding a `&Rc<U>`,
+    /// like handlers that receive one, can project from it without cloning it first.
+    /// An owned `Rc` can be converted without touching its reference count
+    /// with `Prc::from`, and then projected.
+    ///
     /// # Panics
     /// If `f` panics, the panic is propagated to the caller and the rc won't be cloned.
     ///
@@ -162,13 +359,13 @@ impl<T: ?Sized> Prc<T> {
     #[inline]
     pub fn from_rc<U, F>(rc: &Rc<U>, project: F) -> Self
     where
-        U: ?Sized,
+        U: ?Sized + 'static,
         T: 'static,
-        F: FnOnce(&U) -> &T,
+        F: Projection<U, T>,
     {
         let projected = project(rc);
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const T as *mut T) };
         Self {
             rc: TypeErasedRc::new(rc.clone()),
@@ -204,13 +401,13 @@ impl<T: ?Sized> Prc<T> {
     #[inline]
     pub fn try_from_rc<U, E, F>(rc: &Rc<U>, project: F) -> Result<Self, E>
     where
-        U: ?Sized,
+        U: ?Sized + 'static,
         T: 'static,
         F: FnOnce(&U) -> Result<&T, E>,
     {
         let projected = project(rc)?;
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const T as *mut T) };
         Ok(Self {
             rc: TypeErasedRc::new(rc.clone()),
@@ -230,6 +427,16 @@ impl<T: ?Sized> Prc<T> {
     /// let projected = prc.project(|tuple| &tuple.0);
     /// ```
     ///
+    /// The projection can be any closure, including one capturing its environment,
+    /// such as an index computed beforehand:
+    /// ```
+    /// use pared::prc::Prc;
+    /// let prc = Prc::new(vec![3, 1, 4, 1, 5]);
+    /// let index = prc.iter().position(|&x| x == 4).unwrap();
+    /// let four = prc.project(|v| &v[index]);
+    /// assert_eq!(*four, 4);
+    /// ```
+    ///
     /// Note that references to local variables cannot be returned from the `project` function:
     /// ```compile_fail,E0597
     /// use pared::prc::Prc;
@@ -241,11 +448,11 @@ impl<T: ?Sized> Prc<T> {
     pub fn project<U, F>(&self, project: F) -> Prc<U>
     where
         U: ?Sized + 'static,
-        F: FnOnce(&T) -> &U,
+        F: Projection<T, U>,
     {
         let projected = project(self);
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const U as *mut U) };
         Prc::<U> {
             rc: self.rc.clone(),
@@ -284,8 +491,8 @@ impl<T: ?Sized> Prc<T> {
         F: FnOnce(&T) -> Result<&U, E>,
     {
         let projected = project(self)?;
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const U as *mut U) };
         Ok(Prc::<U> {
             rc: self.rc.clone(),
@@ -293,6 +500,204 @@ impl<T: ?Sized> Prc<T> {
         })
     }
 
+    /// Constructs a new `Prc<U>` from an existing `Prc<T>` by projecting to the field at `offset`.
+    ///
+    /// See [`FieldOffset`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{offset_project, prc::Prc};
+    ///
+    /// let prc = Prc::new((1u8, 2u32));
+    /// let projected = prc.project_offset(offset_project!((u8, u32), 1));
+    /// assert_eq!(*projected, 2);
+    /// ```
+    ///
+    /// [`FieldOffset`]: crate::FieldOffset
+    #[inline]
+    pub fn project_offset<U>(&self, offset: crate::FieldOffset<T, U>) -> Prc<U>
+    where
+        T: Sized,
+        U: 'static,
+    {
+        self.project(|value| offset.apply(value))
+    }
+
+    /// Constructs a new `Prc<T>` pointing to `'static` data, without allocating.
+    ///
+    /// The returned `Prc` (and any projections and [`Weak`] pointers created from it) doesn't
+    /// count references at all, as the data is never freed: [`Prc::strong_count`] reports
+    /// `usize::MAX`, [`Prc::weak_count`] reports `0` and [`Weak::upgrade`] always succeeds.
+    /// This allows mixing literals and heap-allocated data behind a single `Prc<T>` type.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let names: Vec<Prc<str>> = vec![
+    ///     Prc::from_static("literal"),
+    ///     Prc::new(String::from("heap")).project(|s| s.as_str()),
+    /// ];
+    /// assert_eq!(&*names[0], "literal");
+    /// assert_eq!(&*names[1], "heap");
+    /// assert_eq!(Prc::root_allocation_size(&names[0]), 0);
+    /// ```
+    #[inline]
+    pub fn from_static(value: &'static T) -> Prc<T> {
+        Self {
+            rc: TypeErasedRc::from_static(value),
+            projected: NonNull::from(value),
+        }
+    }
+
+    /// Constructs a new `Prc<T>` pointing to the whole `rc`.
+    #[inline]
+    fn from_root(rc: Rc<T>) -> Self
+    where
+        T: 'static,
+    {
+        let projected = NonNull::from(&*rc);
+        Self {
+            rc: TypeErasedRc::new(rc),
+            projected,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the type stored in the root `Rc` backing this `Prc`.
+    ///
+    /// The type of the root is known for every `Prc` backed by an `Rc` using the global
+    /// allocator, including those created from borrowed `Rc`s with [`Prc::from_rc`].
+    /// `Prc`s created with [`Prc::from_static`] don't have a root allocation,
+    /// so they return `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::any::TypeId;
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new((1u8, 2u16));
+    /// let second = prc.project(|pair| &pair.1);
+    /// assert_eq!(Prc::root_type_id(&second), Some(TypeId::of::<(u8, u16)>()));
+    /// ```
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    #[inline]
+    pub fn root_type_id(this: &Self) -> Option<core::any::TypeId> {
+        this.rc.type_id()
+    }
+
+    /// Returns the name of the type stored in the root allocation backing this `Prc`,
+    /// as returned by [`core::any::type_name`].
+    ///
+    /// Unlike [`Prc::root_type_id`], the name is known for every root. It's meant for
+    /// diagnostics only, such as finding out what a leaked pointer keeps alive.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new((5u8, String::from("five")));
+    /// let name = prc.project(|pair| &pair.1);
+    /// assert_eq!(Prc::root_type_name(&name), "(u8, alloc::string::String)");
+    /// ```
+    #[cfg(feature = "type-names")]
+    #[inline]
+    pub fn root_type_name(this: &Self) -> &'static str {
+        this.rc.type_name()
+    }
+
+    /// Returns the size in bytes of the root allocation kept alive by this `Prc`.
+    ///
+    /// This includes the whole value stored in the root `Rc`, regardless of what this `Prc`
+    /// projects to, and the reference counts stored alongside it. The layout of `Rc`'s
+    /// allocation isn't guaranteed by the standard library, so the returned size is an estimate
+    /// based on its current implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new([0u8; 1024]);
+    /// let byte = prc.project(|array| &array[0]);
+    /// assert!(Prc::root_allocation_size(&byte) >= 1024);
+    /// ```
+    #[inline]
+    pub fn root_allocation_size(this: &Self) -> usize {
+        this.rc.root_layout().size()
+    }
+
+    /// Returns a snapshot of this `Prc`'s addresses, reference counts and root type,
+    /// for logging the state of a pointer while debugging.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new((0u32, [1u8; 4]));
+    /// let projected = prc.project(|pair| &pair.1[2]);
+    ///
+    /// let info = Prc::debug_info(&projected);
+    /// assert_eq!(info.counts.strong, 2);
+    /// assert_eq!(info.offset, Some(6));
+    /// assert_eq!(info.projected_addr, Prc::as_ptr(&projected) as usize);
+    /// println!("{info:?}");
+    /// ```
+    pub fn debug_info(this: &Self) -> DebugInfo {
+        DebugInfo::new(
+            this.rc.as_ptr() as usize,
+            Prc::as_ptr(this) as *const () as usize,
+            Prc::root_allocation_size(this),
+            Prc::counts(this),
+            Prc::root_type_id(this),
+            #[cfg(feature = "type-names")]
+            Prc::root_type_name(this),
+        )
+    }
+
+    /// Constructs a new `Pin<Prc<U>>` from an existing `Pin<Prc<T>>` by projecting a field.
+    ///
+    /// This is the `Prc` equivalent of [`Pin::map_unchecked`].
+    ///
+    /// # Safety
+    /// The projected value must be structurally pinned in `T`: `T` must never move it
+    /// while `T` is pinned, including in its [`Drop`] implementation.
+    /// See the [`pin` module documentation](core::pin#projections-and-structural-pinning)
+    /// for details.
+    ///
+    /// # Example
+    /// ```
+    /// use core::{marker::PhantomPinned, pin::Pin};
+    /// use pared::prc::Prc;
+    ///
+    /// struct Intrusive {
+    ///     _pinned: PhantomPinned,
+    /// }
+    ///
+    /// struct Outer {
+    ///     inner: Intrusive,
+    /// }
+    ///
+    /// let outer = Prc::pin(Outer {
+    ///     inner: Intrusive { _pinned: PhantomPinned },
+    /// });
+    /// // SAFETY: `Outer` never moves `inner`
+    /// let inner: Pin<Prc<Intrusive>> =
+    ///     unsafe { Prc::project_pinned(outer, |outer| &outer.inner) };
+    /// ```
+    ///
+    /// [`Pin::map_unchecked`]: core::pin::Pin::map_unchecked
+    #[inline]
+    pub unsafe fn project_pinned<U, F>(this: Pin<Self>, project: F) -> Pin<Prc<U>>
+    where
+        U: ?Sized + 'static,
+        F: Projection<T, U>,
+    {
+        // SAFETY: the `Prc` is only used to create a projection, which gets pinned again
+        let prc = unsafe { Pin::into_inner_unchecked(this) };
+        // SAFETY: the caller guarantees that the projected value is structurally pinned
+        unsafe { Pin::new_unchecked(prc.project(project)) }
+    }
+
     /// Provides a raw pointer to the data.
     ///
     /// The counts are not affected in any way and the `Prc` is not consumed. The pointer is valid for
@@ -314,6 +719,116 @@ impl<T: ?Sized> Prc<T> {
         NonNull::as_ptr(this.projected)
     }
 
+    /// Consumes the `Prc`, returning its raw parts.
+    ///
+    /// To avoid a memory leak, the parts have to be converted back to a `Prc` using
+    /// [`Prc::from_raw`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let x = Prc::new("hello".to_owned());
+    /// let raw = Prc::into_raw(x);
+    /// assert_eq!(unsafe { &*raw.as_ptr() }, "hello");
+    ///
+    /// // SAFETY: `raw` comes from `Prc::into_raw`
+    /// let x = unsafe { Prc::from_raw(raw) };
+    /// assert_eq!(&*x, "hello");
+    /// ```
+    #[must_use = "losing the raw parts will leak the underlying allocation"]
+    pub fn into_raw(this: Self) -> RawPrc<T> {
+        let (root, vtable) = this.rc.into_raw();
+        RawPrc {
+            projected: this.projected,
+            root,
+            vtable,
+        }
+    }
+
+    /// Constructs a `Prc<T>` from raw parts obtained from [`Prc::into_raw`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Prc::into_raw`] for a `Prc<T>` with the same `T`.
+    /// Each call to `from_raw` takes ownership of one strong count: it must be balanced
+    /// either by the call to `into_raw` that produced `raw`, or by a call to
+    /// [`Prc::increment_strong_count`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let raw = Prc::into_raw(Prc::new((5u8, 6u16)).project(|t| &t.1));
+    /// // SAFETY: `raw` comes from `Prc::into_raw`
+    /// let prc = unsafe { Prc::from_raw(raw) };
+    /// assert_eq!(*prc, 6);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw(raw: RawPrc<T>) -> Self {
+        Self {
+            rc: TypeErasedRc::from_raw(raw.root, raw.vtable),
+            projected: raw.projected,
+        }
+    }
+
+    /// Increments the strong count of the allocation behind `raw` by one.
+    ///
+    /// See [`Rc::increment_strong_count`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Prc::into_raw`] and the allocation it refers to
+    /// must still be alive (the strong count must be at least 1) for the duration of this call.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let raw = Prc::into_raw(Prc::new(5));
+    /// unsafe {
+    ///     Prc::increment_strong_count(raw);
+    ///
+    ///     let first = Prc::from_raw(raw);
+    ///     assert_eq!(Prc::strong_count(&first), 2);
+    ///     let second = Prc::from_raw(raw);
+    ///     drop(second);
+    ///     assert_eq!(Prc::strong_count(&first), 1);
+    /// }
+    /// ```
+    ///
+    /// [`Rc::increment_strong_count`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.increment_strong_count
+    #[inline]
+    pub unsafe fn increment_strong_count(raw: RawPrc<T>) {
+        (raw.vtable.clone)(raw.root)
+    }
+
+    /// Decrements the strong count of the allocation behind `raw` by one,
+    /// dropping it if this was the last strong reference.
+    ///
+    /// See [`Rc::decrement_strong_count`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Prc::into_raw`] and the strong count it releases
+    /// must be owned by the caller, either from [`Prc::into_raw`] or from
+    /// [`Prc::increment_strong_count`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new(5);
+    /// let raw = Prc::into_raw(prc.clone());
+    /// assert_eq!(Prc::strong_count(&prc), 2);
+    ///
+    /// unsafe { Prc::decrement_strong_count(raw) };
+    /// assert_eq!(Prc::strong_count(&prc), 1);
+    /// ```
+    ///
+    /// [`Rc::decrement_strong_count`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.decrement_strong_count
+    #[inline]
+    pub unsafe fn decrement_strong_count(raw: RawPrc<T>) {
+        (raw.vtable.drop)(raw.root)
+    }
+
     /// Creates a new `Weak` pointer to this allocation.
     ///
     /// This `Weak` pointer is tied to strong references to the original `Rc`, meaning it's not
@@ -331,6 +846,7 @@ impl<T: ?Sized> Prc<T> {
     /// let stored = weak.upgrade().map(|prc| *prc);
     /// assert_eq!(stored, Some(42));
     /// ```
+    #[cfg(feature = "weak")]
     pub fn downgrade(this: &Prc<T>) -> Weak<T> {
         Weak::<T> {
             weak: this.rc.downgrade(),
@@ -343,7 +859,8 @@ impl<T: ?Sized> Prc<T> {
     /// See [`Rc::weak_count`].
     ///
     /// # Example
-    /// ```
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
     /// use pared::prc::Prc;
     /// let six = Prc::new(6);
     /// let _weak_six = Prc::downgrade(&six);
@@ -376,35 +893,871 @@ impl<T: ?Sized> Prc<T> {
         this.rc.strong_count()
     }
 
-    /// Returns `true` if the two `Prc`s point to the same data, using [`core::ptr::eq`].
-    /// See that function for caveats when comparing `dyn Trait` pointers.
+    /// Gets both the number of strong and [`Weak`] pointers to this allocation.
     ///
     /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
+    /// use pared::{prc::Prc, Counts};
+    /// let six = Prc::new(6);
+    /// let _also_six = six.clone();
+    /// let _weak_six = Prc::downgrade(&six);
+    ///
+    /// assert_eq!(Prc::counts(&six), Counts { strong: 2, weak: 1 });
     /// ```
+    #[inline]
+    pub fn counts(this: &Prc<T>) -> Counts {
+        Counts {
+            strong: Prc::strong_count(this),
+            weak: Prc::weak_count(this),
+        }
+    }
+
+    /// Returns `true` if this is the only `Prc` to its allocation and no [`Weak`] pointers to it
+    /// exist, checking both counts at once.
+    ///
+    /// `Prc`s to static data are never unique.
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
     /// use pared::prc::Prc;
+    /// let pair = Prc::new((1, 2));
+    /// let second = pair.project(|pair| &pair.1);
+    /// assert!(!Prc::is_unique(&second));
     ///
-    /// let five = Prc::new(5);
-    /// let same_five = five.clone();
-    /// let other_five = Prc::new(5);
+    /// drop(pair);
+    /// assert!(Prc::is_unique(&second));
     ///
-    /// assert!(Prc::ptr_eq(&five, &same_five));
-    /// assert!(!Prc::ptr_eq(&five, &other_five));
+    /// let _weak = Prc::downgrade(&second);
+    /// assert!(!Prc::is_unique(&second));
     /// ```
-    ///
-    /// [`core::ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
-    pub fn ptr_eq(this: &Prc<T>, other: &Prc<T>) -> bool {
-        core::ptr::eq(this.projected.as_ptr(), other.projected.as_ptr())
-    }
-}
-
-impl<T: ?Sized> AsRef<T> for Prc<T> {
     #[inline]
-    fn as_ref(&self) -> &T {
-        self.deref()
+    pub fn is_unique(this: &Prc<T>) -> bool {
+        this.rc.is_unique()
     }
-}
 
-impl<T: ?Sized> core::borrow::Borrow<T> for Prc<T> {
+    /// Returns a copy of the projected value.
+    ///
+    /// This is handy for small `Copy` values like ids and flags, which are cheaper to copy
+    /// once than to keep dereferencing.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let entry = Prc::new((7u32, "seven")).project(|entry| &entry.0);
+    /// let id = Prc::get(&entry);
+    /// assert_eq!(id, 7);
+    /// ```
+    #[inline]
+    pub fn get(this: &Prc<T>) -> T
+    where
+        T: Copy,
+    {
+        **this
+    }
+
+    /// Returns a copy of the projected value, like [`Option::copied`].
+    ///
+    /// This is the same as [`Prc::get`], and reads better in iterator chains.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let pairs = Prc::new([(1u8, 'a'), (2, 'b')]);
+    /// let numbers = [pairs.project(|p| &p[0].0), pairs.project(|p| &p[1].0)];
+    /// let sum: u8 = numbers.iter().map(Prc::copied).sum();
+    /// assert_eq!(sum, 3);
+    /// ```
+    #[inline]
+    pub fn copied(this: &Prc<T>) -> T
+    where
+        T: Copy,
+    {
+        **this
+    }
+
+    /// Returns a clone of the projected value, like [`Option::cloned`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let user = Prc::new((1u32, String::from("ferris"))).project(|user| &user.1);
+    /// let name: String = Prc::cloned(&user);
+    /// assert_eq!(name, "ferris");
+    /// ```
+    #[inline]
+    pub fn cloned(this: &Prc<T>) -> T
+    where
+        T: Clone,
+    {
+        T::clone(this)
+    }
+
+    /// Returns `true` if the two `Prc`s point to the same data, using [`core::ptr::eq`].
+    /// See that function for caveats when comparing `dyn Trait` pointers.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let five = Prc::new(5);
+    /// let same_five = five.clone();
+    /// let other_five = Prc::new(5);
+    ///
+    /// assert!(Prc::ptr_eq(&five, &same_five));
+    /// assert!(!Prc::ptr_eq(&five, &other_five));
+    /// ```
+    ///
+    /// [`core::ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
+    pub fn ptr_eq(this: &Prc<T>, other: &Prc<T>) -> bool {
+        core::ptr::eq(this.projected.as_ptr(), other.projected.as_ptr())
+    }
+
+    /// Returns `true` if the two `Prc`s are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// Unlike [`Prc::ptr_eq`], this compares the underlying `Rc`s, which is why it can compare
+    /// `Prc`s of different types.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let tuple = Prc::new((5u8, 6u16));
+    /// let first = tuple.project(|t| &t.0);
+    /// let second = tuple.project(|t| &t.1);
+    /// let other = Prc::new((5u8, 6u16));
+    ///
+    /// assert!(Prc::same_root(&first, &second));
+    /// assert!(!Prc::same_root(&first, &other));
+    /// ```
+    #[inline]
+    pub fn same_root<U: ?Sized>(this: &Prc<T>, other: &Prc<U>) -> bool {
+        core::ptr::eq(Prc::root_ptr(this), Prc::root_ptr(other))
+    }
+
+    /// Returns `true` if this `Prc` is backed by the allocation of `rc`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use pared::prc::Prc;
+    ///
+    /// let rc = Rc::new((5u8, 6u16));
+    /// let prc = Prc::from_rc(&rc, |t| &t.1);
+    ///
+    /// assert!(Prc::is_rooted_in(&prc, &rc));
+    /// assert!(!Prc::is_rooted_in(&prc, &Rc::new(5u8)));
+    /// ```
+    #[inline]
+    pub fn is_rooted_in<U: ?Sized>(this: &Prc<T>, rc: &Rc<U>) -> bool {
+        core::ptr::eq(Prc::root_ptr(this), Rc::as_ptr(rc) as *const ())
+    }
+
+    /// Returns the address of the allocation backing this `Prc`.
+    #[inline]
+    pub(crate) fn root_ptr(this: &Prc<T>) -> *const () {
+        this.rc.as_ptr()
+    }
+
+    /// Consumes the `Prc`, returning a reference that lives for the rest of the program.
+    ///
+    /// The allocation backing the `Prc` is never freed, and its value is never dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let name: &'static str = Prc::leak(Prc::new(String::from("pared")).project(|s| s.as_str()));
+    /// assert_eq!(name, "pared");
+    /// ```
+    #[inline]
+    pub fn leak(this: Prc<T>) -> &'static T
+    where
+        T: 'static,
+    {
+        let Prc { rc, projected } = this;
+        core::mem::forget(rc);
+        // SAFETY: the root is kept alive forever, since its strong count is never released
+        unsafe { projected.as_ref() }
+    }
+}
+
+impl<T> Prc<T> {
+    /// Returns a mutable reference into the given `Prc`, if there are no other `Prc`
+    /// or [`Weak`] pointers to the same allocation and `this` points at the root value itself,
+    /// which must have been created with [`Prc::new`] or from an `Rc<T>`.
+    ///
+    /// Projections can't be mutated in place, because the projecting function only promised
+    /// shared access. This includes projections to a field that happens to start at the root,
+    /// such as the only field of a newtype. In that case (and whenever the allocation is shared)
+    /// `None` is returned.
+    ///
+    /// See [`Rc::get_mut`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let mut x = Prc::new(3);
+    /// *Prc::get_mut(&mut x).unwrap() = 4;
+    /// assert_eq!(*x, 4);
+    ///
+    /// let _y = x.clone();
+    /// assert!(Prc::get_mut(&mut x).is_none());
+    ///
+    /// let mut first = Prc::new((1, 2)).project(|pair| &pair.1);
+    /// assert!(Prc::get_mut(&mut first).is_none());
+    ///
+    /// struct Wrapper(u32);
+    /// let mut inner = Prc::new(Wrapper(5)).project(|wrapper| &wrapper.0);
+    /// assert!(Prc::get_mut(&mut inner).is_none());
+    /// ```
+    ///
+    /// [`Rc::get_mut`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.get_mut
+    pub fn get_mut(this: &mut Prc<T>) -> Option<&mut T>
+    where
+        T: 'static,
+    {
+        match this.rc.root_ptr_as::<T>() {
+            Some(root) if core::ptr::eq(root, this.projected.as_ptr()) && this.rc.is_unique() => {
+                // SAFETY: this is the only pointer to the allocation, and it points at the root
+                // value itself, which the `Rc` owns and nothing else can be borrowing.
+                // The root pointer is used (rather than `projected`) since it came from the `Rc`
+                // and allows writes.
+                Some(unsafe { &mut *(root.cast_mut()) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Makes a mutable reference into the given `Prc`.
+    ///
+    /// If [`Prc::get_mut`] would return `None`, the projected value is cloned into a new
+    /// allocation first, and `this` is replaced with a `Prc` pointing to it (clone-on-write).
+    ///
+    /// See [`Rc::make_mut`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let mut data = Prc::new(5);
+    /// *Prc::make_mut(&mut data) += 1; // Won't clone anything
+    /// let mut other_data = data.clone();
+    /// *Prc::make_mut(&mut data) += 1; // Clones inner data
+    ///
+    /// assert_eq!(*data, 7);
+    /// assert_eq!(*other_data, 6);
+    ///
+    /// let mut second = Prc::new((1, 2)).project(|pair| &pair.1);
+    /// *Prc::make_mut(&mut second) += 1; // Clones the projected field
+    /// assert_eq!(*second, 3);
+    /// ```
+    ///
+    /// [`Rc::make_mut`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.make_mut
+    pub fn make_mut(this: &mut Prc<T>) -> &mut T
+    where
+        T: Clone + 'static,
+    {
+        if Prc::get_mut(this).is_none() {
+            *this = Prc::new(T::clone(this));
+        }
+        Prc::get_mut(this).expect("a freshly allocated Prc is unique")
+    }
+
+    /// Returns the inner value, if the `Prc` has exactly one strong reference
+    /// and points at the whole root value.
+    ///
+    /// Otherwise, an [`Err`] is returned with the same `Prc` that was passed in. This happens
+    /// when other `Prc`s to the same allocation exist, when `this` is a projection,
+    /// or when the type of the root isn't known (see [`Prc::root_type_id`]).
+    ///
+    /// See [`Rc::try_unwrap`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let x = Prc::new(3);
+    /// assert_eq!(Prc::try_unwrap(x), Ok(3));
+    ///
+    /// let x = Prc::new(4);
+    /// let _y = Prc::clone(&x);
+    /// assert_eq!(*Prc::try_unwrap(x).unwrap_err(), 4);
+    ///
+    /// let second = Prc::new((1, 2)).project(|pair| &pair.1);
+    /// assert_eq!(*Prc::try_unwrap(second).unwrap_err(), 2);
+    /// ```
+    ///
+    /// [`Rc::try_unwrap`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.try_unwrap
+    pub fn try_unwrap(this: Prc<T>) -> Result<T, Prc<T>>
+    where
+        T: 'static,
+    {
+        let Prc { rc, projected } = this;
+        match rc.root_ptr_as::<T>() {
+            Some(root) if core::ptr::eq(root, projected.as_ptr()) => {
+                let _ = rc.into_raw();
+                // SAFETY: the erased root is an Rc<T>,
+                // whose strong count is now owned by the `Rc` being reconstructed
+                let rc = unsafe { Rc::from_raw(root) };
+                Rc::try_unwrap(rc).map_err(Prc::from_root)
+            }
+            _ => Err(Prc { rc, projected }),
+        }
+    }
+
+    /// Returns the inner value, if the `Prc` has exactly one strong reference
+    /// and points at the whole root value.
+    ///
+    /// Otherwise, `None` is returned and the `Prc` is dropped. Unlike [`Prc::try_unwrap`],
+    /// this guarantees that exactly one of the `Prc`s to a root value returns it, when
+    /// each of them calls `into_inner` without projecting.
+    ///
+    /// See [`Rc::into_inner`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let x = Prc::new(3);
+    /// let y = Prc::clone(&x);
+    ///
+    /// assert_eq!(Prc::into_inner(x), None);
+    /// assert_eq!(Prc::into_inner(y), Some(3));
+    /// ```
+    ///
+    /// [`Rc::into_inner`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.into_inner
+    #[inline]
+    pub fn into_inner(this: Prc<T>) -> Option<T>
+    where
+        T: 'static,
+    {
+        Prc::try_unwrap(this).ok()
+    }
+}
+
+impl<T: ?Sized> Prc<T> {
+    /// Moves the root of this `Prc` into one pointing to the reference returned by `project`,
+    /// without touching the reference counts.
+    #[inline]
+    fn map_owned<U, F>(self, project: F) -> Prc<U>
+    where
+        U: ?Sized + 'static,
+        F: for<'x> FnOnce(&'x T) -> &'x U,
+    {
+        let Prc { rc, projected } = self;
+        // SAFETY: `rc` keeps the allocation alive for at least the duration of this function
+        let projected = project(unsafe { projected.as_ref() });
+        Prc {
+            rc,
+            projected: NonNull::from(projected),
+        }
+    }
+
+    /// Like [`Prc::map_owned`], but `project` can return a reference to something else
+    /// instead, which the root is moved into.
+    #[inline]
+    fn try_map_owned<U, E, F>(self, project: F) -> Result<Prc<U>, Prc<E>>
+    where
+        U: ?Sized + 'static,
+        E: ?Sized + 'static,
+        F: for<'x> FnOnce(&'x T) -> Result<&'x U, &'x E>,
+    {
+        let Prc { rc, projected } = self;
+        // SAFETY: `rc` keeps the allocation alive for at least the duration of this function
+        match project(unsafe { projected.as_ref() }) {
+            Ok(value) => Ok(Prc {
+                rc,
+                projected: NonNull::from(value),
+            }),
+            Err(error) => Err(Prc {
+                rc,
+                projected: NonNull::from(error),
+            }),
+        }
+    }
+}
+
+impl<T> Prc<Option<T>>
+where
+    T: 'static,
+{
+    /// Converts a `Prc<Option<T>>` into an `Option<Prc<T>>` projecting to the contained value.
+    ///
+    /// This consumes the `Prc` without touching the reference counts in the `Some` case;
+    /// in the `None` case, the `Prc` is dropped right away.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// struct Config {
+    ///     name: Option<String>,
+    /// }
+    ///
+    /// let config = Prc::new(Config { name: Some("pared".to_owned()) });
+    /// let name = config.project(|c| &c.name).transpose().unwrap();
+    /// assert_eq!(&*name, "pared");
+    ///
+    /// let nothing = Prc::new(None::<u32>);
+    /// assert!(nothing.transpose().is_none());
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Option<Prc<T>> {
+        self.try_map_owned(|option| option.as_ref().ok_or(&())).ok()
+    }
+}
+
+impl<T, E> Prc<Result<T, E>>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Converts a `Prc<Result<T, E>>` into a `Result<Prc<T>, Prc<E>>` projecting to the
+    /// contained value or error.
+    ///
+    /// This consumes the `Prc` without touching the reference counts or cloning the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let result: Result<String, String> = Ok("done".to_owned());
+    /// let ok = Prc::new(result).transpose().unwrap();
+    /// assert_eq!(&*ok, "done");
+    ///
+    /// let result: Result<String, String> = Err("failed".to_owned());
+    /// let err = Prc::new(result).transpose().unwrap_err();
+    /// assert_eq!(&*err, "failed");
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Result<Prc<T>, Prc<E>> {
+        self.try_map_owned(Result::as_ref)
+    }
+
+    /// Projects this `Prc<Result<T, E>>` to the contained value or error, keeping `self`.
+    ///
+    /// This is the borrowing version of [`Prc::transpose`], which clones the `Prc` instead
+    /// of the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let result: Prc<Result<u32, String>> = Prc::new(Err("failed".to_owned()));
+    /// let err = result.split_result().unwrap_err();
+    /// assert_eq!(&*err, "failed");
+    /// assert_eq!(Prc::strong_count(&result), 2);
+    /// ```
+    #[inline]
+    pub fn split_result(&self) -> Result<Prc<T>, Prc<E>> {
+        self.clone().transpose()
+    }
+}
+
+impl<T> Prc<Vec<T>>
+where
+    T: 'static,
+{
+    /// Projects this `Prc<Vec<T>>` to a slice of its elements.
+    ///
+    /// To convert the `Prc` without cloning it, use the `From<Prc<Vec<T>>>` implementation
+    /// for `Prc<[T]>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let vec = Prc::new(vec![1, 2, 3]);
+    /// let slice: Prc<[i32]> = vec.as_slice_prc();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice_prc(&self) -> Prc<[T]> {
+        self.project(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the elements of the `Vec`, projecting to each element.
+    ///
+    /// See `iter_projected` on `Prc<[T]>` for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new(vec![1, 2, 3]);
+    /// let sum: i32 = prc.iter_projected().map(|x| *x).sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    #[inline]
+    pub fn iter_projected(&self) -> IterProjected<T> {
+        self.as_slice_prc().iter_projected()
+    }
+}
+
+impl Prc<String> {
+    /// Projects this `Prc<String>` to a string slice.
+    ///
+    /// To convert the `Prc` without cloning it, use the `From<Prc<String>>` implementation
+    /// for `Prc<str>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let string = Prc::new(String::from("pared"));
+    /// let str: Prc<str> = string.as_str_prc();
+    /// assert_eq!(&*str, "pared");
+    /// ```
+    #[inline]
+    pub fn as_str_prc(&self) -> Prc<str> {
+        self.project(String::as_str)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Prc<std::collections::HashMap<K, V, S>>
+where
+    K: Eq + Hash + 'static,
+    V: 'static,
+    S: core::hash::BuildHasher + 'static,
+{
+    /// Projects to the value corresponding to `key`, returning `None` if the map
+    /// doesn't contain it.
+    ///
+    /// The key may be any borrowed form of the map's key type, like with [`HashMap::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use pared::prc::Prc;
+    ///
+    /// let routes = Prc::new(HashMap::from([("/".to_owned(), "index".to_owned())]));
+    /// let index = routes.project_get("/").unwrap();
+    /// assert_eq!(&*index, "index");
+    /// assert!(routes.project_get("/missing").is_none());
+    /// ```
+    ///
+    /// [`HashMap::get`]: std::collections::HashMap::get
+    #[inline]
+    pub fn project_get<Q>(&self, key: &Q) -> Option<Prc<V>>
+    where
+        K: core::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.try_project(|map| map.get(key).ok_or(())).ok()
+    }
+}
+
+impl<K, V> Prc<BTreeMap<K, V>>
+where
+    K: Ord + 'static,
+    V: 'static,
+{
+    /// Projects to the value corresponding to `key`, returning `None` if the map
+    /// doesn't contain it.
+    ///
+    /// The key may be any borrowed form of the map's key type, like with [`BTreeMap::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use pared::prc::Prc;
+    ///
+    /// let ids = Prc::new(BTreeMap::from([(1, "one".to_owned()), (2, "two".to_owned())]));
+    /// let two = ids.project_get(&2).unwrap();
+    /// assert_eq!(&*two, "two");
+    /// assert!(ids.project_get(&3).is_none());
+    /// ```
+    #[inline]
+    pub fn project_get<Q>(&self, key: &Q) -> Option<Prc<V>>
+    where
+        K: core::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.try_project(|map| map.get(key).ok_or(())).ok()
+    }
+}
+
+impl<T> Prc<[T]>
+where
+    T: 'static,
+{
+    /// Projects to a subslice, returning a [`SliceError`] if `range` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc: Prc<[u8]> = Prc::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(&*prc.slice(1..3).unwrap(), &[2, 3]);
+    /// assert!(prc.slice(3..5).is_err());
+    /// ```
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<Prc<[T]>, SliceError> {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.try_project(|slice| {
+            slice
+                .get(bounds)
+                .ok_or_else(|| SliceError::new(slice.len(), &range))
+        })
+    }
+
+    /// Splits the slice into two at `mid`, returning a [`SliceError`] if `mid > len`.
+    ///
+    /// Both halves share the root `Rc` allocation with `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc: Prc<[u8]> = Prc::from(vec![1, 2, 3, 4]);
+    /// let (head, tail) = prc.split_at(1).unwrap();
+    /// assert_eq!((&*head, &*tail), (&[1][..], &[2, 3, 4][..]));
+    /// assert!(prc.split_at(5).is_err());
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn split_at(&self, mid: usize) -> Result<(Prc<[T]>, Prc<[T]>), SliceError> {
+        let head = self.slice(..mid)?;
+        let tail = self.project(|slice| &slice[mid..]);
+        Ok((head, tail))
+    }
+
+    /// Returns an iterator over the elements of the slice, projecting to each element.
+    ///
+    /// The iterator holds its own `Prc`, so neither it nor the returned elements borrow `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc: Prc<[String]> = Prc::from(vec!["a".to_owned(), "b".to_owned()]);
+    /// let elements: Vec<Prc<String>> = prc.iter_projected().collect();
+    /// drop(prc);
+    /// assert_eq!(&*elements[1], "b");
+    /// ```
+    #[inline]
+    pub fn iter_projected(&self) -> IterProjected<T> {
+        IterProjected {
+            front: 0,
+            back: self.len(),
+            slice: self.clone(),
+        }
+    }
+}
+
+impl Prc<str> {
+    /// Projects to a substring, returning a [`SliceError`] if `range` is out of bounds
+    /// or doesn't lie on `char` boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc: Prc<str> = Prc::from("héllo");
+    /// assert_eq!(&*prc.slice(3..).unwrap(), "llo");
+    /// assert!(prc.slice(2..).is_err());
+    /// ```
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<Prc<str>, SliceError> {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.try_project(|str| {
+            str.get(bounds)
+                .ok_or_else(|| SliceError::new(str.len(), &range))
+        })
+    }
+
+    /// Splits the string into two at `mid`, returning a [`SliceError`] if `mid` is out of bounds
+    /// or not on a `char` boundary.
+    ///
+    /// Both halves share the root `Rc` allocation with `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let prc: Prc<str> = Prc::from("key=value");
+    /// let (key, value) = prc.split_at(3).unwrap();
+    /// assert_eq!((&*key, &*value), ("key", "=value"));
+    /// ```
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> Result<(Prc<str>, Prc<str>), SliceError> {
+        let head = self.slice(..mid)?;
+        let tail = self.project(|str| &str[mid..]);
+        Ok((head, tail))
+    }
+
+    /// Returns an iterator over the lines of the string, projecting to each line.
+    ///
+    /// Lines are split like [`str::lines`]: they end with either `\n` or `\r\n`,
+    /// which isn't included in the returned lines, and a final empty line is skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let source: Prc<str> = Prc::from("let x = 1;\r\nlet y = 2;\n");
+    /// let lines: Vec<Prc<str>> = source.lines_projected().collect();
+    /// drop(source);
+    /// assert_eq!((&*lines[0], &*lines[1]), ("let x = 1;", "let y = 2;"));
+    /// assert_eq!(lines.len(), 2);
+    /// ```
+    #[inline]
+    pub fn lines_projected(&self) -> SplitProjected {
+        SplitProjected {
+            str: self.clone(),
+            position: Some(0),
+            delimiter: None,
+        }
+    }
+
+    /// Returns an iterator over the substrings separated by `delimiter`,
+    /// projecting to each substring.
+    ///
+    /// Substrings are split like [`str::split`], so empty substrings between adjacent
+    /// delimiters and at either end are returned as well.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let row: Prc<str> = Prc::from("name,,42");
+    /// let fields: Vec<Prc<str>> = row.split_projected(',').collect();
+    /// let fields: Vec<&str> = fields.iter().map(|field| &**field).collect();
+    /// assert_eq!(fields, ["name", "", "42"]);
+    /// ```
+    #[inline]
+    pub fn split_projected(&self, delimiter: char) -> SplitProjected {
+        SplitProjected {
+            str: self.clone(),
+            position: Some(0),
+            delimiter: Some(delimiter),
+        }
+    }
+}
+
+impl Prc<[u8]> {
+    /// Converts the bytes into a string slice sharing the same root allocation,
+    /// validating that they're UTF-8.
+    ///
+    /// The reference counts aren't touched; if the bytes aren't valid UTF-8,
+    /// this `Prc` is dropped and the [`Utf8Error`] is returned.
+    /// To convert a `Prc<str>` back, use the `From<Prc<str>>` implementation for `Prc<[u8]>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let source: Prc<[u8]> = Prc::from(b"fn main() {}".to_vec());
+    /// let name = source.slice(3..7).unwrap().try_into_str().unwrap();
+    /// assert_eq!(&*name, "main");
+    ///
+    /// let invalid: Prc<[u8]> = Prc::from(vec![0xff]);
+    /// assert!(invalid.try_into_str().is_err());
+    /// ```
+    ///
+    /// [`Utf8Error`]: core::str::Utf8Error
+    #[inline]
+    pub fn try_into_str(self) -> Result<Prc<str>, core::str::Utf8Error> {
+        core::str::from_utf8(&self)?;
+        let Prc { rc, projected } = self;
+        // SAFETY: the bytes were just validated as UTF-8, and `str` has the same layout as `[u8]`
+        let projected = unsafe { NonNull::new_unchecked(projected.as_ptr() as *mut str) };
+        Ok(Prc { rc, projected })
+    }
+}
+
+impl<T> Prc<MaybeUninit<T>>
+where
+    T: 'static,
+{
+    /// Converts to `Prc<T>`.
+    ///
+    /// If this `Prc` points to the whole root value, as returned by [`Prc::new_uninit`] or
+    /// [`Prc::new_zeroed`], the allocation is converted as well, so that the value is dropped
+    /// along with the allocation. Otherwise, only the projected pointer is converted.
+    /// Just like [`Rc::assume_init`], other pointers to the same allocation aren't converted;
+    /// if one of them ends up dropping the allocation, the value is leaked.
+    ///
+    /// # Safety
+    /// As with [`MaybeUninit::assume_init`], it is up to the caller to guarantee that the inner
+    /// value really is in an initialized state.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let mut name = Prc::<String>::new_uninit();
+    /// Prc::get_mut(&mut name).unwrap().write(String::from("pared"));
+    /// let name = unsafe { name.assume_init() };
+    /// assert_eq!(&*name, "pared");
+    /// ```
+    ///
+    /// [`Rc::assume_init`]: https://doc.rust-lang.org/std/rc/struct.Rc.html#method.assume_init
+    /// [`MaybeUninit::assume_init`]: core::mem::MaybeUninit::assume_init
+    pub unsafe fn assume_init(self) -> Prc<T> {
+        let Prc { rc, projected } = self;
+        match rc.root_ptr_as::<MaybeUninit<T>>() {
+            Some(root) if core::ptr::eq(root, projected.as_ptr()) => {
+                let _ = rc.into_raw();
+                // SAFETY: the erased root is an Rc<MaybeUninit<T>>, whose strong count
+                // is now owned by the `Rc` being reconstructed
+                Prc::from_root(Rc::from_raw(root).assume_init())
+            }
+            _ => Prc {
+                rc,
+                projected: projected.cast::<T>(),
+            },
+        }
+    }
+}
+
+impl Prc<dyn Any> {
+    /// Attempts to downcast the `Prc<dyn Any>` to a concrete type,
+    /// returning the original `Prc` if the projected value isn't a `T`.
+    ///
+    /// Only the type of the projected value is checked; the root `Rc` can be of any type.
+    ///
+    /// # Example
+    /// ```
+    /// use std::any::Any;
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::new((1u8, String::from("plugin")));
+    /// let any: Prc<dyn Any> = prc.project(|x| &x.1 as &dyn Any);
+    ///
+    /// let any = any.downcast::<u8>().unwrap_err();
+    /// let string = any.downcast::<String>().unwrap();
+    /// assert_eq!(&*string, "plugin");
+    /// ```
+    #[inline]
+    pub fn downcast<T: Any>(self) -> Result<Prc<T>, Prc<dyn Any>> {
+        if !(*self).is::<T>() {
+            return Err(self);
+        }
+        let Prc { rc, projected } = self;
+        Ok(Prc {
+            rc,
+            projected: projected.cast::<T>(),
+        })
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for Prc<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.deref()
+    }
+}
+
+impl<T: ?Sized> core::borrow::Borrow<T> for Prc<T> {
     #[inline]
     fn borrow(&self) -> &T {
         self.deref()
@@ -431,33 +1784,83 @@ impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for Prc<T> {
 
 impl<T> core::fmt::Display for Prc<T>
 where
-    T: core::fmt::Display + ?Sized,
+    T: core::fmt::Display + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: ?Sized> Deref for Prc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.projected.as_ref() }
+    }
+}
+
+impl<T> core::error::Error for Prc<T>
+where
+    T: core::error::Error + ?Sized,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.deref().source()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Prc<T>
+where
+    T: defmt::Format + ?Sized,
 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        self.deref().fmt(f)
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.deref().format(f)
     }
 }
 
-impl<T: ?Sized> Deref for Prc<T> {
-    type Target = T;
-
+/// Serializes the projected value, like `Rc<T>` with serde's `rc` feature.
+///
+/// Projections sharing an allocation are serialized separately, so sharing isn't preserved.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Prc<T>
+where
+    T: serde::Serialize + ?Sized,
+{
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { self.projected.as_ref() }
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
     }
 }
 
-#[cfg(feature = "std")]
-impl<T> std::error::Error for Prc<T>
+/// Deserializes a new `Prc` pointing to its own allocation, like `Rc<T>` with serde's `rc` feature.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Prc<T>
 where
-    T: std::error::Error + ?Sized,
+    T: ?Sized + 'static,
+    alloc::boxed::Box<T>: serde::Deserialize<'de>,
 {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        self.deref().source()
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        alloc::boxed::Box::<T>::deserialize(deserializer).map(Prc::from)
     }
 }
 
+/// Converts any value that can be converted into an `Rc<T>` into a `Prc<T>`.
+///
+/// This includes all of the owned-container conversions of `Rc`, such as
+/// `Vec<T>` into `Prc<[T]>`, `String` and `Box<str>` into `Prc<str>`, and `Box<T>` into `Prc<T>`.
+///
+/// # Example
+/// ```
+/// use pared::prc::Prc;
+///
+/// let slice: Prc<[u8]> = Prc::from(vec![1, 2, 3]);
+/// let str: Prc<str> = Prc::from(String::from("pared"));
+/// let boxed: Prc<u32> = Prc::from(Box::new(5));
+/// assert_eq!((&*slice, &*str, *boxed), (&[1, 2, 3][..], "pared", 5));
+/// ```
 impl<T, F> From<F> for Prc<T>
 where
     T: ?Sized + 'static,
@@ -465,7 +1868,35 @@ where
 {
     #[inline]
     fn from(value: F) -> Self {
-        Prc::from_rc(&value.into(), |x| x)
+        Prc::from_root(value.into())
+    }
+}
+
+impl<T> From<Prc<Vec<T>>> for Prc<[T]>
+where
+    T: 'static,
+{
+    #[inline]
+    fn from(prc: Prc<Vec<T>>) -> Self {
+        prc.map_owned(Vec::as_slice)
+    }
+}
+
+impl From<Prc<String>> for Prc<str> {
+    #[inline]
+    fn from(prc: Prc<String>) -> Self {
+        prc.map_owned(String::as_str)
+    }
+}
+
+impl From<Prc<str>> for Prc<[u8]> {
+    /// Converts the string slice into its bytes without touching the reference counts.
+    #[inline]
+    fn from(prc: Prc<str>) -> Self {
+        let Prc { rc, projected } = prc;
+        // SAFETY: `str` has the same layout as `[u8]`, and the pointer came from a `NonNull`
+        let projected = unsafe { NonNull::new_unchecked(projected.as_ptr() as *mut [u8]) };
+        Prc { rc, projected }
     }
 }
 
@@ -538,12 +1969,215 @@ where
 impl<T> Unpin for Prc<T> where T: ?Sized {}
 impl<T> core::panic::UnwindSafe for Prc<T> where T: core::panic::RefUnwindSafe + ?Sized {}
 
+/// The raw parts of a [`Prc`].
+///
+/// `RawPrc` is an opaque `Copy` value that doesn't own anything by itself;
+/// it's created by [`Prc::into_raw`] and turned back into a `Prc` by [`Prc::from_raw`].
+/// The strong count of the backing allocation can be adjusted while it's stored elsewhere
+/// using [`Prc::increment_strong_count`] and [`Prc::decrement_strong_count`].
+///
+/// Its layout is unspecified and it's larger than a single pointer, so it can't be handed
+/// to foreign code as is. Box it and pass the pointer returned by [`Box::into_raw`] instead.
+///
+/// [`Box::into_raw`]: alloc::boxed::Box::into_raw
+pub struct RawPrc<T: ?Sized> {
+    projected: NonNull<T>,
+    root: TypeErasedPtr,
+    vtable: &'static RcVTable,
+}
+
+impl<T: ?Sized> RawPrc<T> {
+    /// Returns a raw pointer to the projected data.
+    ///
+    /// The pointer is valid for as long as the allocation backing the `Prc` is alive.
+    #[inline]
+    pub fn as_ptr(self) -> *const T {
+        self.projected.as_ptr()
+    }
+}
+
+impl<T: ?Sized> Clone for RawPrc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for RawPrc<T> {}
+
+impl<T: ?Sized> core::fmt::Debug for RawPrc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawPrc")
+            .field("projected", &self.projected)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The raw parts of a prc [`Weak`].
+///
+/// `RawWeak` is an opaque `Copy` value that doesn't own anything by itself;
+/// it's created by [`Weak::into_raw`] and turned back into a `Weak` by [`Weak::from_raw`].
+/// Like [`RawPrc`], its layout is unspecified.
+#[cfg(feature = "weak")]
+pub struct RawWeak<T: ?Sized> {
+    projected: NonNull<T>,
+    root: TypeErasedPtr,
+    vtable: &'static RcVTable,
+}
+
+#[cfg(feature = "weak")]
+impl<T: ?Sized> RawWeak<T> {
+    /// Returns a raw pointer to the projected data.
+    ///
+    /// The pointer is only valid while the allocation backing the `Weak` has strong references.
+    #[inline]
+    pub fn as_ptr(self) -> *const T {
+        self.projected.as_ptr()
+    }
+}
+
+#[cfg(feature = "weak")]
+impl<T: ?Sized> Clone for RawWeak<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "weak")]
+impl<T: ?Sized> Copy for RawWeak<T> {}
+
+#[cfg(feature = "weak")]
+impl<T: ?Sized> core::fmt::Debug for RawWeak<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawWeak")
+            .field("projected", &self.projected)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over the elements of a `Prc<[T]>`, yielding a `Prc<T>` for each element.
+///
+/// Created by [`Prc::iter_projected`].
+pub struct IterProjected<T> {
+    slice: Prc<[T]>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: 'static> Iterator for IterProjected<T> {
+    type Item = Prc<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Some(self.slice.project(|slice| &slice[index]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T: 'static> DoubleEndedIterator for IterProjected<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.back;
+        Some(self.slice.project(|slice| &slice[index]))
+    }
+}
+
+impl<T: 'static> ExactSizeIterator for IterProjected<T> {}
+
+impl<T: 'static> core::iter::FusedIterator for IterProjected<T> {}
+
+impl<T> Clone for IterProjected<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for IterProjected<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IterProjected")
+            .field(&&self.slice[self.front..self.back])
+            .finish()
+    }
+}
+
+/// An iterator over substrings of a `Prc<str>`, yielding a `Prc<str>` for each substring.
+///
+/// Created by [`Prc::lines_projected`] and [`Prc::split_projected`].
+#[derive(Clone)]
+pub struct SplitProjected {
+    str: Prc<str>,
+    // The start of the next substring, or `None` once all substrings were returned
+    position: Option<usize>,
+    // The delimiter to split at, or `None` to split into lines
+    delimiter: Option<char>,
+}
+
+impl Iterator for SplitProjected {
+    type Item = Prc<str>;
+
+    fn next(&mut self) -> Option<Prc<str>> {
+        let start = self.position?;
+        let rest = &self.str[start..];
+        let (len, delimiter_len) = match self.delimiter {
+            Some(delimiter) => match rest.find(delimiter) {
+                Some(index) => (index, Some(delimiter.len_utf8())),
+                None => (rest.len(), None),
+            },
+            // Unlike other delimiters, a final line ending doesn't start another line
+            None if rest.is_empty() => {
+                self.position = None;
+                return None;
+            }
+            None => match rest.find('\n') {
+                Some(index) if rest[..index].ends_with('\r') => (index - 1, Some(2)),
+                Some(index) => (index, Some(1)),
+                None => (rest.len(), None),
+            },
+        };
+        self.position = delimiter_len.map(|delimiter_len| start + len + delimiter_len);
+        let end = start + len;
+        Some(self.str.project(|str| &str[start..end]))
+    }
+}
+
+impl core::iter::FusedIterator for SplitProjected {}
+
+impl core::fmt::Debug for SplitProjected {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let rest = self.position.map_or("", |position| &self.str[position..]);
+        f.debug_tuple("SplitProjected").field(&rest).finish()
+    }
+}
+
 /// Weak is a version of [`Prc`] that holds a non-owning reference to the managed allocation.
 /// The allocation is accessed by calling [`upgrade`], which returns `Option<Prc<T>>`.
 ///
 /// `Weak` will be valid as long as the original allocation is alive; it's not tied to the specific
 /// `Prc` it was created from.
 ///
+/// Like `Prc<T>`, `Weak<T>` is covariant in `T`, and `Option<Weak<T>>` is the same size as
+/// `Weak<T>`.
+///
 /// See [`std::sync::Weak`] for more details.
 ///
 /// # Example
@@ -567,11 +2201,37 @@ impl<T> core::panic::UnwindSafe for Prc<T> where T: core::panic::RefUnwindSafe +
 ///
 /// [`upgrade`]: Weak::upgrade
 /// [`std::sync::Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+#[cfg(feature = "weak")]
 pub struct Weak<T: ?Sized> {
     weak: TypeErasedWeak,
     projected: NonNull<T>,
 }
 
+#[cfg(feature = "weak")]
+impl<T> Weak<T> {
+    /// Constructs a new `Weak<T>`, without allocating any memory.
+    /// Calling [`upgrade`] on the return value always gives [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Weak;
+    ///
+    /// let empty: Weak<i64> = Weak::new();
+    /// assert!(empty.upgrade().is_none());
+    /// assert!(empty.is_dangling());
+    /// ```
+    ///
+    /// [`upgrade`]: Weak::upgrade
+    #[inline]
+    pub fn new() -> Weak<T> {
+        Weak {
+            weak: TypeErasedWeak::dangling(),
+            projected: NonNull::dangling(),
+        }
+    }
+}
+
+#[cfg(feature = "weak")]
 impl<T: ?Sized> Weak<T> {
     /// Returns a raw pointer to the object `T` pointed to by this `Weak<T>`.
     ///
@@ -603,6 +2263,46 @@ impl<T: ?Sized> Weak<T> {
         NonNull::as_ptr(self.projected)
     }
 
+    /// Consumes the `Weak`, returning its raw parts.
+    ///
+    /// To avoid a memory leak, the parts have to be converted back to a `Weak` using
+    /// [`Weak::from_raw`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::{Prc, Weak};
+    ///
+    /// let strong = Prc::new(5);
+    /// let raw = Prc::downgrade(&strong).into_raw();
+    /// assert_eq!(Prc::weak_count(&strong), 1);
+    ///
+    /// // SAFETY: `raw` comes from `Weak::into_raw`
+    /// let weak = unsafe { Weak::from_raw(raw) };
+    /// assert_eq!(*weak.upgrade().unwrap(), 5);
+    /// ```
+    #[must_use = "losing the raw parts will leak the underlying allocation"]
+    pub fn into_raw(self) -> RawWeak<T> {
+        let (root, vtable) = self.weak.into_raw();
+        RawWeak {
+            projected: self.projected,
+            root,
+            vtable,
+        }
+    }
+
+    /// Constructs a `Weak<T>` from raw parts obtained from [`Weak::into_raw`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Weak::into_raw`] for a `Weak<T>` with the same `T`,
+    /// and each call to `from_raw` must be balanced by exactly one call to `into_raw`.
+    #[inline]
+    pub unsafe fn from_raw(raw: RawWeak<T>) -> Self {
+        Self {
+            weak: TypeErasedWeak::from_raw(raw.root, raw.vtable),
+            projected: raw.projected,
+        }
+    }
+
     /// Attempts to upgrade the `Weak` pointer to a [`Prc`], delaying dropping of the inner value
     /// if successful.
     ///
@@ -633,6 +2333,122 @@ impl<T: ?Sized> Weak<T> {
         })
     }
 
+    /// Constructs a new `Weak<U>` from an existing `Weak<T>` by projecting a field,
+    /// returning `None` if the inner value has since been dropped.
+    ///
+    /// The allocation is kept alive while `project` runs, so the returned `Weak` points to
+    /// the same allocation as `self`.
+    ///
+    /// # Panics
+    /// If `project` panics, the panic is propagated to the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let widget = Prc::new((String::from("button"), 42u32));
+    /// let weak = Prc::downgrade(&widget);
+    /// let weak_id = weak.project(|widget| &widget.1).unwrap();
+    ///
+    /// assert_eq!(*weak_id.upgrade().unwrap(), 42);
+    /// drop(widget);
+    /// assert!(weak_id.upgrade().is_none());
+    /// assert!(weak.project(|widget| &widget.1).is_none());
+    /// ```
+    #[inline]
+    pub fn project<U, F>(&self, project: F) -> Option<Weak<U>>
+    where
+        U: ?Sized + 'static,
+        F: Projection<T, U>,
+    {
+        let strong = self.upgrade()?;
+        let projected = NonNull::from(project(&strong));
+        Some(Weak {
+            weak: self.weak.clone(),
+            projected,
+        })
+    }
+
+    /// Attempts to upgrade the `Weak` pointer to a [`Prc`] projected by `project`,
+    /// returning `None` if the inner value has since been dropped.
+    ///
+    /// This is equivalent to upgrading and then projecting, but clones the root only once.
+    ///
+    /// # Panics
+    /// If `project` panics, the panic is propagated to the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let widget = Prc::new((String::from("button"), 42u32));
+    /// let weak = Prc::downgrade(&widget);
+    ///
+    /// let label = weak.upgrade_project(|widget| widget.0.as_str()).unwrap();
+    /// assert_eq!(&*label, "button");
+    /// drop(widget);
+    /// drop(label);
+    /// assert!(weak.upgrade_project(|widget| &widget.1).is_none());
+    /// ```
+    #[inline]
+    pub fn upgrade_project<U, F>(&self, project: F) -> Option<Prc<U>>
+    where
+        U: ?Sized + 'static,
+        F: Projection<T, U>,
+    {
+        let strong = self.upgrade()?;
+        let projected = NonNull::from(project(&strong));
+        let Prc { rc, .. } = strong;
+        Some(Prc { rc, projected })
+    }
+
+    /// Returns `true` if this `Weak` was created with [`Weak::new`] (or [`Default`])
+    /// and never pointed to an allocation.
+    ///
+    /// Note that this is different from being unable to [`upgrade`]: a `Weak` to an allocation
+    /// whose value has been dropped isn't dangling.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::{Prc, Weak};
+    ///
+    /// struct Widget {
+    ///     parent: Weak<Widget>,
+    /// }
+    ///
+    /// let root = Prc::new(Widget { parent: Weak::default() });
+    /// assert!(root.parent.is_dangling());
+    ///
+    /// let weak_root = Prc::downgrade(&root);
+    /// drop(root);
+    /// assert!(!weak_root.is_dangling());
+    /// ```
+    ///
+    /// [`upgrade`]: Weak::upgrade
+    #[inline]
+    pub fn is_dangling(&self) -> bool {
+        self.weak.is_dangling()
+    }
+
+    /// Returns `true` if the value this `Weak` points to hasn't been dropped yet,
+    /// without creating a strong pointer like [`upgrade`](Weak::upgrade) does.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::{Prc, Weak};
+    ///
+    /// let root = Prc::new((1, 2));
+    /// let weak = Prc::downgrade(&root.project(|pair| &pair.0));
+    /// assert!(weak.is_alive());
+    /// drop(root);
+    /// assert!(!weak.is_alive());
+    /// assert!(!Weak::<u32>::new().is_alive());
+    /// ```
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.weak.strong_count() > 0
+    }
+
     /// Returns the number of strong pointers pointing to this allocation.
     #[inline]
     pub fn strong_count(&self) -> usize {
@@ -649,6 +2465,28 @@ impl<T: ?Sized> Weak<T> {
         self.weak.weak_count()
     }
 
+    /// Gets both the number of strong and `Weak` pointers to this allocation.
+    ///
+    /// See [`Weak::strong_count`] and [`Weak::weak_count`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{prc::Prc, Counts};
+    /// let six = Prc::new(6);
+    /// let weak_six = Prc::downgrade(&six);
+    ///
+    /// assert_eq!(weak_six.counts(), Counts { strong: 1, weak: 1 });
+    /// drop(six);
+    /// assert_eq!(weak_six.counts(), Counts { strong: 0, weak: 0 });
+    /// ```
+    #[inline]
+    pub fn counts(&self) -> Counts {
+        Counts {
+            strong: self.strong_count(),
+            weak: self.weak_count(),
+        }
+    }
+
     /// Returns `true` if the two `Weak`s point to the same data, using [`core::ptr::eq`].
     /// See that function for caveats when comparing `dyn Trait` pointers.
     ///
@@ -658,8 +2496,61 @@ impl<T: ?Sized> Weak<T> {
     pub fn ptr_eq(&self, other: &Weak<T>) -> bool {
         core::ptr::eq(self.projected.as_ptr(), other.projected.as_ptr())
     }
+
+    /// Returns `true` if the two `Weak`s are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// Like [`Weak::ptr_eq`], this can compare `Weak` pointers even when either or both of them
+    /// can't successfully `upgrade` anymore.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let tuple = Prc::new((5u8, 6u16));
+    /// let first = Prc::downgrade(&tuple.project(|t| &t.0));
+    /// let second = Prc::downgrade(&tuple.project(|t| &t.1));
+    /// let other = Prc::downgrade(&Prc::new(5u8));
+    ///
+    /// assert!(first.same_root(&second));
+    /// assert!(!first.same_root(&other));
+    /// ```
+    #[inline]
+    pub fn same_root<U: ?Sized>(&self, other: &Weak<U>) -> bool {
+        core::ptr::eq(self.weak.as_ptr(), other.weak.as_ptr())
+    }
+
+    /// Returns `true` if this `Weak` and the `Prc` are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::prc::Prc;
+    ///
+    /// let tuple = Prc::new((5u8, 6u16));
+    /// let weak = Prc::downgrade(&tuple.project(|t| &t.0));
+    ///
+    /// assert!(weak.same_root_as(&tuple));
+    /// assert!(!weak.same_root_as(&Prc::new(5u8)));
+    /// ```
+    #[inline]
+    pub fn same_root_as<U: ?Sized>(&self, strong: &Prc<U>) -> bool {
+        core::ptr::eq(self.weak.as_ptr(), strong.rc.as_ptr())
+    }
+}
+
+#[cfg(feature = "weak")]
+impl<T> Default for Weak<T> {
+    /// Constructs a new `Weak<T>`, without allocating any memory.
+    ///
+    /// See [`Weak::new`].
+    #[inline]
+    fn default() -> Self {
+        Weak::new()
+    }
 }
 
+#[cfg(feature = "weak")]
 impl<T: ?Sized> Clone for Weak<T> {
     #[inline]
     fn clone(&self) -> Self {
@@ -670,8 +2561,50 @@ impl<T: ?Sized> Clone for Weak<T> {
     }
 }
 
+#[cfg(feature = "weak")]
 impl<T: ?Sized> core::fmt::Debug for Weak<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "(Weak)")
     }
 }
+
+#[cfg(all(feature = "defmt", feature = "weak"))]
+impl<T: ?Sized> defmt::Format for Weak<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let counts = self.counts();
+        defmt::write!(
+            f,
+            "(Weak) {{ alive: {=bool}, strong: {=usize}, weak: {=usize} }}",
+            counts.strong > 0,
+            counts.strong,
+            counts.weak
+        )
+    }
+}
+
+/// Serializes the value as an `Option`, which is `None` if it has already been dropped,
+/// like `rc::Weak<T>` with serde's `rc` feature.
+#[cfg(all(feature = "serde", feature = "weak"))]
+impl<T> serde::Serialize for Weak<T>
+where
+    T: serde::Serialize + ?Sized,
+{
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.upgrade().serialize(serializer)
+    }
+}
+
+/// Deserializes an `Option<T>` and discards it, returning a dangling `Weak`,
+/// like `rc::Weak<T>` with serde's `rc` feature.
+#[cfg(all(feature = "serde", feature = "weak"))]
+impl<'de, T> serde::Deserialize<'de> for Weak<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer)?;
+        Ok(Weak::new())
+    }
+}
@@ -0,0 +1,42 @@
+//! Constructors for `Prc` using custom allocators.
+
+use alloc::{alloc::Allocator, rc::Rc};
+use core::ptr::NonNull;
+
+use super::{erased_rc::TypeErasedRc, Prc};
+
+impl<T> Prc<T>
+where
+    T: 'static,
+{
+    /// Constructs a new `Prc<T>` in the provided allocator.
+    ///
+    /// The allocator is stored in every pointer to the allocation, so it must be `Copy` and
+    /// no larger than a pointer, such as a reference to an arena or a zero-sized handle;
+    /// this is checked at compile time. The resulting `Prc<T>` is interchangeable with
+    /// `Prc`s from any other allocator.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use std::alloc::System;
+    /// use pared::prc::Prc;
+    ///
+    /// let from_system = Prc::new_in((1u8, 2u32), System);
+    /// let second = from_system.project(|pair| &pair.1);
+    /// let from_global = Prc::new(3u32);
+    ///
+    /// assert_eq!(*second + *from_global, 5);
+    /// ```
+    pub fn new_in<A>(value: T, alloc: A) -> Prc<T>
+    where
+        A: Allocator + Copy + 'static,
+    {
+        let rc = Rc::new_in(value, alloc);
+        let projected = NonNull::from(&*rc);
+        Prc {
+            rc: TypeErasedRc::new_in(rc),
+            projected,
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use alloc::rc::{Rc, Weak};
+#[cfg(feature = "allocator-api")]
+use alloc::alloc::Allocator;
+use alloc::rc::Rc;
+#[cfg(feature = "weak")]
+use alloc::rc::Weak;
 use core::{
+    alloc::Layout,
+    any::TypeId,
     clone::Clone,
     marker::{PhantomData, Sized},
     mem::ManuallyDrop,
@@ -7,7 +13,12 @@ use core::{
     option::{Option, Option::Some},
 };
 
-use crate::{erased_ptr::TypeErasedPtr, vtable::RcVTable};
+#[cfg(feature = "weak")]
+use crate::vtable::DanglingErased;
+use crate::{
+    erased_ptr::TypeErasedPtr,
+    vtable::{rc_allocation_layout, RcVTable, StaticErased},
+};
 
 pub struct TypeErasedRc {
     ptr: TypeErasedPtr,
@@ -18,7 +29,7 @@ pub struct TypeErasedRc {
 
 impl TypeErasedRc {
     #[inline]
-    pub(crate) fn new<T: ?Sized>(arc: Rc<T>) -> Self {
+    pub(crate) fn new<T: ?Sized + 'static>(arc: Rc<T>) -> Self {
         Self {
             ptr: TypeErasedPtr::new(Rc::into_raw(arc)),
             vtable: &RcErased::<T>::VTABLE,
@@ -26,6 +37,52 @@ impl TypeErasedRc {
         }
     }
 
+    /// Consumes `self` without decrementing the strong count, returning its raw parts.
+    #[inline]
+    pub(crate) fn into_raw(self) -> (TypeErasedPtr, &'static RcVTable) {
+        let this = ManuallyDrop::new(self);
+        (this.ptr, this.vtable)
+    }
+
+    /// Reconstructs `Self` from the parts returned by [`TypeErasedRc::into_raw`].
+    ///
+    /// # Safety
+    /// `ptr` and `vtable` must come from a call to [`TypeErasedRc::into_raw`] and
+    /// the strong count they own must not have been released since.
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: TypeErasedPtr, vtable: &'static RcVTable) -> Self {
+        Self {
+            ptr,
+            vtable,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Like [`TypeErasedRc::new`], but for an `Rc` using a custom allocator.
+    ///
+    /// The allocator is stored alongside the pointer, so it must be `Copy` and fit in a pointer.
+    #[cfg(feature = "allocator-api")]
+    #[inline]
+    pub(crate) fn new_in<T, A: Allocator + Copy>(rc: Rc<T, A>) -> Self {
+        let (ptr, alloc) = Rc::into_raw_with_allocator(rc);
+        Self {
+            ptr: TypeErasedPtr::with_extra(ptr, alloc),
+            vtable: &RcInErased::<T, A>::VTABLE,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a `TypeErasedRc` borrowing `'static` data without any reference counting.
+    #[inline]
+    pub(crate) fn from_static<T: ?Sized>(value: &'static T) -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(value as *const T),
+            vtable: &StaticErased::<T>::VTABLE,
+            _phantom: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "weak")]
     #[inline]
     pub(crate) fn downgrade(&self) -> TypeErasedWeak {
         TypeErasedWeak {
@@ -49,6 +106,49 @@ impl TypeErasedRc {
         // which guarantees that self.vtable and self.ptr match
         unsafe { (self.vtable.weak_count)(self.ptr) }
     }
+
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        // SAFETY: once set in TypeErasedRc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.is_unique)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const () {
+        // SAFETY: once set in TypeErasedRc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.as_ptr)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn root_layout(&self) -> Layout {
+        // SAFETY: once set in TypeErasedRc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.root_layout)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn type_id(&self) -> Option<TypeId> {
+        self.vtable.type_id.map(|type_id| type_id())
+    }
+
+    #[cfg(feature = "type-names")]
+    #[inline]
+    pub(crate) fn type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
+
+    /// Returns the pointer to the root value if it's known to be an `R`.
+    #[inline]
+    pub(crate) fn root_ptr_as<R: ?Sized + 'static>(&self) -> Option<*const R> {
+        if self.type_id()? == TypeId::of::<R>() {
+            // SAFETY: the vtable only knows the type of T when self.ptr is an erased Rc<T>
+            Some(unsafe { self.ptr.as_ptr::<R>() })
+        } else {
+            None
+        }
+    }
 }
 
 impl Clone for TypeErasedRc {
@@ -70,13 +170,62 @@ impl Drop for TypeErasedRc {
     }
 }
 
+#[cfg(feature = "weak")]
 pub(crate) struct TypeErasedWeak {
     ptr: TypeErasedPtr,
     vtable: &'static RcVTable,
     _phantom: PhantomData<*mut ()>,
 }
 
+#[cfg(feature = "weak")]
 impl TypeErasedWeak {
+    /// Consumes `self` without decrementing the weak count, returning its raw parts.
+    #[inline]
+    pub(crate) fn into_raw(self) -> (TypeErasedPtr, &'static RcVTable) {
+        let this = ManuallyDrop::new(self);
+        (this.ptr, this.vtable)
+    }
+
+    /// Reconstructs `Self` from the parts returned by [`TypeErasedWeak::into_raw`].
+    ///
+    /// # Safety
+    /// `ptr` and `vtable` must come from a call to [`TypeErasedWeak::into_raw`] and
+    /// the weak count they own must not have been released since.
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: TypeErasedPtr, vtable: &'static RcVTable) -> Self {
+        Self {
+            ptr,
+            vtable,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a `TypeErasedWeak` that doesn't point to any allocation.
+    #[inline]
+    pub(crate) fn dangling() -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(core::ptr::null::<()>()),
+            vtable: &DanglingErased::VTABLE,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns `true` if this was created with [`TypeErasedWeak::dangling`].
+    #[inline]
+    pub(crate) fn is_dangling(&self) -> bool {
+        self.as_ptr().is_null()
+    }
+
+    /// Like [`TypeErasedRc::new`], but for `Weak<T>`.
+    #[inline]
+    pub(crate) fn new<T: ?Sized + 'static>(weak: Weak<T>) -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(Weak::into_raw(weak)),
+            vtable: &RcErased::<T>::VTABLE,
+            _phantom: PhantomData,
+        }
+    }
+
     #[inline]
     pub(crate) fn upgrade(&self) -> Option<TypeErasedRc> {
         Some(TypeErasedRc {
@@ -100,8 +249,16 @@ impl TypeErasedWeak {
         // which guarantees that self.vtable and self.ptr match
         unsafe { (self.vtable.weak_count_weak)(self.ptr) }
     }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const () {
+        // SAFETY: once set in TypeErasedWeak::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.as_ptr)(self.ptr) }
+    }
 }
 
+#[cfg(feature = "weak")]
 impl Clone for TypeErasedWeak {
     #[inline]
     fn clone(&self) -> Self {
@@ -112,6 +269,7 @@ impl Clone for TypeErasedWeak {
     }
 }
 
+#[cfg(feature = "weak")]
 impl Drop for TypeErasedWeak {
     #[inline]
     fn drop(&mut self) {
@@ -123,18 +281,31 @@ impl Drop for TypeErasedWeak {
 
 pub(crate) struct RcErased<T: ?Sized>(PhantomData<*const T>);
 
-impl<T: ?Sized> RcErased<T> {
-    // A "vtable" for Rc<T> and rc::Weak<T> where T: ?Sized
+impl<T: ?Sized + 'static> RcErased<T> {
+    // A "vtable" for Rc<T> and rc::Weak<T> where T: ?Sized, which also knows the type of T
     const VTABLE: RcVTable = RcVTable {
         clone: Self::clone,
         drop: Self::drop,
+        #[cfg(feature = "weak")]
         downgrade: Self::downgrade,
         strong_count: Self::strong_count,
         weak_count: Self::weak_count,
+        is_unique: Self::is_unique,
+        as_ptr: Self::as_ptr,
+        root_layout: Self::root_layout,
+        type_id: Some(TypeId::of::<T>),
+        #[cfg(feature = "type-names")]
+        type_name: core::any::type_name::<T>,
+        any_root: None,
+        #[cfg(feature = "weak")]
         clone_weak: Self::clone_weak,
+        #[cfg(feature = "weak")]
         drop_weak: Self::drop_weak,
+        #[cfg(feature = "weak")]
         upgrade_weak: Self::upgrade_weak,
+        #[cfg(feature = "weak")]
         strong_count_weak: Self::strong_count_weak,
+        #[cfg(feature = "weak")]
         weak_count_weak: Self::weak_count_weak,
     };
 
@@ -151,6 +322,7 @@ impl<T: ?Sized> RcErased<T> {
     }
 
     // Must be called with an erased pointer to Rc<T>
+    #[cfg(feature = "weak")]
     unsafe fn downgrade(ptr: TypeErasedPtr) -> TypeErasedPtr {
         let arc = Self::as_manually_drop_rc(ptr);
         let weak = Rc::downgrade(&arc);
@@ -167,45 +339,184 @@ impl<T: ?Sized> RcErased<T> {
         let arc = Self::as_manually_drop_rc(ptr);
         Rc::weak_count(&arc)
     }
+    // Must be called with an erased pointer to Rc<T>
+    unsafe fn is_unique(ptr: TypeErasedPtr) -> bool {
+        let rc = Self::as_manually_drop_rc(ptr);
+        Rc::strong_count(&rc) == 1 && Rc::weak_count(&rc) == 0
+    }
+    // Must be called with an erased pointer to Rc<T> or rc::Weak<T>
+    unsafe fn as_ptr(ptr: TypeErasedPtr) -> *const () {
+        ptr.as_ptr::<T>() as *const ()
+    }
+    // Must be called with an erased pointer to Rc<T>
+    unsafe fn root_layout(ptr: TypeErasedPtr) -> Layout {
+        rc_allocation_layout(Layout::for_value::<T>(&*ptr.as_ptr()))
+    }
     // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn clone_weak(ptr: TypeErasedPtr) {
         let weak = Self::as_manually_drop_weak(ptr);
         let _cloned = weak.clone();
     }
     // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn drop_weak(ptr: TypeErasedPtr) {
         let weak: Weak<T> = Weak::from_raw(ptr.as_ptr());
         core::mem::drop(weak);
     }
     // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn upgrade_weak(ptr: TypeErasedPtr) -> Option<TypeErasedPtr> {
         let weak = Self::as_manually_drop_weak(ptr);
         let arc = weak.upgrade();
         arc.map(|arc| TypeErasedPtr::new(Rc::into_raw(arc)))
     }
     // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn strong_count_weak(ptr: TypeErasedPtr) -> usize {
         let weak = Self::as_manually_drop_weak(ptr);
         Weak::strong_count(&weak)
     }
     // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn weak_count_weak(ptr: TypeErasedPtr) -> usize {
         let weak = Self::as_manually_drop_weak(ptr);
         Weak::weak_count(&weak)
     }
 
-    // Must be called with an erased pointer to rc::Weak<T>
+    // Must be called with an erased pointer to Rc<T>
     #[inline]
     unsafe fn as_manually_drop_rc(ptr: TypeErasedPtr) -> ManuallyDrop<Rc<T>> {
         ManuallyDrop::new(Rc::from_raw(ptr.as_ptr()))
     }
 
+    // Must be called with an erased pointer to rc::Weak<T>
+    #[cfg(feature = "weak")]
     #[inline]
     unsafe fn as_manually_drop_weak(ptr: TypeErasedPtr) -> ManuallyDrop<Weak<T>> {
         ManuallyDrop::new(Weak::from_raw(ptr.as_ptr()))
     }
 }
 
+#[cfg(feature = "allocator-api")]
+pub(crate) struct RcInErased<T, A>(PhantomData<(*const T, A)>);
+
+#[cfg(feature = "allocator-api")]
+impl<T, A: Allocator + Copy> RcInErased<T, A> {
+    // A "vtable" for Rc<T, A> and rc::Weak<T, A>, with the allocator stored in the erased pointer
+    const VTABLE: RcVTable = RcVTable {
+        clone: Self::clone,
+        drop: Self::drop,
+        #[cfg(feature = "weak")]
+        downgrade: Self::downgrade,
+        strong_count: Self::strong_count,
+        weak_count: Self::weak_count,
+        is_unique: Self::is_unique,
+        as_ptr: Self::as_ptr,
+        root_layout: Self::root_layout,
+        type_id: None,
+        #[cfg(feature = "type-names")]
+        type_name: core::any::type_name::<T>,
+        any_root: None,
+        #[cfg(feature = "weak")]
+        clone_weak: Self::clone_weak,
+        #[cfg(feature = "weak")]
+        drop_weak: Self::drop_weak,
+        #[cfg(feature = "weak")]
+        upgrade_weak: Self::upgrade_weak,
+        #[cfg(feature = "weak")]
+        strong_count_weak: Self::strong_count_weak,
+        #[cfg(feature = "weak")]
+        weak_count_weak: Self::weak_count_weak,
+    };
+
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn clone(ptr: TypeErasedPtr) {
+        Rc::increment_strong_count_in(ptr.as_ptr::<T>(), ptr.extra::<A>());
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn drop(ptr: TypeErasedPtr) {
+        let rc = Rc::from_raw_in(ptr.as_ptr::<T>(), ptr.extra::<A>());
+        core::mem::drop(rc);
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn downgrade(ptr: TypeErasedPtr) -> TypeErasedPtr {
+        let rc = Self::as_manually_drop_rc(ptr);
+        let (weak, alloc) = Weak::into_raw_with_allocator(Rc::downgrade(&rc));
+        TypeErasedPtr::with_extra(weak, alloc)
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn strong_count(ptr: TypeErasedPtr) -> usize {
+        let rc = Self::as_manually_drop_rc(ptr);
+        Rc::strong_count(&rc)
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn weak_count(ptr: TypeErasedPtr) -> usize {
+        let rc = Self::as_manually_drop_rc(ptr);
+        Rc::weak_count(&rc)
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn is_unique(ptr: TypeErasedPtr) -> bool {
+        let rc = Self::as_manually_drop_rc(ptr);
+        Rc::strong_count(&rc) == 1 && Rc::weak_count(&rc) == 0
+    }
+    // Must be called with an erased pointer to Rc<T, A> or rc::Weak<T, A>
+    unsafe fn as_ptr(ptr: TypeErasedPtr) -> *const () {
+        ptr.as_ptr::<T>() as *const ()
+    }
+    // Must be called with an erased pointer to Rc<T, A>
+    unsafe fn root_layout(_: TypeErasedPtr) -> Layout {
+        rc_allocation_layout(Layout::new::<T>())
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn clone_weak(ptr: TypeErasedPtr) {
+        let weak = Self::as_manually_drop_weak(ptr);
+        let _cloned = weak.clone();
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn drop_weak(ptr: TypeErasedPtr) {
+        let weak = Weak::from_raw_in(ptr.as_ptr::<T>(), ptr.extra::<A>());
+        core::mem::drop(weak);
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn upgrade_weak(ptr: TypeErasedPtr) -> Option<TypeErasedPtr> {
+        let weak = Self::as_manually_drop_weak(ptr);
+        let rc = weak.upgrade();
+        rc.map(|rc| {
+            let (ptr, alloc) = Rc::into_raw_with_allocator(rc);
+            TypeErasedPtr::with_extra(ptr, alloc)
+        })
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn strong_count_weak(ptr: TypeErasedPtr) -> usize {
+        let weak = Self::as_manually_drop_weak(ptr);
+        Weak::strong_count(&weak)
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    unsafe fn weak_count_weak(ptr: TypeErasedPtr) -> usize {
+        let weak = Self::as_manually_drop_weak(ptr);
+        Weak::weak_count(&weak)
+    }
+
+    // Must be called with an erased pointer to Rc<T, A>
+    #[inline]
+    unsafe fn as_manually_drop_rc(ptr: TypeErasedPtr) -> ManuallyDrop<Rc<T, A>> {
+        ManuallyDrop::new(Rc::from_raw_in(ptr.as_ptr(), ptr.extra::<A>()))
+    }
+    // Must be called with an erased pointer to rc::Weak<T, A>
+    #[cfg(feature = "weak")]
+    #[inline]
+    unsafe fn as_manually_drop_weak(ptr: TypeErasedPtr) -> ManuallyDrop<Weak<T, A>> {
+        ManuallyDrop::new(Weak::from_raw_in(ptr.as_ptr(), ptr.extra::<A>()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,6 +552,7 @@ mod tests {
         assert_eq!(unsafe { DROPPED_COUNT }, 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_drops_object_when_last_instance_drops_with_weak() {
@@ -271,6 +583,7 @@ mod tests {
         assert_eq!(unsafe { DROPPED_COUNT }, 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_strong_count_tracks_instances() {
@@ -292,6 +605,7 @@ mod tests {
         assert_eq!(erased2.strong_count(), 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_weak_count() {
@@ -319,6 +633,68 @@ mod tests {
         assert_eq!(weak.weak_count(), 0);
     }
 
+    #[cfg(feature = "weak")]
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn static_is_never_released() {
+        let erased = TypeErasedRc::from_static("static");
+        let weak = erased.downgrade();
+        let cloned = erased.clone();
+        core::mem::drop(erased);
+        core::mem::drop(cloned);
+        assert_eq!(weak.strong_count(), usize::MAX);
+        assert_eq!(weak.weak_count(), 0);
+
+        let upgraded = weak.clone().upgrade().unwrap();
+        assert_eq!(upgraded.root_layout().size(), 0);
+        assert_eq!(upgraded.type_id(), None);
+        assert_eq!(unsafe { &*(upgraded.as_ptr() as *const u8) }, &b's');
+    }
+
+    #[test]
+    #[cfg(all(feature = "allocator-api", feature = "weak"))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn erased_rc_in_allocator() {
+        use alloc::alloc::Global;
+
+        let erased = TypeErasedRc::new_in(Rc::new_in(alloc::string::String::from("a"), &Global));
+        let cloned = erased.clone();
+        assert_eq!(erased.strong_count(), 2);
+
+        let weak = erased.downgrade();
+        let weak2 = weak.clone();
+        assert_eq!(erased.weak_count(), 2);
+        core::mem::drop(weak2);
+        core::mem::drop(erased);
+
+        let upgraded = weak.upgrade().unwrap();
+        assert_eq!(upgraded.strong_count(), 2);
+        assert_eq!(weak.strong_count(), 2);
+        assert_eq!(weak.weak_count(), 1);
+        assert_eq!(upgraded.type_id(), None);
+        assert!(upgraded.root_layout().size() >= core::mem::size_of::<alloc::string::String>());
+
+        core::mem::drop(upgraded);
+        core::mem::drop(cloned);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[cfg(feature = "weak")]
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn dangling_weak() {
+        let weak = TypeErasedWeak::dangling();
+        let cloned = weak.clone();
+        assert!(cloned.is_dangling());
+        assert!(cloned.upgrade().is_none());
+        assert_eq!(cloned.strong_count(), 0);
+        assert_eq!(cloned.weak_count(), 0);
+
+        let erased = TypeErasedRc::new(Rc::new(42));
+        assert!(!erased.downgrade().is_dangling());
+    }
+
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn weak_can_upgrade_when_there_are_instances() {
@@ -0,0 +1,191 @@
+//! Fallible constructors for `Prc`.
+
+use alloc::{alloc::AllocError, boxed::Box, rc::Rc, string::String, vec::Vec};
+use core::{iter::IntoIterator, mem::MaybeUninit};
+
+use super::Prc;
+
+impl<T> Prc<T>
+where
+    T: 'static,
+{
+    /// Constructs a new `Prc<T>`, returning an error if the allocation fails.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::try_new(6)?;
+    /// assert_eq!(*prc, 6);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_new(value: T) -> Result<Prc<T>, AllocError> {
+        Ok(Prc::from_root(Rc::try_new(value)?))
+    }
+
+    /// Constructs a new `Prc` with uninitialized contents, returning an error if the allocation
+    /// fails.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let mut five = Prc::<u32>::try_new_uninit()?;
+    /// Prc::get_mut(&mut five).unwrap().write(5);
+    /// let five = unsafe { five.assume_init() };
+    /// assert_eq!(*five, 5);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_new_uninit() -> Result<Prc<MaybeUninit<T>>, AllocError> {
+        Ok(Prc::from_root(Rc::try_new_uninit()?))
+    }
+
+    /// Constructs a new `Prc` with uninitialized contents, with the memory being filled
+    /// with `0` bytes, returning an error if the allocation fails.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let zero = Prc::<u32>::try_new_zeroed()?;
+    /// let zero = unsafe { zero.assume_init() };
+    /// assert_eq!(*zero, 0);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_new_zeroed() -> Result<Prc<MaybeUninit<T>>, AllocError> {
+        Ok(Prc::from_root(Rc::try_new_zeroed()?))
+    }
+}
+
+impl<T> Prc<T>
+where
+    T: ?Sized + 'static,
+{
+    /// Constructs a new `Prc<T>` from a boxed value, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<Box<T>>`, the value isn't moved into a new allocation: the `Box` itself
+    /// is stored in the root `Rc`, and the `Prc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::<[u8]>::try_from_box(Box::from([1, 2, 3]))?;
+    /// assert_eq!(&*prc, &[1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_box(boxed: Box<T>) -> Result<Prc<T>, AllocError> {
+        let root = Prc::from_root(Rc::try_new(boxed)?);
+        Ok(root.project(|boxed| &**boxed))
+    }
+}
+
+impl<T> Prc<[T]>
+where
+    T: 'static,
+{
+    /// Constructs a new `Prc<[T]>` from a `Vec<T>`, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<Vec<T>>`, the elements aren't moved into a new allocation: the `Vec` itself
+    /// is stored in the root `Rc`, and the `Prc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::try_from_vec(vec![1, 2, 3])?;
+    /// assert_eq!(&*prc, &[1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Prc<[T]>, AllocError> {
+        Ok(Prc::from_root(Rc::try_new(vec)?).into())
+    }
+
+    /// Constructs a new `Prc<[T]>` from the items of an iterator,
+    /// returning an error if any allocation fails.
+    ///
+    /// This is the fallible version of `FromIterator`.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::try_from_iter((0..4).map(|x| x * 2))?;
+    /// assert_eq!(&*prc, &[0, 2, 4, 6]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Prc<[T]>, AllocError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let mut vec = Vec::new();
+        vec.try_reserve(iter.size_hint().0)
+            .map_err(|_| AllocError)?;
+        for item in iter {
+            if vec.len() == vec.capacity() {
+                vec.try_reserve(1).map_err(|_| AllocError)?;
+            }
+            vec.push(item);
+        }
+        Prc::try_from_vec(vec)
+    }
+}
+
+impl Prc<str> {
+    /// Constructs a new `Prc<str>` from a `String`, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<String>`, the text isn't moved into a new allocation: the `String` itself
+    /// is stored in the root `Rc`, and the `Prc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::prc::Prc;
+    ///
+    /// let prc = Prc::try_from_string(String::from("pared"))?;
+    /// assert_eq!(&*prc, "pared");
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_string(string: String) -> Result<Prc<str>, AllocError> {
+        Ok(Prc::from_root(Rc::try_new(string)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fallible_constructors() {
+        let value = Prc::try_new((1u8, 2u16)).unwrap();
+        assert_eq!(*value.project(|x| &x.1), 2);
+
+        let boxed = Prc::<str>::try_from_box(Box::from("boxed")).unwrap();
+        assert_eq!(&*boxed, "boxed");
+
+        let collected = Prc::try_from_iter((0..100).filter(|x| x % 2 == 0)).unwrap();
+        assert_eq!(collected.len(), 50);
+        assert_eq!(collected[49], 98);
+
+        let string = Prc::try_from_string(String::from("string")).unwrap();
+        assert_eq!(&*string, "string");
+        assert_eq!(
+            Prc::root_type_id(&string),
+            Some(core::any::TypeId::of::<String>())
+        );
+    }
+}
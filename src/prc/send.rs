@@ -0,0 +1,112 @@
+//! Moving a uniquely owned `Prc` to another thread.
+
+use core::{fmt, marker::Send, ops::Deref};
+
+use super::Prc;
+
+/// A uniquely owned [`Prc`] that can be sent to another thread.
+///
+/// `Prc` can't be sent to other threads, since its clones would update the same reference
+/// counts without synchronization. A `Prc` that is the only pointer to its allocation, with no
+/// other `Prc`s or [`Weak`](super::Weak) pointers, has no such clones, and can be moved to
+/// a thread that becomes its sole user, for example to drop a large value on a worker thread
+/// that's shutting down. `SendPrc` checks that its `Prc` is unique when it's created, and
+/// doesn't allow creating new pointers until it's unwrapped with [`SendPrc::into_inner`].
+///
+/// # Example
+/// ```
+/// use pared::prc::{Prc, SendPrc};
+///
+/// let state = Prc::new((vec![1, 2, 3], "worker"));
+/// let numbers = state.project(|state| &state.0);
+/// drop(state);
+///
+/// // SAFETY: the root, `(Vec<i32>, &str)`, can be sent to other threads
+/// let numbers = unsafe { SendPrc::new(numbers) };
+/// let sum = std::thread::spawn(move || {
+///     let numbers = numbers.into_inner();
+///     numbers.iter().sum::<i32>()
+/// });
+/// assert_eq!(sum.join().unwrap(), 6);
+/// ```
+pub struct SendPrc<T: ?Sized> {
+    prc: Prc<T>,
+}
+
+// SAFETY: the Prc is the only pointer to its allocation, as checked by SendPrc::try_new,
+// and SendPrc doesn't allow creating other pointers to it. The root can be sent to other
+// threads as guaranteed by the callers of SendPrc::try_new, and so can the projected value.
+unsafe impl<T: ?Sized + Send> Send for SendPrc<T> {}
+
+impl<T: ?Sized> SendPrc<T> {
+    /// Wraps `prc` to send it to another thread.
+    ///
+    /// # Panics
+    /// Panics if `prc` isn't unique, see [`Prc::is_unique`].
+    ///
+    /// # Safety
+    /// The root value `prc` was projected from, including everything it owns, must be safe
+    /// to send to another thread, i.e. its type must implement [`Send`].
+    /// For example, a root holding an `Rc` that's shared with other values can't be sent,
+    /// even if it's projected to a field that can.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn new(prc: Prc<T>) -> Self {
+        // SAFETY: guaranteed by the caller
+        match unsafe { Self::try_new(prc) } {
+            Ok(send) => send,
+            Err(prc) => panic!(
+                "SendPrc::new called with a Prc that isn't unique ({:?})",
+                Prc::counts(&prc)
+            ),
+        }
+    }
+
+    /// Wraps `prc` to send it to another thread, or returns it if it isn't unique.
+    ///
+    /// # Safety
+    /// See [`SendPrc::new`].
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
+    /// use pared::prc::{Prc, SendPrc};
+    ///
+    /// let value = Prc::new(5);
+    /// let weak = Prc::downgrade(&value);
+    /// // SAFETY: the root is an `i32`
+    /// let value = unsafe { SendPrc::try_new(value) }.unwrap_err();
+    ///
+    /// drop(weak);
+    /// assert!(unsafe { SendPrc::try_new(value) }.is_ok());
+    /// ```
+    #[inline]
+    pub unsafe fn try_new(prc: Prc<T>) -> Result<Self, Prc<T>> {
+        if Prc::is_unique(&prc) {
+            Ok(Self { prc })
+        } else {
+            Err(prc)
+        }
+    }
+
+    /// Returns the wrapped `Prc`, to be used on the thread it was sent to.
+    #[inline]
+    pub fn into_inner(self) -> Prc<T> {
+        self.prc
+    }
+}
+
+impl<T: ?Sized> Deref for SendPrc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.prc
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SendPrc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendPrc").field(&&*self.prc).finish()
+    }
+}
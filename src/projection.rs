@@ -0,0 +1,57 @@
+//! The bound on projection functions.
+
+/// A function projecting a reference to `T` to a reference to a part of it of type `U`.
+///
+/// This is the bound on the functions passed to [`Parc::project`](crate::sync::Parc::project),
+/// [`Prc::project`](crate::prc::Prc::project) and related methods. It's implemented for all
+/// closures and functions taking `&T` and returning a `&U` borrowed from it (or a `&'static U`),
+/// and can't be implemented for anything else.
+///
+/// The trait exists to give more helpful compiler errors when something else is passed
+/// in place of such a function.
+///
+/// # Example
+#[cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#[cfg_attr(feature = "sync", doc = "```")]
+/// use pared::{sync::Parc, Projection};
+///
+/// fn project_both<T, U>(
+///     parc: &Parc<T>,
+///     first: impl Projection<T, U>,
+///     second: impl Projection<T, U>,
+/// ) -> [Parc<U>; 2]
+/// where
+///     T: Send + Sync,
+///     U: 'static,
+/// {
+///     [parc.project(first), parc.project(second)]
+/// }
+///
+/// let pair = Parc::new((1, 2));
+/// let [first, second] = project_both(&pair, |pair| &pair.0, |pair| &pair.1);
+/// assert_eq!((*first, *second), (1, 2));
+/// ```
+///
+/// Passing anything else explains what's expected:
+#[cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#[cfg_attr(feature = "sync", doc = "```compile_fail,E0277")]
+/// use pared::sync::Parc;
+///
+/// let pair = Parc::new((1, 2));
+/// let first = pair.project(pair.0);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used to project a `&{T}` to a `&{U}`",
+    label = "expected a function taking `&{T}` and returning a reference borrowed from it",
+    note = "projections are usually closures like `|value| &value.field`",
+    note = "to apply a stored `Projector`, use its `apply_*` methods instead"
+)]
+pub trait Projection<T: ?Sized, U: ?Sized>: FnOnce(&T) -> &U + sealed::Sealed<T, U> {}
+
+impl<T: ?Sized, U: ?Sized, F> Projection<T, U> for F where F: FnOnce(&T) -> &U {}
+
+mod sealed {
+    pub trait Sealed<T: ?Sized, U: ?Sized> {}
+
+    impl<T: ?Sized, U: ?Sized, F> Sealed<T, U> for F where F: FnOnce(&T) -> &U {}
+}
@@ -0,0 +1,218 @@
+//! Reusable projections.
+
+use alloc::rc::Rc;
+#[cfg(feature = "sync")]
+use alloc::sync::Arc;
+#[cfg(feature = "sync")]
+use core::marker::{Send, Sync};
+
+use crate::prc::Prc;
+#[cfg(feature = "sync")]
+use crate::sync::Parc;
+
+/// A projection from `T` to `U` that can be stored and applied to any number of pointers.
+///
+/// Unlike the closures passed to [`Parc::project`] and friends, a `Projector` is a plain
+/// function pointer: it's [`Copy`], it can be stored in collections and tables, and a single
+/// `Projector` can create projections from `Arc`s, `Parc`s, `Rc`s and `Prc`s alike.
+///
+/// Non-capturing closures coerce to the function pointer expected by [`Projector::new`].
+///
+/// # Example
+#[cfg_attr(not(feature = "sync"), doc = "```ignore")]
+#[cfg_attr(feature = "sync", doc = "```")]
+/// use std::sync::Arc;
+/// use pared::{sync::Parc, Projector};
+///
+/// struct Config {
+///     name: String,
+///     region: String,
+/// }
+///
+/// let fields = [
+///     ("name", Projector::new(|c: &Config| &c.name)),
+///     ("region", Projector::new(|c: &Config| &c.region)),
+/// ];
+///
+/// let snapshot = Arc::new(Config {
+///     name: "pared".to_owned(),
+///     region: "eu".to_owned(),
+/// });
+/// let views: Vec<(&str, Parc<String>)> = fields
+///     .iter()
+///     .map(|(key, projector)| (*key, projector.apply_arc(&snapshot)))
+///     .collect();
+///
+/// assert_eq!(&*views[1].1, "eu");
+/// ```
+pub struct Projector<T: ?Sized, U: ?Sized> {
+    project: for<'a> fn(&'a T) -> &'a U,
+}
+
+impl<T: ?Sized, U: ?Sized> Projector<T, U> {
+    /// Creates a new `Projector` from a projection function.
+    #[inline]
+    pub const fn new(project: for<'a> fn(&'a T) -> &'a U) -> Self {
+        Self { project }
+    }
+
+    /// Applies the projection to a reference.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::Projector;
+    ///
+    /// let first = Projector::new(|pair: &(u8, u16)| &pair.0);
+    /// assert_eq!(*first.apply(&(1, 2)), 1);
+    /// ```
+    #[inline]
+    pub fn apply<'a>(&self, value: &'a T) -> &'a U {
+        (self.project)(value)
+    }
+
+    /// Constructs a new `Parc<U>` by applying the projection to `arc`.
+    ///
+    /// This is equivalent to [`Parc::from_arc`] with this projection.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use pared::Projector;
+    ///
+    /// let first = Projector::new(|pair: &(u8, u16)| &pair.0);
+    /// let parc = first.apply_arc(&Arc::new((1, 2)));
+    /// assert_eq!(*parc, 1);
+    /// ```
+    #[cfg(feature = "sync")]
+    #[inline]
+    pub fn apply_arc(&self, arc: &Arc<T>) -> Parc<U>
+    where
+        T: Send + Sync + 'static,
+        U: 'static,
+    {
+        Parc::from_arc(arc, self.project)
+    }
+
+    /// Constructs a new `Parc<U>` by applying the projection to `parc`.
+    ///
+    /// This is equivalent to [`Parc::project`] with this projection.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, Projector};
+    ///
+    /// let second = Projector::new(|pair: &(u8, u16)| &pair.1);
+    /// let parc = second.apply_parc(&Parc::new((1, 2)));
+    /// assert_eq!(*parc, 2);
+    /// ```
+    #[cfg(feature = "sync")]
+    #[inline]
+    pub fn apply_parc(&self, parc: &Parc<T>) -> Parc<U>
+    where
+        T: Send + Sync,
+        U: 'static,
+    {
+        parc.project(self.project)
+    }
+
+    /// Constructs a new `Prc<U>` by applying the projection to `rc`.
+    ///
+    /// This is equivalent to [`Prc::from_rc`] with this projection.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use pared::Projector;
+    ///
+    /// let first = Projector::new(|pair: &(u8, u16)| &pair.0);
+    /// let prc = first.apply_rc(&Rc::new((1, 2)));
+    /// assert_eq!(*prc, 1);
+    /// ```
+    #[inline]
+    pub fn apply_rc(&self, rc: &Rc<T>) -> Prc<U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        Prc::from_rc(rc, self.project)
+    }
+
+    /// Constructs a new `Prc<U>` by applying the projection to `prc`.
+    ///
+    /// This is equivalent to [`Prc::project`] with this projection.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{prc::Prc, Projector};
+    ///
+    /// let second = Projector::new(|pair: &(u8, u16)| &pair.1);
+    /// let prc = second.apply_prc(&Prc::new((1, 2)));
+    /// assert_eq!(*prc, 2);
+    /// ```
+    #[inline]
+    pub fn apply_prc(&self, prc: &Prc<T>) -> Prc<U>
+    where
+        U: 'static,
+    {
+        prc.project(self.project)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Clone for Projector<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Copy for Projector<T, U> {}
+
+impl<T: ?Sized, U: ?Sized> core::fmt::Debug for Projector<T, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Projector")
+            .field("project", &(self.project as *const ()))
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, vec::Vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn applies_to_all_pointers() {
+        let projector = Projector::new(|x: &(u8, String)| x.1.as_str());
+        let copy = projector;
+
+        let arc = Arc::new((1, String::from("arc")));
+        let from_arc = projector.apply_arc(&arc);
+        let from_parc = copy.apply_parc(&Parc::new((2, String::from("parc"))));
+        assert_eq!(&*from_arc, "arc");
+        assert_eq!(&*from_parc, "parc");
+        assert_eq!(Arc::strong_count(&arc), 2);
+
+        let rc = Rc::new((3, String::from("rc")));
+        let from_rc = projector.apply_rc(&rc);
+        let from_prc = copy.apply_prc(&Prc::new((4, String::from("prc"))));
+        assert_eq!(&*from_rc, "rc");
+        assert_eq!(&*from_prc, "prc");
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        let _ = format!("{:?}", projector);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn stored_in_collections() {
+        let projectors: Vec<Projector<[u8], u8>> = Vec::from([
+            Projector::new(|s: &[u8]| &s[0]),
+            Projector::new(|s: &[u8]| &s[s.len() - 1]),
+        ]);
+        let parc: Parc<[u8]> = Parc::from([1u8, 2, 3].as_slice());
+        let ends: Vec<u8> = projectors.iter().map(|p| *p.apply_parc(&parc)).collect();
+        assert_eq!(ends, [1, 3]);
+        assert_eq!(Parc::strong_count(&parc), 1);
+    }
+}
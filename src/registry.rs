@@ -0,0 +1,267 @@
+//! A registry of shared services.
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::{any::TypeId, fmt, ptr::NonNull};
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use crate::{
+    erased_ptr::TypeErasedPtr,
+    poison::unpoisoned,
+    sync::{erased_arc::TypeErasedArc, Parc},
+};
+
+/// A thread-safe map of shared services, keyed by their type or by name.
+///
+/// Services are stored as type-erased `Parc`s, keeping their roots alive for as long as they're
+/// registered, and retrieved as the `Parc<T>` they were registered as. A service may be
+/// projected from a larger value; e.g. a plugin can export a part of its state while the
+/// registry keeps the whole plugin alive.
+///
+/// Besides creating registries with [`Global::new`], a process-wide registry is available
+/// through [`Global::instance`] for application-wide singletons.
+///
+/// # Example
+/// ```
+/// use pared::{registry::Global, sync::Parc};
+///
+/// struct Plugin {
+///     name: String,
+///     greeting: String,
+/// }
+///
+/// let registry = Global::new();
+/// let plugin = Parc::new(Plugin { name: "hello".to_owned(), greeting: "Hi!".to_owned() });
+/// registry.insert_named("greeting", plugin.project(|p| p.greeting.as_str()));
+/// registry.insert(plugin);
+///
+/// assert_eq!(registry.get::<Plugin>().unwrap().name, "hello");
+/// assert_eq!(&*registry.get_named::<str>("greeting").unwrap(), "Hi!");
+/// // Looking a service up as the wrong type fails
+/// assert!(registry.get_named::<String>("greeting").is_none());
+/// ```
+#[derive(Default)]
+pub struct Global {
+    services: RwLock<Services>,
+}
+
+#[derive(Default)]
+struct Services {
+    by_type: HashMap<TypeId, Service>,
+    by_name: HashMap<Cow<'static, str>, Service>,
+}
+
+/// A type-erased `Parc<T>`, which remembers `T` to be restored.
+struct Service {
+    arc: TypeErasedArc,
+    projected: TypeErasedPtr,
+    type_id: TypeId,
+}
+
+// SAFETY: services are only created from `Parc<T>`s where `T: Send + Sync`,
+// which are `Send` and `Sync` themselves
+unsafe impl Send for Service {}
+// SAFETY: see above
+unsafe impl Sync for Service {}
+
+impl Service {
+    #[inline]
+    fn new<T>(service: Parc<T>) -> Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        Self {
+            projected: TypeErasedPtr::new(Parc::as_ptr(&service)),
+            type_id: TypeId::of::<T>(),
+            arc: Parc::into_erased(service),
+        }
+    }
+
+    /// Returns a clone of the `Parc` this service was created from, if it's a `Parc<T>`.
+    #[inline]
+    fn get<T>(&self) -> Option<Parc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        if self.type_id != TypeId::of::<T>() {
+            return None;
+        }
+        // SAFETY: the type check above guarantees that `projected` was erased from a `*const T`,
+        // which came from a `Parc` backed by `arc`, so it's non-null and owned by `arc`
+        Some(unsafe {
+            let projected = NonNull::new_unchecked(self.projected.as_ptr::<T>().cast_mut());
+            Parc::from_erased(self.arc.clone(), projected)
+        })
+    }
+}
+
+impl Global {
+    /// Creates an empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide registry.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{registry::Global, sync::Parc};
+    ///
+    /// struct Settings {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// Global::instance().insert(Parc::new(Settings { verbose: true }));
+    /// assert!(Global::instance().get::<Settings>().unwrap().verbose);
+    /// ```
+    pub fn instance() -> &'static Global {
+        static INSTANCE: OnceLock<Global> = OnceLock::new();
+        INSTANCE.get_or_init(Global::new)
+    }
+
+    /// Registers `service` under its type, returning whether a service of the same type
+    /// was replaced.
+    pub fn insert<T>(&self, service: Parc<T>) -> bool
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let service = Service::new(service);
+        let previous = unpoisoned(self.services.write())
+            .by_type
+            .insert(TypeId::of::<T>(), service);
+        // Drop the previous service outside of the lock
+        previous.is_some()
+    }
+
+    /// Returns the service registered under type `T`.
+    #[inline]
+    pub fn get<T>(&self) -> Option<Parc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        unpoisoned(self.services.read())
+            .by_type
+            .get(&TypeId::of::<T>())?
+            .get()
+    }
+
+    /// Unregisters the service registered under type `T`, returning it.
+    pub fn remove<T>(&self) -> Option<Parc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let service = unpoisoned(self.services.write())
+            .by_type
+            .remove(&TypeId::of::<T>())?;
+        service.get()
+    }
+
+    /// Registers `service` under `name`, returning whether a service with the same name
+    /// was replaced.
+    pub fn insert_named<T>(&self, name: impl Into<Cow<'static, str>>, service: Parc<T>) -> bool
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let service = Service::new(service);
+        let previous = unpoisoned(self.services.write())
+            .by_name
+            .insert(name.into(), service);
+        previous.is_some()
+    }
+
+    /// Returns the service registered under `name`, if there is one and it's a `T`.
+    #[inline]
+    pub fn get_named<T>(&self, name: &str) -> Option<Parc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        unpoisoned(self.services.read()).by_name.get(name)?.get()
+    }
+
+    /// Unregisters the service registered under `name`, returning whether there was one.
+    pub fn remove_named(&self, name: &str) -> bool {
+        let previous = unpoisoned(self.services.write()).by_name.remove(name);
+        previous.is_some()
+    }
+
+    /// Returns `true` if a service is registered under `name`.
+    #[inline]
+    pub fn contains_named(&self, name: &str) -> bool {
+        unpoisoned(self.services.read()).by_name.contains_key(name)
+    }
+
+    /// Returns the number of registered services.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let services = unpoisoned(self.services.read());
+        services.by_type.len() + services.by_name.len()
+    }
+
+    /// Returns `true` if no services are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Unregisters all services.
+    pub fn clear(&self) {
+        let services = core::mem::take(&mut *unpoisoned(self.services.write()));
+        drop(services);
+    }
+}
+
+impl fmt::Debug for Global {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let services = unpoisoned(self.services.read());
+        let mut names: Vec<&str> = services.by_name.keys().map(|n| &**n).collect();
+        names.sort_unstable();
+        f.debug_struct("Global")
+            .field("types", &services.by_type.len())
+            .field("names", &names)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn typed_and_named() {
+        let registry = Global::new();
+        assert!(registry.is_empty());
+
+        let config = Parc::new((8u16, String::from("name")));
+        assert!(!registry.insert(config.project(|c| &c.0)));
+        assert!(registry.insert(config.project(|c| &c.0)));
+        assert!(!registry.insert_named(String::from("name"), config.project(|c| c.1.as_str())));
+
+        assert_eq!(registry.get::<u16>().as_deref(), Some(&8));
+        assert!(registry.get::<u32>().is_none());
+        assert_eq!(registry.get_named::<str>("name").as_deref(), Some("name"));
+        assert!(registry.get_named::<u16>("name").is_none());
+        assert!(registry.contains_named("name"));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(Parc::strong_count(&config), 3);
+        assert_eq!(
+            format!("{:?}", registry),
+            "Global { types: 1, names: [\"name\"] }"
+        );
+
+        assert_eq!(registry.remove::<u16>().as_deref(), Some(&8));
+        assert!(registry.remove::<u16>().is_none());
+        assert!(registry.remove_named("name"));
+        assert!(!registry.remove_named("name"));
+        assert_eq!(Parc::strong_count(&config), 1);
+
+        registry.insert_named("again", config.clone());
+        registry.clear();
+        assert!(registry.is_empty());
+        assert_eq!(Parc::strong_count(&config), 1);
+    }
+}
@@ -0,0 +1,331 @@
+//! Slabs of shared values addressed by generational keys.
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    marker::{Send, Sync},
+};
+
+use crate::sync::{Parc, Weak};
+
+// Don't bother sweeping dead slots before the slab has at least this many slots
+const MIN_SWEEP_LEN: usize = 32;
+
+/// A stable key of a value stored in a [`ParcSlab`].
+///
+/// Keys carry the generation of their slot, so a key of a removed or dropped value
+/// never refers to a value inserted into the same slot later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    index: u32,
+    generation: u32,
+}
+
+impl Key {
+    /// Returns the index of the slot this key refers to.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns the generation of the slot this key refers to.
+    #[inline]
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+/// A table of shared values, which hands out a [`Parc`] and a stable [`Key`] for every
+/// inserted value.
+///
+/// The slab only holds [`Weak`] pointers to its values: a value is freed as soon as the last
+/// `Parc` pointing to it is dropped, after which [`ParcSlab::get`] returns `None` for its key.
+/// Slots of freed values are swept for reuse by later insertions, with a new generation so that
+/// old keys stay invalid.
+///
+/// # Example
+/// ```
+/// use pared::{slab::ParcSlab, sync::Parc};
+///
+/// struct Connection {
+///     peer: String,
+/// }
+///
+/// let mut connections = ParcSlab::new();
+/// let (key, connection) = connections.insert(Connection { peer: "10.0.0.1".to_owned() });
+/// assert_eq!(connections.get(key).unwrap().peer, "10.0.0.1");
+///
+/// // Closing the connection frees it, and its key no longer resolves
+/// drop(connection);
+/// assert!(connections.get(key).is_none());
+///
+/// // Once its slot is swept, the slot is reused with a new generation
+/// connections.sweep();
+/// let (reused, _connection) = connections.insert(Connection { peer: "10.0.0.2".to_owned() });
+/// assert_eq!(reused.index(), key.index());
+/// assert!(connections.get(key).is_none());
+/// ```
+pub struct ParcSlab<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    sweep_at: usize,
+}
+
+struct Slot<T> {
+    generation: u32,
+    value: Option<Weak<T>>,
+}
+
+impl<T> Slot<T> {
+    #[inline]
+    fn get(&self, generation: u32) -> Option<&Weak<T>> {
+        self.value
+            .as_ref()
+            .filter(|_| self.generation == generation)
+    }
+
+    #[inline]
+    fn vacate(&mut self) -> Option<Weak<T>> {
+        self.generation = self.generation.wrapping_add(1);
+        self.value.take()
+    }
+}
+
+impl<T> ParcSlab<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Creates an empty slab.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            sweep_at: MIN_SWEEP_LEN,
+        }
+    }
+
+    /// Inserts `value`, returning its key and the first `Parc` pointing to it.
+    ///
+    /// # Panics
+    /// Panics if the slab would have more than `u32::MAX` slots.
+    pub fn insert(&mut self, value: T) -> (Key, Parc<T>) {
+        let parc = Parc::new(value);
+        let key = self.insert_parc(&parc);
+        (key, parc)
+    }
+
+    /// Inserts a value that's already shared, returning its key.
+    ///
+    /// The value is looked up with [`ParcSlab::get`] for as long as `value` or any other `Parc`
+    /// pointing to it is alive. Projections are kept as they are, so a slab can also hold
+    /// parts of larger values.
+    ///
+    /// # Panics
+    /// Panics if the slab would have more than `u32::MAX` slots.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{slab::ParcSlab, sync::Parc};
+    ///
+    /// let world = Parc::new((String::from("player"), String::from("enemy")));
+    /// let mut names = ParcSlab::new();
+    /// let player = names.insert_parc(&world.project(|world| &world.0));
+    /// assert_eq!(&*names.get(player).unwrap(), "player");
+    /// ```
+    pub fn insert_parc(&mut self, value: &Parc<T>) -> Key {
+        if self.free.is_empty() && self.slots.len() >= self.sweep_at {
+            self.sweep();
+        }
+        let weak = Parc::downgrade(value);
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = u32::try_from(self.slots.len()).expect("too many slots in a ParcSlab");
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: None,
+                });
+                index
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        slot.value = Some(weak);
+        Key {
+            index,
+            generation: slot.generation,
+        }
+    }
+
+    /// Returns a `Parc` pointing to the value with `key`, if it's still alive.
+    #[inline]
+    pub fn get(&self, key: Key) -> Option<Parc<T>> {
+        self.slots.get(key.index())?.get(key.generation)?.upgrade()
+    }
+
+    /// Returns `true` if the value with `key` is still alive.
+    #[inline]
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.slots
+            .get(key.index())
+            .and_then(|slot| slot.get(key.generation))
+            .is_some_and(|weak| weak.is_alive())
+    }
+
+    /// Removes the value with `key` from the slab, returning a `Parc` pointing to it
+    /// if it's still alive.
+    ///
+    /// The value itself stays alive for as long as any `Parc` points to it,
+    /// but `key` no longer refers to it.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::slab::ParcSlab;
+    ///
+    /// let mut entities = ParcSlab::new();
+    /// let (key, _entity) = entities.insert("goblin");
+    /// assert_eq!(entities.remove(key).as_deref(), Some(&"goblin"));
+    /// assert!(entities.get(key).is_none());
+    /// assert!(entities.remove(key).is_none());
+    /// ```
+    pub fn remove(&mut self, key: Key) -> Option<Parc<T>> {
+        let slot = self.slots.get_mut(key.index())?;
+        slot.get(key.generation)?;
+        let weak = slot.vacate()?;
+        self.free.push(key.index);
+        weak.upgrade()
+    }
+
+    /// Returns an iterator over the keys and live values in the slab, ordered by their slots.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::slab::ParcSlab;
+    ///
+    /// let mut scores = ParcSlab::new();
+    /// let (_, kept) = scores.insert(10);
+    /// drop(scores.insert(20));
+    /// let (_, also_kept) = scores.insert(30);
+    ///
+    /// let live: Vec<i32> = scores.iter().map(|(_, score)| *score).collect();
+    /// assert_eq!(live, [10, 30]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Key, Parc<T>)> + '_ {
+        self.slots.iter().zip(0..).filter_map(|(slot, index)| {
+            let parc = slot.value.as_ref()?.upgrade()?;
+            let key = Key {
+                index,
+                generation: slot.generation,
+            };
+            Some((key, parc))
+        })
+    }
+
+    /// Returns the number of occupied slots.
+    ///
+    /// This includes the slots of values that have already been freed,
+    /// but haven't been swept yet; see [`ParcSlab::sweep`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns `true` if no slots are occupied.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Frees the slots of all values that have already been freed for reuse,
+    /// returning the number of freed slots.
+    ///
+    /// This also happens automatically when inserting into a slab without free slots.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::slab::ParcSlab;
+    ///
+    /// let mut slab = ParcSlab::new();
+    /// let (_, kept) = slab.insert("kept");
+    /// drop(slab.insert("dropped"));
+    ///
+    /// assert_eq!(slab.len(), 2);
+    /// assert_eq!(slab.sweep(), 1);
+    /// assert_eq!(slab.len(), 1);
+    /// ```
+    pub fn sweep(&mut self) -> usize {
+        let before = self.free.len();
+        for (slot, index) in self.slots.iter_mut().zip(0..) {
+            if slot.value.as_ref().is_some_and(|weak| !weak.is_alive()) {
+                slot.vacate();
+                self.free.push(index);
+            }
+        }
+        self.sweep_at = MIN_SWEEP_LEN.max(self.len() * 2);
+        self.free.len() - before
+    }
+}
+
+impl<T> Default for ParcSlab<T>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ParcSlab<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParcSlab")
+            .field("len", &(self.slots.len() - self.free.len()))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn keys_are_generational() {
+        let mut slab = ParcSlab::default();
+        let (a, a_value) = slab.insert(1);
+        let (b, b_value) = slab.insert(2);
+        assert_eq!((a.index(), b.index()), (0, 1));
+        assert!(slab.contains_key(a));
+
+        drop(a_value);
+        assert!(!slab.contains_key(a));
+        assert_eq!(slab.len(), 2);
+        assert_eq!(slab.sweep(), 1);
+        assert_eq!(format!("{:?}", slab), "ParcSlab { len: 1, .. }");
+
+        let (c, _c_value) = slab.insert(3);
+        assert_eq!(c.index(), a.index());
+        assert_eq!(c.generation(), a.generation() + 1);
+        assert!(slab.get(a).is_none());
+        assert!(slab.remove(a).is_none());
+        assert_eq!(slab.get(c).as_deref(), Some(&3));
+
+        assert_eq!(slab.remove(b).as_deref(), Some(&2));
+        assert!(!slab.contains_key(b));
+        assert_eq!(*b_value, 2);
+        let keys: Vec<Key> = slab.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, [c]);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn sweeps_dead_slots_on_insert() {
+        let mut slab = ParcSlab::new();
+        for i in 0..MIN_SWEEP_LEN * 4 {
+            slab.insert(i);
+        }
+        assert!(slab.slots.len() <= MIN_SWEEP_LEN);
+        assert!(slab.iter().next().is_none());
+    }
+}
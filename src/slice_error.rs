@@ -0,0 +1,73 @@
+//! The error returned when projecting to an invalid subslice.
+
+use core::ops::{Bound, Range, RangeBounds};
+
+/// An error returned when slicing a projected slice or string with an invalid range.
+///
+/// Returned by [`Prc::slice`] and [`Prc::split_at`] on slices and strings.
+///
+/// # Example
+/// ```
+/// use pared::{prc::Prc, SliceError};
+///
+/// let prc: Prc<[u8]> = Prc::from(vec![1, 2, 3]);
+/// let error: SliceError = prc.slice(1..=3).unwrap_err();
+/// assert_eq!((error.len, error.range.clone()), (3, 1..4));
+/// assert_eq!(error.to_string(), "range 1..4 is out of bounds for length 3");
+/// ```
+///
+/// [`Prc::slice`]: crate::prc::Prc::slice
+/// [`Prc::split_at`]: crate::prc::Prc::split_at
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SliceError {
+    /// The length of the slice or string, in elements or bytes.
+    pub len: usize,
+    /// The requested range, with inclusive and unbounded ends resolved against `len`.
+    ///
+    /// Ends that would overflow saturate at `usize::MAX`.
+    pub range: Range<usize>,
+}
+
+impl SliceError {
+    pub(crate) fn new<R: RangeBounds<usize>>(len: usize, range: &R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        Self {
+            len,
+            range: start..end,
+        }
+    }
+
+    /// Returns `true` if the range is out of bounds or decreasing, and `false` if it only failed
+    /// because it doesn't lie on `char` boundaries of a string.
+    #[inline]
+    pub fn is_out_of_bounds(&self) -> bool {
+        self.range.start > self.range.end || self.range.end > self.len
+    }
+}
+
+impl core::fmt::Display for SliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Range { start, end } = self.range;
+        if self.is_out_of_bounds() {
+            write!(
+                f,
+                "range {start}..{end} is out of bounds for length {}",
+                self.len
+            )
+        } else {
+            write!(f, "range {start}..{end} doesn't lie on char boundaries")
+        }
+    }
+}
+
+impl core::error::Error for SliceError {}
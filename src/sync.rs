@@ -3,11 +3,13 @@
 //! Available pointer types:
 //! - [`Parc`]
 //! - [`Weak`]
+//! - [`ParcRef`]
+//! - [`RawParc`]
 //!
 //! # Example
 //! ```
 //! # use std::sync::Arc;
-//! use pared::sync::{Parc, Weak};
+//! use pared::sync::Parc;
 //! fn accepts_parc(parc: Parc<u8>) {}
 //!
 //! // Parc can be created by projecting references from an Arc
@@ -92,23 +94,51 @@
 //! println!("{}", &*z); // printing garbage, accessing `s` after it’s freed
 //! ```
 
-mod erased_arc;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod cache;
+mod dom;
+pub(crate) mod erased_arc;
+mod external;
+#[cfg(feature = "allocator-api")]
+mod fallible;
+mod ffi;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "bytemuck")]
+mod pod;
+mod project_mut;
+#[cfg(feature = "smallvec")]
+mod root_set;
+#[cfg(feature = "unique-rc-arc")]
+mod unique;
 
-use alloc::sync::Arc;
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 use core::{
     clone::Clone,
     cmp::{Eq, Ord, PartialEq, PartialOrd},
     convert::{AsRef, From, Into},
     hash::Hash,
     iter::{FromIterator, IntoIterator},
-    marker::{Send, Sized, Sync, Unpin},
+    marker::{Copy, Send, Sized, Sync, Unpin},
     ops::Deref,
     ops::FnOnce,
     option::{Option, Option::Some},
     ptr::NonNull,
 };
 
-use erased_arc::{TypeErasedArc, TypeErasedWeak};
+use crate::{erased_ptr::TypeErasedPtr, vtable::RcVTable, Counts, DebugInfo, Projection};
+pub use cache::SmallCache;
+use erased_arc::TypeErasedArc;
+#[cfg(feature = "weak")]
+use erased_arc::TypeErasedWeak;
+pub use project_mut::ParcMutGuard;
+#[cfg(feature = "smallvec")]
+pub use root_set::RootSet;
+#[cfg(feature = "unique-rc-arc")]
+pub use unique::UniqueParc;
 
 /// Projected atomic reference counted pointer.
 ///
@@ -121,7 +151,7 @@ use erased_arc::{TypeErasedArc, TypeErasedWeak};
 /// # Example
 /// ```
 /// # use std::sync::Arc;
-/// use pared::sync::{Parc, Weak};
+/// use pared::sync::Parc;
 /// fn accepts_parc(parc: Parc<u8>) {}
 ///
 /// // Parc can be created by projecting references from an Arc
@@ -139,6 +169,53 @@ use erased_arc::{TypeErasedArc, TypeErasedWeak};
 /// });
 /// ```
 ///
+/// # Lifetimes
+///
+/// A `Parc` doesn't know the type of its root, so it can't carry the lifetimes the root
+/// borrows from either. Roots and projected types must therefore be `'static`: otherwise,
+/// a `Parc<u8>` projected from an `Arc<Parser<'a>>` could outlive `'a` and drop the parser
+/// after the data it borrows is gone.
+///
+/// ```compile_fail,E0597
+/// use std::sync::Arc;
+/// use pared::sync::Parc;
+///
+/// struct Parser<'a> {
+///     input: &'a str,
+///     depth: u8,
+/// }
+///
+/// let depth: Parc<u8>;
+/// {
+///     let input = String::from("[[]]");
+///     let parser = Arc::new(Parser { input: &input, depth: 2 });
+///     // Error: `input` doesn't live long enough
+///     depth = Parc::from_arc(&parser, |parser| &parser.depth);
+/// }
+/// ```
+///
+/// Values borrowing from other parts of the root, like a `Cow<'a, str>` borrowing from
+/// a source string stored next to it, can't be described with lifetimes either.
+/// Instead, store the owned data along with offsets or ranges into it, and project
+/// to the borrowed parts when they're needed:
+///
+/// ```
+/// use std::ops::Range;
+/// use pared::sync::Parc;
+///
+/// struct Document {
+///     source: String,
+///     // Instead of `title: &'a str` borrowing from `source`
+///     title: Range<usize>,
+/// }
+///
+/// let document = Parc::new(Document { source: "# Title\ntext".to_owned(), title: 2..7 });
+/// let title: Parc<str> = document.project(|doc| &doc.source[doc.title.clone()]);
+/// assert_eq!(&*title, "Title");
+/// ```
+///
+/// Fields holding `&'static` references, such as `&'static str`, can be projected to as usual.
+///
 /// Parc can only be created from `Arc`s (or other `Parc`s) for `T: Send + Sync`.
 ///
 /// ```compile_fail,E0277
@@ -178,13 +255,118 @@ where
     /// ```
     #[inline]
     pub fn new(value: T) -> Parc<T> {
-        Arc::new(value).into()
+        let arc = Arc::new(value);
+        let projected = NonNull::from(&*arc);
+        Parc {
+            arc: TypeErasedArc::new_any(arc),
+            projected,
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Parc<T>
+where
+    T: zeroize::Zeroize + Send + Sync + 'static,
+{
+    /// Constructs a new `Parc<T>` that zeroizes `value` when the last pointer to it is dropped.
+    ///
+    /// The value is stored wrapped in [`zeroize::Zeroizing`], so any projections
+    /// from the returned `Parc` keep the wipe-on-drop behavior of the whole allocation.
+    ///
+    /// Note that this only applies to the allocation backing the `Parc`: dropping a single `Parc`
+    /// doesn't wipe anything while other pointers to the same allocation exist. Projections from
+    /// `Arc`s holding a [`zeroize::ZeroizeOnDrop`] type behave the same way, as the allocation is
+    /// always dropped as its original type.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let key = Parc::new_zeroizing([0x42u8; 32]);
+    /// let first_half = key.project(|k| &k[..16]);
+    /// drop(key);
+    /// // The key is wiped only after `first_half` is dropped as well
+    /// assert_eq!(&*first_half, &[0x42u8; 16]);
+    /// ```
+    pub fn new_zeroizing(value: T) -> Parc<T> {
+        Parc::from_root(Arc::new(zeroize::Zeroizing::new(value))).project(|value| &**value)
+    }
+}
+
+impl<T> Parc<T>
+where
+    T: ?Sized + Sync,
+{
+    /// Constructs a new `Parc<T>` pointing to `'static` data, without allocating.
+    ///
+    /// The returned `Parc` (and any projections and [`Weak`] pointers created from it) doesn't
+    /// count references at all, as the data is never freed: cloning and dropping it doesn't
+    /// touch any atomics, [`Parc::strong_count`] reports `usize::MAX`, [`Parc::weak_count`]
+    /// reports `0` and [`Weak::upgrade`] always succeeds. This allows mixing literals and
+    /// heap-allocated data behind a single `Parc<T>` type.
+    ///
+    /// As a `const fn`, this can be used to initialize constants and statics;
+    /// see also [`parc_str!`](crate::parc_str).
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// static LIMITS: [u32; 2] = [10, 100];
+    /// static MAX: Parc<[u32; 2]> = Parc::from_static(&LIMITS);
+    ///
+    /// let max = MAX.project(|limits| &limits[1]);
+    /// assert_eq!(*max, 100);
+    /// assert_eq!(Parc::root_allocation_size(&max), 0);
+    /// ```
+    #[inline]
+    pub const fn from_static(value: &'static T) -> Parc<T> {
+        Parc {
+            arc: TypeErasedArc::from_static(value),
+            // SAFETY: references are never null
+            projected: unsafe { NonNull::new_unchecked(value as *const T as *mut T) },
+        }
     }
 }
 
+/// Creates a `Parc<str>` pointing to a string literal, without allocating.
+///
+/// This is a shorthand for [`Parc::from_static`], and can be used to initialize
+/// constants and statics.
+///
+/// # Example
+/// ```
+/// use pared::{parc_str, sync::Parc};
+///
+/// struct Config {
+///     name: Parc<str>,
+/// }
+///
+/// const DEFAULT_NAME: Parc<str> = parc_str!("default");
+///
+/// let configs = [
+///     Config { name: DEFAULT_NAME },
+///     Config { name: Parc::from(String::from("custom")) },
+/// ];
+/// assert_eq!(&*configs[0].name, "default");
+/// assert_eq!(&*configs[1].name, "custom");
+/// ```
+#[macro_export]
+macro_rules! parc_str {
+    ($text:expr $(,)?) => {
+        $crate::sync::Parc::<str>::from_static($text)
+    };
+}
+
 impl<T: ?Sized> Parc<T> {
     /// Constructs a new `Parc<T>` from an existing `Arc<T>` by projecting a field.
     ///
+    /// The `Arc` is only borrowed and cloned internally, so code holding a `&Arc<U>`,
+    /// like handlers that receive one, can project from it without cloning it first.
+    /// An owned `Arc` can be converted without touching its reference count
+    /// with `Parc::from`, and then projected.
+    ///
     /// # Panics
     /// If `f` panics, the panic is propagated to the caller and the arc won't be cloned.
     ///
@@ -208,8 +390,8 @@ impl<T: ?Sized> Parc<T> {
     pub fn from_arc<U, F>(arc: &Arc<U>, project: F) -> Self
     where
         T: 'static,
-        U: ?Sized + Send + Sync,
-        F: FnOnce(&U) -> &T,
+        U: ?Sized + Send + Sync + 'static,
+        F: Projection<U, T>,
     {
         let projected = project(arc);
         // SAFETY: the returned reference always converts to a non-null pointer.
@@ -252,13 +434,13 @@ impl<T: ?Sized> Parc<T> {
     #[inline]
     pub fn try_from_arc<U, E, F>(arc: &Arc<U>, project: F) -> Result<Self, E>
     where
-        U: ?Sized + Sync + Send,
+        U: ?Sized + Sync + Send + 'static,
         T: 'static,
         F: FnOnce(&U) -> Result<&T, E>,
     {
         let projected = project(arc)?;
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const T as *mut T) };
         Ok(Self {
             arc: TypeErasedArc::new(arc.clone()),
@@ -266,6 +448,38 @@ impl<T: ?Sized> Parc<T> {
         })
     }
 
+    /// Constructs a new `Parc<T>` from an existing `Arc<U>` by projecting a field,
+    /// keeping the ability to get the root back with [`Parc::as_any_root`].
+    ///
+    /// `Parc`s created with [`Parc::new`] have this ability as well.
+    ///
+    /// # Panics
+    /// If `f` panics, the panic is propagated to the caller and the arc won't be cloned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use pared::sync::Parc;
+    ///
+    /// let arc = Arc::new((5u64, "five"));
+    /// let parc = Parc::from_arc_any(&arc, |tuple| &tuple.1);
+    /// let root = Parc::as_any_root(&parc).unwrap();
+    /// assert_eq!(root.downcast_ref::<(u64, &str)>(), Some(&(5, "five")));
+    /// ```
+    #[inline]
+    pub fn from_arc_any<U, F>(arc: &Arc<U>, project: F) -> Self
+    where
+        T: 'static,
+        U: Send + Sync + 'static,
+        F: Projection<U, T>,
+    {
+        let projected = NonNull::from(project(arc));
+        Self {
+            arc: TypeErasedArc::new_any(arc.clone()),
+            projected,
+        }
+    }
+
     /// Constructs a new `Parc<T>` from an existing `Parc<T>` by projecting a field.
     ///
     /// # Panics
@@ -278,6 +492,16 @@ impl<T: ?Sized> Parc<T> {
     /// let projected = parc.project(|tuple| &tuple.0);
     /// ```
     ///
+    /// The projection can be any closure, including one capturing its environment,
+    /// such as an index computed beforehand:
+    /// ```
+    /// use pared::sync::Parc;
+    /// let parc = Parc::new(vec![3, 1, 4, 1, 5]);
+    /// let index = parc.iter().position(|&x| x == 4).unwrap();
+    /// let four = parc.project(|v| &v[index]);
+    /// assert_eq!(*four, 4);
+    /// ```
+    ///
     /// Note that references to local variables cannot be returned from the `project` function:
     /// ```compile_fail,E0597
     /// use pared::sync::Parc;
@@ -290,7 +514,7 @@ impl<T: ?Sized> Parc<T> {
     where
         T: Send + Sync,
         U: ?Sized + 'static,
-        F: FnOnce(&T) -> &U,
+        F: Projection<T, U>,
     {
         let projected = project(self);
         // SAFETY: the returned reference always converts to a non-null pointer.
@@ -298,6 +522,8 @@ impl<T: ?Sized> Parc<T> {
         // because the lifetime of the reference returned by `F` must be either the lifetime
         // of the local reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const U as *mut U) };
+        #[cfg(feature = "debug-cycles")]
+        crate::debug::record_projection(&self.arc, projected.as_ptr() as *const ());
         Parc::<U> {
             arc: self.arc.clone(),
             projected,
@@ -336,19 +562,240 @@ impl<T: ?Sized> Parc<T> {
         F: for<'x> FnOnce(&'x T) -> Result<&'x U, E>,
     {
         let projected = project(self)?;
-        // SAFETY: fn shouldn't be able to capture any local references
-        // which should mean that the projection done by f is safe
+        // SAFETY: the closure may capture its environment, but the lifetime of the reference
+        // it returns must be either the lifetime of the reference passed to it, or 'static
         let projected = unsafe { NonNull::new_unchecked(projected as *const U as *mut U) };
+        #[cfg(feature = "debug-cycles")]
+        crate::debug::record_projection(&self.arc, projected.as_ptr() as *const ());
         Ok(Parc::<U> {
             arc: self.arc.clone(),
             projected,
         })
     }
+    /// Constructs a new `Parc<U>` sharing ownership with this `Parc`, pointing to `projected`.
+    ///
+    /// # Safety
+    /// `projected` must point into the allocation backing this `Parc`, and it must stay valid
+    /// for as long as that allocation is alive.
+    #[inline]
+    unsafe fn project_ref<U: ?Sized>(&self, projected: &U) -> Parc<U> {
+        Parc::<U> {
+            arc: self.arc.clone(),
+            projected: NonNull::from(projected),
+        }
+    }
+
+    /// Constructs a new `Parc<U>` from an existing `Parc<T>` by projecting to the field
+    /// at `offset`.
+    ///
+    /// See [`FieldOffset`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{offset_project, sync::Parc};
+    ///
+    /// let parc = Parc::new((1u8, 2u32));
+    /// let projected = parc.project_offset(offset_project!((u8, u32), 1));
+    /// assert_eq!(*projected, 2);
+    /// ```
+    ///
+    /// [`FieldOffset`]: crate::FieldOffset
+    #[inline]
+    pub fn project_offset<U>(&self, offset: crate::FieldOffset<T, U>) -> Parc<U>
+    where
+        T: Sized,
+        T: Send + Sync,
+        U: 'static,
+    {
+        self.project(|value| offset.apply(value))
+    }
+
+    /// Constructs a new `Parc<T>` pointing to the whole `arc`.
+    #[inline]
+    fn from_root(arc: Arc<T>) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let projected = NonNull::from(&*arc);
+        Self {
+            arc: TypeErasedArc::new(arc),
+            projected,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the type stored in the root `Arc` backing this `Parc`.
+    ///
+    /// The type of the root is known for every `Parc` backed by an `Arc`, including those
+    /// created from borrowed `Arc`s with [`Parc::from_arc`]. `Parc`s created with
+    /// [`Parc::from_static`] don't have a root allocation, so they return `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::any::TypeId;
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((1u8, 2u16));
+    /// let second = parc.project(|pair| &pair.1);
+    /// assert_eq!(Parc::root_type_id(&second), Some(TypeId::of::<(u8, u16)>()));
+    /// ```
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    #[inline]
+    pub fn root_type_id(this: &Self) -> Option<core::any::TypeId> {
+        this.arc.type_id()
+    }
+
+    /// Returns the name of the type stored in the root allocation backing this `Parc`,
+    /// as returned by [`core::any::type_name`].
+    ///
+    /// Unlike [`Parc::root_type_id`], the name is known for every root. It's meant for
+    /// diagnostics only, such as finding out what a leaked pointer keeps alive.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((5u8, String::from("five")));
+    /// let name = parc.project(|pair| &pair.1);
+    /// assert_eq!(Parc::root_type_name(&name), "(u8, alloc::string::String)");
+    /// ```
+    #[cfg(feature = "type-names")]
+    #[inline]
+    pub fn root_type_name(this: &Self) -> &'static str {
+        this.arc.type_name()
+    }
+
+    /// Returns the size in bytes of the root allocation kept alive by this `Parc`.
+    ///
+    /// This includes the whole value stored in the root `Arc`, regardless of what this `Parc`
+    /// projects to, and the reference counts stored alongside it. The layout of `Arc`'s
+    /// allocation isn't guaranteed by the standard library, so the returned size is an estimate
+    /// based on its current implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new([0u8; 1024]);
+    /// let byte = parc.project(|array| &array[0]);
+    /// assert!(Parc::root_allocation_size(&byte) >= 1024);
+    /// ```
+    #[inline]
+    pub fn root_allocation_size(this: &Self) -> usize {
+        this.arc.root_layout().size()
+    }
+
+    /// Returns a snapshot of this `Parc`'s addresses, reference counts and root type,
+    /// for logging the state of a pointer while debugging.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((0u32, [1u8; 4]));
+    /// let projected = parc.project(|pair| &pair.1[2]);
+    ///
+    /// let info = Parc::debug_info(&projected);
+    /// assert_eq!(info.counts.strong, 2);
+    /// assert_eq!(info.offset, Some(6));
+    /// assert_eq!(info.projected_addr, Parc::addr(&projected));
+    /// println!("{info:?}");
+    /// ```
+    pub fn debug_info(this: &Self) -> DebugInfo {
+        DebugInfo::new(
+            Parc::root_ptr(this) as usize,
+            Parc::addr(this),
+            Parc::root_allocation_size(this),
+            Parc::counts(this),
+            Parc::root_type_id(this),
+            #[cfg(feature = "type-names")]
+            Parc::root_type_name(this),
+        )
+    }
+
+    /// Returns the whole root `Arc` backing this `Parc` as `Arc<dyn Any + Send + Sync>`.
+    ///
+    /// This is only possible for `Parc`s created with [`Parc::new`], [`Parc::from_arc_any`]
+    /// or [`Parc::map_root`] and their projections; for all other `Parc`s, this returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((1u8, 2u16));
+    /// let second = parc.project(|pair| &pair.1);
+    /// let root = Parc::as_any_root(&second).unwrap();
+    /// assert_eq!(root.downcast_ref::<(u8, u16)>(), Some(&(1, 2)));
+    ///
+    /// let from_vec: Parc<[u8]> = Parc::from(vec![1, 2]);
+    /// assert!(Parc::as_any_root(&from_vec).is_none());
+    /// ```
+    #[inline]
+    pub fn as_any_root(this: &Self) -> Option<Arc<dyn core::any::Any + Send + Sync>> {
+        this.arc.any_root()
+    }
+
+    /// Replaces the root keeping this `Parc`'s value alive with the `Arc` returned by `f`,
+    /// without changing what the `Parc` points to.
+    ///
+    /// `f` is passed this `Parc`, and is expected to move it into the new root, e.g. to wrap it
+    /// in a larger context. The returned `Parc` and all of its clones and projections then share
+    /// the new root, which can also be retrieved with [`Parc::as_any_root`]. Existing clones
+    /// and [`Weak`] pointers are unaffected, and keep using the old root.
+    ///
+    /// If the new root owns the `Parc`, consider projecting from it with [`Parc::from_arc_any`]
+    /// instead, which doesn't need `unsafe`.
+    ///
+    /// # Safety
+    /// For as long as the new root is alive, i.e. until the last strong pointer to it is
+    /// dropped, the value this `Parc` points to must stay valid for reads and must not be
+    /// mutated, other than through `UnsafeCell`s. This holds if the new root owns this `Parc`,
+    /// or another pointer keeping its value alive, and never drops or replaces it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use pared::sync::Parc;
+    ///
+    /// struct Request {
+    ///     body: Parc<str>,
+    ///     received_at: u64,
+    /// }
+    ///
+    /// let packet = Parc::new(String::from("POST /\n\nhello"));
+    /// let body = packet.project(|packet| &packet[8..]);
+    /// drop(packet);
+    ///
+    /// // SAFETY: the request owns `body` and never replaces it
+    /// let body = unsafe {
+    ///     Parc::map_root(body, |body| Arc::new(Request { body, received_at: 7 }))
+    /// };
+    /// assert_eq!(&*body, "hello");
+    ///
+    /// let root = Parc::as_any_root(&body).unwrap();
+    /// assert_eq!(root.downcast_ref::<Request>().unwrap().received_at, 7);
+    /// ```
+    #[inline]
+    pub unsafe fn map_root<R, F>(this: Parc<T>, f: F) -> Parc<T>
+    where
+        R: Send + Sync + 'static,
+        F: FnOnce(Parc<T>) -> Arc<R>,
+    {
+        let projected = this.projected;
+        Parc {
+            arc: TypeErasedArc::new_any(f(this)),
+            projected,
+        }
+    }
+
     /// Provides a raw pointer to the data.
     ///
     /// The counts are not affected in any way and the `Parc` is not consumed. The pointer is valid for
     /// as long as there are strong counts in the `Parc` or in the underlying `Arc`.
     ///
+    /// The returned pointer is stable: it's the same for every clone of this `Parc` and for
+    /// every [`Weak`] created from it, and it doesn't change when the `Parc` is moved.
+    ///
     /// # Examples
     ///
     /// ```
@@ -365,101 +812,1095 @@ impl<T: ?Sized> Parc<T> {
         NonNull::as_ptr(this.projected)
     }
 
-    /// Creates a new `Weak` pointer to this allocation.
+    /// Returns the address of the projected data.
     ///
-    /// This `Weak` pointer is tied to strong references to the original `Arc`, meaning it's not
-    /// tied to instances of the `Parc` it was created from.
+    /// This is the address of the pointer returned by [`Parc::as_ptr`] without any metadata,
+    /// which makes it convenient for keying maps or comparing with pointers obtained elsewhere.
+    /// The same stability guarantees apply.
     ///
     /// # Example
     /// ```
-    /// # use std::sync::Arc;
     /// use pared::sync::Parc;
-    /// let arc = Arc::new((42,));
-    /// let weak = {
-    ///     let parc = Parc::from_arc(&arc, |tuple| &tuple.0);
-    ///     Parc::downgrade(&parc)
-    /// };
-    /// let stored = weak.upgrade().map(|parc| *parc);
-    /// assert_eq!(stored, Some(42));
+    ///
+    /// let x = Parc::new((5u8, 6u16));
+    /// let y = x.project(|t| &t.1);
+    /// assert_eq!(Parc::addr(&y), &x.1 as *const u16 as usize);
+    /// assert_eq!(Parc::addr(&y), Parc::addr(&y.clone()));
     /// ```
+    #[must_use]
     #[inline]
-    pub fn downgrade(this: &Parc<T>) -> Weak<T> {
-        Weak::<T> {
-            weak: this.arc.downgrade(),
-            projected: this.projected,
-        }
+    pub fn addr(this: &Self) -> usize {
+        Parc::as_ptr(this) as *const () as usize
     }
 
-    /// Gets the number of [`Weak`] pointers to this allocation.
-    ///
-    /// See [`Arc::weak_count`].
+    /// Returns the address of the root value backing this `Parc`.
     ///
-    /// # Safety
-    /// This method by itself is safe, but using it correctly requires extra care.
-    /// Another thread can change the weak count at any time, including potentially between
-    /// calling this method and acting on the result.
+    /// All `Parc`s sharing the same root allocation have the same root address,
+    /// regardless of which parts of it they point to.
     ///
     /// # Example
     /// ```
     /// use pared::sync::Parc;
-    /// let six = Parc::new(6);
-    /// let _weak_six = Parc::downgrade(&six);
     ///
-    /// // Deterministic because we didn't share between threads
-    /// assert_eq!(Parc::weak_count(&six), 1);
+    /// let x = Parc::new((5u8, 6u16));
+    /// let y = x.project(|t| &t.1);
+    /// assert_eq!(Parc::root_addr(&y), Parc::addr(&x));
+    /// assert_ne!(Parc::root_addr(&y), Parc::addr(&y));
     /// ```
-    ///
-    /// [`Arc::weak_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.weak_count
+    #[must_use]
     #[inline]
-    pub fn weak_count(this: &Parc<T>) -> usize {
-        this.arc.weak_count()
+    pub fn root_addr(this: &Self) -> usize {
+        Parc::root_ptr(this) as usize
     }
 
-    /// Gets the number of strong pointers to this allocation.
-    ///
-    /// See [`Arc::strong_count`].
+    /// Compares two `Parc`s by the address of their root value, and then by the address
+    /// of the data they point to.
     ///
-    /// # Safety
-    /// This method by itself is safe, but using it correctly requires extra care.
-    /// Another thread can change the weak count at any time, including potentially between
-    /// calling this method and acting on the result.
+    /// This is a total order that's stable for as long as both `Parc`s are alive, so it can be
+    /// used to always acquire several locks in the same order and avoid deadlocks; see
+    /// [`lock::lock_all`](crate::lock::lock_all) for a helper doing that for [`std::sync::Mutex`].
     ///
     /// # Example
     /// ```
+    /// use std::cmp::Ordering;
     /// use pared::sync::Parc;
-    /// let six = Parc::new(6);
-    /// let _also_six = six.clone();
     ///
-    /// // Deterministic because we didn't share between threads
-    /// assert_eq!(Parc::strong_count(&six), 2);
+    /// let pair = Parc::new((1u8, 2u8));
+    /// let first = pair.project(|pair| &pair.0);
+    /// let second = pair.project(|pair| &pair.1);
+    /// assert_eq!(Parc::cmp_roots(&first, &second), Ordering::Less);
+    /// assert_eq!(Parc::cmp_roots(&first, &pair), Ordering::Equal);
     /// ```
-    ///
-    /// [`Arc::weak_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.strong_count
     #[inline]
-    pub fn strong_count(this: &Parc<T>) -> usize {
-        this.arc.strong_count()
+    pub fn cmp_roots<U: ?Sized>(this: &Parc<T>, other: &Parc<U>) -> core::cmp::Ordering {
+        (Parc::root_addr(this), Parc::addr(this)).cmp(&(Parc::root_addr(other), Parc::addr(other)))
     }
 
-    /// Returns `true` if the two `Parc`s point to the same data, using [`core::ptr::eq`].
-    /// See that function for caveats when comparing `dyn Trait` pointers.
+    /// Consumes the `Parc`, returning its raw parts.
+    ///
+    /// To avoid a memory leak, the parts have to be converted back to a `Parc` using
+    /// [`Parc::from_raw`].
     ///
     /// # Example
     /// ```
     /// use pared::sync::Parc;
     ///
-    /// let five = Parc::new(5);
-    /// let same_five = five.clone();
-    /// let other_five = Parc::new(5);
+    /// let x = Parc::new("hello".to_owned());
+    /// let raw = Parc::into_raw(x);
+    /// assert_eq!(unsafe { &*raw.as_ptr() }, "hello");
     ///
-    /// assert!(Parc::ptr_eq(&five, &same_five));
-    /// assert!(!Parc::ptr_eq(&five, &other_five));
+    /// // SAFETY: `raw` comes from `Parc::into_raw`
+    /// let x = unsafe { Parc::from_raw(raw) };
+    /// assert_eq!(&*x, "hello");
     /// ```
-    ///
-    /// [`core::ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
-    pub fn ptr_eq(this: &Parc<T>, other: &Parc<T>) -> bool {
-        core::ptr::eq(this.projected.as_ptr(), other.projected.as_ptr())
+    #[must_use = "losing the raw parts will leak the underlying allocation"]
+    pub fn into_raw(this: Self) -> RawParc<T> {
+        let (root, vtable) = this.arc.into_raw();
+        RawParc {
+            projected: this.projected,
+            root,
+            vtable,
+        }
     }
-}
+
+    /// Constructs a `Parc<T>` from raw parts obtained from [`Parc::into_raw`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Parc::into_raw`] for a `Parc<T>` with the same `T`.
+    /// Each call to `from_raw` takes ownership of one strong count: it must be balanced
+    /// either by the call to `into_raw` that produced `raw`, or by a call to
+    /// [`Parc::increment_strong_count`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let raw = Parc::into_raw(Parc::new((5u8, 6u16)).project(|t| &t.1));
+    /// // SAFETY: `raw` comes from `Parc::into_raw`
+    /// let parc = unsafe { Parc::from_raw(raw) };
+    /// assert_eq!(*parc, 6);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw(raw: RawParc<T>) -> Self {
+        Self {
+            arc: TypeErasedArc::from_raw(raw.root, raw.vtable),
+            projected: raw.projected,
+        }
+    }
+
+    /// Increments the strong count of the allocation behind `raw` by one.
+    ///
+    /// This is useful when the raw parts of a `Parc` are duplicated while stored elsewhere;
+    /// every duplicate needs to own its own strong count, which is later released by
+    /// [`Parc::decrement_strong_count`] or by converting it back with [`Parc::from_raw`].
+    ///
+    /// See [`Arc::increment_strong_count`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Parc::into_raw`] and the allocation it refers to
+    /// must still be alive (the strong count must be at least 1) for the duration of this call.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let raw = Parc::into_raw(Parc::new(5));
+    /// unsafe {
+    ///     Parc::increment_strong_count(raw);
+    ///
+    ///     let first = Parc::from_raw(raw);
+    ///     assert_eq!(Parc::strong_count(&first), 2);
+    ///     let second = Parc::from_raw(raw);
+    ///     drop(second);
+    ///     assert_eq!(Parc::strong_count(&first), 1);
+    /// }
+    /// ```
+    ///
+    /// [`Arc::increment_strong_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.increment_strong_count
+    #[inline]
+    pub unsafe fn increment_strong_count(raw: RawParc<T>) {
+        (raw.vtable.clone)(raw.root)
+    }
+
+    /// Decrements the strong count of the allocation behind `raw` by one,
+    /// dropping it if this was the last strong reference.
+    ///
+    /// See [`Arc::decrement_strong_count`].
+    ///
+    /// # Safety
+    /// `raw` must have been returned by [`Parc::into_raw`] and the strong count it releases
+    /// must be owned by the caller, either from [`Parc::into_raw`] or from
+    /// [`Parc::increment_strong_count`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new(5);
+    /// let raw = Parc::into_raw(parc.clone());
+    /// assert_eq!(Parc::strong_count(&parc), 2);
+    ///
+    /// unsafe { Parc::decrement_strong_count(raw) };
+    /// assert_eq!(Parc::strong_count(&parc), 1);
+    /// ```
+    ///
+    /// [`Arc::decrement_strong_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.decrement_strong_count
+    #[inline]
+    pub unsafe fn decrement_strong_count(raw: RawParc<T>) {
+        (raw.vtable.drop)(raw.root)
+    }
+
+    /// Creates a new `Weak` pointer to this allocation.
+    ///
+    /// This `Weak` pointer is tied to strong references to the original `Arc`, meaning it's not
+    /// tied to instances of the `Parc` it was created from.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// use pared::sync::Parc;
+    /// let arc = Arc::new((42,));
+    /// let weak = {
+    ///     let parc = Parc::from_arc(&arc, |tuple| &tuple.0);
+    ///     Parc::downgrade(&parc)
+    /// };
+    /// let stored = weak.upgrade().map(|parc| *parc);
+    /// assert_eq!(stored, Some(42));
+    /// ```
+    #[cfg(feature = "weak")]
+    #[inline]
+    pub fn downgrade(this: &Parc<T>) -> Weak<T> {
+        Weak::<T> {
+            weak: this.arc.downgrade(),
+            projected: this.projected,
+        }
+    }
+
+    /// Gets the number of [`Weak`] pointers to this allocation.
+    ///
+    /// See [`Arc::weak_count`].
+    ///
+    /// # Safety
+    /// This method by itself is safe, but using it correctly requires extra care.
+    /// Another thread can change the weak count at any time, including potentially between
+    /// calling this method and acting on the result.
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
+    /// use pared::sync::Parc;
+    /// let six = Parc::new(6);
+    /// let _weak_six = Parc::downgrade(&six);
+    ///
+    /// // Deterministic because we didn't share between threads
+    /// assert_eq!(Parc::weak_count(&six), 1);
+    /// ```
+    ///
+    /// [`Arc::weak_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.weak_count
+    #[inline]
+    pub fn weak_count(this: &Parc<T>) -> usize {
+        this.arc.weak_count()
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    ///
+    /// See [`Arc::strong_count`].
+    ///
+    /// # Safety
+    /// This method by itself is safe, but using it correctly requires extra care.
+    /// Another thread can change the weak count at any time, including potentially between
+    /// calling this method and acting on the result.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    /// let six = Parc::new(6);
+    /// let _also_six = six.clone();
+    ///
+    /// // Deterministic because we didn't share between threads
+    /// assert_eq!(Parc::strong_count(&six), 2);
+    /// ```
+    ///
+    /// [`Arc::weak_count`]: https://doc.rust-lang.org/std/sync/struct.Arc.html#method.strong_count
+    #[inline]
+    pub fn strong_count(this: &Parc<T>) -> usize {
+        this.arc.strong_count()
+    }
+
+    /// Gets both the number of strong and [`Weak`] pointers to this allocation.
+    ///
+    /// # Safety
+    /// This method by itself is safe, but using it correctly requires extra care.
+    /// Another thread can change the counts at any time, including between reading
+    /// the strong and the weak count.
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
+    /// use pared::{sync::Parc, Counts};
+    /// let six = Parc::new(6);
+    /// let _also_six = six.clone();
+    /// let _weak_six = Parc::downgrade(&six);
+    ///
+    /// assert_eq!(Parc::counts(&six), Counts { strong: 2, weak: 1 });
+    /// ```
+    #[inline]
+    pub fn counts(this: &Parc<T>) -> Counts {
+        Counts {
+            strong: Parc::strong_count(this),
+            weak: Parc::weak_count(this),
+        }
+    }
+
+    /// Returns `true` if this is the only `Parc` to its allocation and no [`Weak`] pointers to it
+    /// exist, checking both counts atomically like [`Arc::get_mut`].
+    ///
+    /// # Safety
+    /// This method by itself is safe, but using it correctly requires extra care.
+    /// A `true` result stays valid until this `Parc` is cloned or downgraded. A `false` result
+    /// can become outdated as soon as other threads drop their pointers.
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "weak"), doc = "```ignore")]
+    #[cfg_attr(feature = "weak", doc = "```")]
+    /// use pared::sync::Parc;
+    /// let pair = Parc::new((1, 2));
+    /// let second = pair.project(|pair| &pair.1);
+    /// assert!(!Parc::is_unique(&second));
+    ///
+    /// drop(pair);
+    /// assert!(Parc::is_unique(&second));
+    ///
+    /// let _weak = Parc::downgrade(&second);
+    /// assert!(!Parc::is_unique(&second));
+    /// ```
+    #[inline]
+    pub fn is_unique(this: &Parc<T>) -> bool {
+        this.arc.is_unique()
+    }
+
+    /// Returns `true` if the two `Parc`s point to the same data, using [`core::ptr::eq`].
+    /// See that function for caveats when comparing `dyn Trait` pointers.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let five = Parc::new(5);
+    /// let same_five = five.clone();
+    /// let other_five = Parc::new(5);
+    ///
+    /// assert!(Parc::ptr_eq(&five, &same_five));
+    /// assert!(!Parc::ptr_eq(&five, &other_five));
+    /// ```
+    ///
+    /// [`core::ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
+    pub fn ptr_eq(this: &Parc<T>, other: &Parc<T>) -> bool {
+        core::ptr::eq(this.projected.as_ptr(), other.projected.as_ptr())
+    }
+
+    /// Returns `true` if the two `Parc`s are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// Unlike [`Parc::ptr_eq`], this compares the underlying `Arc`s, which is why it can compare
+    /// `Parc`s of different types.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let tuple = Parc::new((5u8, 6u16));
+    /// let first = tuple.project(|t| &t.0);
+    /// let second = tuple.project(|t| &t.1);
+    /// let other = Parc::new((5u8, 6u16));
+    ///
+    /// assert!(Parc::same_root(&first, &second));
+    /// assert!(!Parc::same_root(&first, &other));
+    /// ```
+    #[inline]
+    pub fn same_root<U: ?Sized>(this: &Parc<T>, other: &Parc<U>) -> bool {
+        core::ptr::eq(Parc::root_ptr(this), Parc::root_ptr(other))
+    }
+
+    /// Returns `true` if this `Parc` is backed by the allocation of `arc`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use pared::sync::Parc;
+    ///
+    /// let arc = Arc::new((5u8, 6u16));
+    /// let parc = Parc::from_arc(&arc, |t| &t.1);
+    ///
+    /// assert!(Parc::is_rooted_in(&parc, &arc));
+    /// assert!(!Parc::is_rooted_in(&parc, &Arc::new(5u8)));
+    /// ```
+    #[inline]
+    pub fn is_rooted_in<U: ?Sized>(this: &Parc<T>, arc: &Arc<U>) -> bool {
+        core::ptr::eq(Parc::root_ptr(this), Arc::as_ptr(arc) as *const ())
+    }
+
+    /// Calls `f` with a reference to the projected value without touching the reference counts.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((5u8, 6u16)).project(|t| &t.1);
+    /// let doubled = Parc::with(&parc, |x| x * 2);
+    /// assert_eq!(doubled, 12);
+    /// ```
+    #[inline]
+    pub fn with<R, F>(this: &Parc<T>, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        f(this)
+    }
+
+    /// Returns a copy of the projected value.
+    ///
+    /// This is handy for small `Copy` values like ids and flags, which are cheaper to copy
+    /// once than to keep dereferencing.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let entry = Parc::new((7u32, "seven")).project(|entry| &entry.0);
+    /// let id = Parc::get(&entry);
+    /// assert_eq!(id, 7);
+    /// ```
+    #[inline]
+    pub fn get(this: &Parc<T>) -> T
+    where
+        T: Copy,
+    {
+        **this
+    }
+
+    /// Returns a copy of the projected value, like [`Option::copied`].
+    ///
+    /// This is the same as [`Parc::get`], and reads better in iterator chains.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let pairs = Parc::new([(1u8, 'a'), (2, 'b')]);
+    /// let numbers = [pairs.project(|p| &p[0].0), pairs.project(|p| &p[1].0)];
+    /// let sum: u8 = numbers.iter().map(Parc::copied).sum();
+    /// assert_eq!(sum, 3);
+    /// ```
+    #[inline]
+    pub fn copied(this: &Parc<T>) -> T
+    where
+        T: Copy,
+    {
+        **this
+    }
+
+    /// Returns a clone of the projected value, like [`Option::cloned`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let user = Parc::new((1u32, String::from("ferris"))).project(|user| &user.1);
+    /// let name: String = Parc::cloned(&user);
+    /// assert_eq!(name, "ferris");
+    /// ```
+    #[inline]
+    pub fn cloned(this: &Parc<T>) -> T
+    where
+        T: Clone,
+    {
+        T::clone(this)
+    }
+
+    /// Borrows this `Parc` as a [`ParcRef`], a `Copy` handle that can be passed around
+    /// without any reference counting.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::{Parc, ParcRef};
+    ///
+    /// fn sum(a: ParcRef<'_, u32>, b: ParcRef<'_, u32>) -> u32 {
+    ///     *a + *b
+    /// }
+    ///
+    /// let parc = Parc::new(5);
+    /// let borrowed = Parc::borrow_parc(&parc);
+    /// assert_eq!(sum(borrowed, borrowed), 10);
+    /// assert_eq!(Parc::strong_count(&parc), 1);
+    /// ```
+    #[inline]
+    pub fn borrow_parc(this: &Parc<T>) -> ParcRef<'_, T> {
+        ParcRef { parc: this }
+    }
+
+    /// Sends this `Parc` to `queue`, deferring the drop of its allocation until
+    /// [`DropQueue::collect`] is called.
+    ///
+    /// See [`DropQueue`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{defer::DropQueue, sync::Parc};
+    ///
+    /// let queue = DropQueue::new();
+    /// let parc = Parc::new(vec![0u8; 1024]);
+    /// Parc::defer_drop(parc, &queue);
+    ///
+    /// // Later, on a thread where freeing memory is fine
+    /// assert_eq!(queue.collect(), 1);
+    /// ```
+    ///
+    /// [`DropQueue::collect`]: crate::defer::DropQueue::collect
+    /// [`DropQueue`]: crate::defer::DropQueue
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn defer_drop(this: Self, queue: &crate::defer::DropQueue) {
+        queue.push(this)
+    }
+
+    /// Blocks the current thread until this is the only strong pointer to its allocation,
+    /// or until `timeout` elapses. Returns `true` if this `Parc` is now unique.
+    ///
+    /// [`Weak`] pointers aren't taken into account. Nothing notifies the waiting thread
+    /// when other pointers are dropped, so the strong count is polled with an exponential
+    /// backoff of up to a millisecond; this is meant for rare events such as graceful shutdown.
+    ///
+    /// Note that another thread may clone a `Parc` sharing this allocation right after
+    /// this method returns, unless all other `Parc`s were dropped for good.
+    ///
+    /// `Parc`s created with [`Parc::from_static`] are never unique, so this returns `false`
+    /// for them right away.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use pared::sync::Parc;
+    ///
+    /// let state = Parc::new((String::from("connections"), 3));
+    /// let worker = state.project(|state| &state.1);
+    /// let handle = std::thread::spawn(move || {
+    ///     assert_eq!(*worker, 3);
+    /// });
+    ///
+    /// assert!(Parc::wait_until_unique(&state, Duration::from_secs(10)));
+    /// # handle.join().unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn wait_until_unique(this: &Self, timeout: core::time::Duration) -> bool {
+        const MAX_BACKOFF: core::time::Duration = core::time::Duration::from_millis(1);
+
+        if this.arc.is_static() {
+            return false;
+        }
+        let deadline = std::time::Instant::now().checked_add(timeout);
+        let mut backoff = core::time::Duration::from_micros(1);
+        loop {
+            if Parc::strong_count(this) == 1 {
+                return true;
+            }
+            let sleep = match deadline {
+                Some(deadline) => {
+                    match deadline.checked_duration_since(std::time::Instant::now()) {
+                        Some(remaining) if !remaining.is_zero() => backoff.min(remaining),
+                        _ => return false,
+                    }
+                }
+                // The deadline is too far in the future to represent
+                None => backoff,
+            };
+            std::thread::sleep(sleep);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Discards the projection, returning the type-erased `Arc` backing this `Parc`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn into_erased(this: Self) -> TypeErasedArc {
+        this.arc
+    }
+
+    /// Constructs a `Parc<T>` from a type-erased `Arc` and a pointer to the projected data.
+    ///
+    /// # Safety
+    /// `projected` must point to data owned by the allocation backing `arc`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) unsafe fn from_erased(arc: TypeErasedArc, projected: NonNull<T>) -> Self {
+        Self { arc, projected }
+    }
+
+    /// Returns the address of the allocation backing this `Parc`.
+    #[inline]
+    pub(crate) fn root_ptr(this: &Parc<T>) -> *const () {
+        this.arc.as_ptr()
+    }
+}
+
+impl<T: ?Sized> Parc<T> {
+    /// Moves the root of this `Parc` into one pointing to the reference returned by `project`,
+    /// without touching the reference counts.
+    #[inline]
+    fn map_owned<U, F>(self, project: F) -> Parc<U>
+    where
+        T: Send + Sync,
+        U: ?Sized + 'static,
+        F: for<'x> FnOnce(&'x T) -> &'x U,
+    {
+        let Parc { arc, projected } = self;
+        // SAFETY: `arc` keeps the allocation alive for at least the duration of this function
+        let projected = project(unsafe { projected.as_ref() });
+        Parc {
+            arc,
+            projected: NonNull::from(projected),
+        }
+    }
+
+    /// Like [`Parc::map_owned`], but `project` can return a reference to something else
+    /// instead, which the root is moved into.
+    #[inline]
+    fn try_map_owned<U, E, F>(self, project: F) -> Result<Parc<U>, Parc<E>>
+    where
+        T: Send + Sync,
+        U: ?Sized + 'static,
+        E: ?Sized + 'static,
+        F: for<'x> FnOnce(&'x T) -> Result<&'x U, &'x E>,
+    {
+        let Parc { arc, projected } = self;
+        // SAFETY: `arc` keeps the allocation alive for at least the duration of this function
+        match project(unsafe { projected.as_ref() }) {
+            Ok(value) => Ok(Parc {
+                arc,
+                projected: NonNull::from(value),
+            }),
+            Err(error) => Err(Parc {
+                arc,
+                projected: NonNull::from(error),
+            }),
+        }
+    }
+}
+
+impl<T> Parc<Option<T>>
+where
+    T: Send + Sync + 'static,
+{
+    /// Converts a `Parc<Option<T>>` into an `Option<Parc<T>>` projecting to the contained value.
+    ///
+    /// This consumes the `Parc` without touching the reference counts in the `Some` case;
+    /// in the `None` case, the `Parc` is dropped right away.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// struct Config {
+    ///     name: Option<String>,
+    /// }
+    ///
+    /// let config = Parc::new(Config { name: Some("pared".to_owned()) });
+    /// let name = config.project(|c| &c.name).transpose().unwrap();
+    /// assert_eq!(&*name, "pared");
+    ///
+    /// let nothing = Parc::new(None::<u32>);
+    /// assert!(nothing.transpose().is_none());
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Option<Parc<T>> {
+        self.try_map_owned(|option| option.as_ref().ok_or(&())).ok()
+    }
+}
+
+impl<T, E> Parc<Result<T, E>>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Converts a `Parc<Result<T, E>>` into a `Result<Parc<T>, Parc<E>>` projecting to the
+    /// contained value or error.
+    ///
+    /// This consumes the `Parc` without touching the reference counts or cloning the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let result: Result<String, String> = Ok("done".to_owned());
+    /// let ok = Parc::new(result).transpose().unwrap();
+    /// assert_eq!(&*ok, "done");
+    ///
+    /// let result: Result<String, String> = Err("failed".to_owned());
+    /// let err = Parc::new(result).transpose().unwrap_err();
+    /// assert_eq!(&*err, "failed");
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Result<Parc<T>, Parc<E>> {
+        self.try_map_owned(Result::as_ref)
+    }
+
+    /// Projects this `Parc<Result<T, E>>` to the contained value or error, keeping `self`.
+    ///
+    /// This is the borrowing version of [`Parc::transpose`], which clones the `Parc` instead
+    /// of the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let result: Parc<Result<u32, String>> = Parc::new(Err("failed".to_owned()));
+    /// let err = result.split_result().unwrap_err();
+    /// assert_eq!(&*err, "failed");
+    /// assert_eq!(Parc::strong_count(&result), 2);
+    /// ```
+    #[inline]
+    pub fn split_result(&self) -> Result<Parc<T>, Parc<E>> {
+        self.clone().transpose()
+    }
+}
+
+impl<T> Parc<Vec<T>>
+where
+    T: Send + Sync + 'static,
+{
+    /// Projects this `Parc<Vec<T>>` to a slice of its elements.
+    ///
+    /// To convert the `Parc` without cloning it, use the `From<Parc<Vec<T>>>` implementation
+    /// for `Parc<[T]>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let vec = Parc::new(vec![1, 2, 3]);
+    /// let slice: Parc<[i32]> = vec.as_slice_parc();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice_parc(&self) -> Parc<[T]> {
+        self.project(Vec::as_slice)
+    }
+}
+
+impl<T, const N: usize> Parc<[T; N]>
+where
+    T: Send + Sync + 'static,
+{
+    /// Splits this `Parc<[T; N]>` into `Parc`s projected to each of its elements.
+    ///
+    /// This `Parc` is reused for the last element, so the root is only cloned `N - 1` times.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let corners = Parc::new([(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// let [top_left, _, _, bottom_right] = corners.clone().project_iter();
+    /// assert_eq!((*top_left, *bottom_right), ((0, 0), (1, 1)));
+    /// // The unused corners were dropped right away
+    /// assert_eq!(Parc::strong_count(&corners), 3);
+    /// ```
+    pub fn project_iter(self) -> [Parc<T>; N] {
+        let Parc { arc, projected } = self;
+        let mut arc = Some(arc);
+        core::array::from_fn(|i| {
+            let arc = if i + 1 == N { arc.take() } else { arc.clone() };
+            Parc {
+                arc: arc.expect("the root is only moved into the last element"),
+                // SAFETY: `i < N`, so the element is within the array
+                projected: unsafe { projected.cast::<T>().add(i) },
+            }
+        })
+    }
+}
+
+impl<T> Parc<[T]>
+where
+    T: Send + Sync + 'static,
+{
+    /// Projects to the element at `index`, returning `None` if it's out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let primes: Parc<[u32]> = Parc::from(vec![2, 3, 5, 7]);
+    /// assert_eq!(primes.get_project(2).as_deref(), Some(&5));
+    /// assert!(primes.get_project(4).is_none());
+    /// ```
+    #[inline]
+    pub fn get_project(&self, index: usize) -> Option<Parc<T>> {
+        self.try_project(|slice| slice.get(index).ok_or(())).ok()
+    }
+
+    /// Binary searches this sorted slice for `x`, projecting to the matching element.
+    ///
+    /// If there is no match, returns the index where `x` could be inserted to keep the slice
+    /// sorted, like [`slice::binary_search`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let sorted: Parc<[u32]> = Parc::from(vec![1, 3, 5, 7]);
+    /// assert_eq!(sorted.binary_search_project(&5).as_deref(), Ok(&5));
+    /// assert_eq!(sorted.binary_search_project(&4).map(|found| *found), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search_project(&self, x: &T) -> Result<Parc<T>, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_project_by(|element| element.cmp(x))
+    }
+
+    /// Binary searches this sorted slice with a comparator function, projecting to
+    /// the matching element.
+    ///
+    /// See [`slice::binary_search_by`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let users: Parc<[(u32, &str)]> = Parc::from(vec![(1, "ann"), (4, "bob"), (9, "cy")]);
+    /// let bob = users.binary_search_project_by(|(id, _)| id.cmp(&4)).unwrap();
+    /// assert_eq!(bob.1, "bob");
+    /// ```
+    #[inline]
+    pub fn binary_search_project_by<F>(&self, f: F) -> Result<Parc<T>, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        let index = self.binary_search_by(f)?;
+        Ok(self.project(|slice| &slice[index]))
+    }
+}
+
+impl Parc<String> {
+    /// Projects this `Parc<String>` to a string slice.
+    ///
+    /// To convert the `Parc` without cloning it, use the `From<Parc<String>>` implementation
+    /// for `Parc<str>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let string = Parc::new(String::from("pared"));
+    /// let str: Parc<str> = string.as_str_parc();
+    /// assert_eq!(&*str, "pared");
+    /// ```
+    #[inline]
+    pub fn as_str_parc(&self) -> Parc<str> {
+        self.project(String::as_str)
+    }
+}
+
+impl Parc<[u8]> {
+    /// Converts the bytes into a string slice sharing the same root allocation,
+    /// validating that they're UTF-8.
+    ///
+    /// The reference counts aren't touched; if the bytes aren't valid UTF-8,
+    /// this `Parc` is dropped and the [`Utf8Error`] is returned.
+    /// To convert a `Parc<str>` back, use the `From<Parc<str>>` implementation for `Parc<[u8]>`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let packet: Parc<[u8]> = Parc::from(b"GET /index.html".to_vec());
+    /// let path = packet.project(|bytes| &bytes[4..]).try_into_str().unwrap();
+    /// assert_eq!(&*path, "/index.html");
+    ///
+    /// let invalid: Parc<[u8]> = Parc::from(vec![0xff]);
+    /// assert!(invalid.try_into_str().is_err());
+    /// ```
+    ///
+    /// [`Utf8Error`]: core::str::Utf8Error
+    #[inline]
+    pub fn try_into_str(self) -> Result<Parc<str>, core::str::Utf8Error> {
+        core::str::from_utf8(&self)?;
+        let Parc { arc, projected } = self;
+        // SAFETY: the bytes were just validated as UTF-8, and `str` has the same layout as `[u8]`
+        let projected = unsafe { NonNull::new_unchecked(projected.as_ptr() as *mut str) };
+        Ok(Parc { arc, projected })
+    }
+}
+
+impl Parc<str> {
+    /// Converts the string slice into a `Cow<'static, str>`, for APIs that take one.
+    ///
+    /// Strings projected out of [`Parc::from_static`] data, and empty strings, are borrowed.
+    /// Otherwise, a `Cow` can't share ownership of the string, so the text is copied into
+    /// a new `String`. Prefer passing `Parc<str>` around where possible, and converting
+    /// at the boundary of such APIs.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use pared::sync::Parc;
+    ///
+    /// fn set_title(title: impl Into<Cow<'static, str>>) -> Cow<'static, str> {
+    ///     title.into()
+    /// }
+    ///
+    /// let page = Parc::new(String::from("title: Pared"));
+    /// let title = page.project(|page| &page[7..]);
+    /// assert_eq!(set_title(title.into_cow_static()), "Pared");
+    /// assert!(matches!(page.project(|page| &page[..0]).into_cow_static(), Cow::Borrowed("")));
+    ///
+    /// let name = Parc::from_static("name: Pared").project(|name| &name[6..]);
+    /// assert!(matches!(name.into_cow_static(), Cow::Borrowed("Pared")));
+    /// ```
+    #[inline]
+    pub fn into_cow_static(self) -> Cow<'static, str> {
+        if self.arc.is_static() {
+            // SAFETY: static data is never freed, so everything projected out of it
+            // lives for 'static as well
+            Cow::Borrowed(unsafe { &*self.projected.as_ptr() })
+        } else if self.is_empty() {
+            Cow::Borrowed("")
+        } else {
+            Cow::Owned(String::from(&*self))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Parc<std::collections::HashMap<K, V, S>>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    S: core::hash::BuildHasher + Send + Sync + 'static,
+{
+    /// Projects to the value corresponding to `key`, returning `None` if the map
+    /// doesn't contain it.
+    ///
+    /// The key may be any borrowed form of the map's key type, like with [`HashMap::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use pared::sync::Parc;
+    ///
+    /// let routes = Parc::new(HashMap::from([("/".to_owned(), "index".to_owned())]));
+    /// let index = routes.project_get("/").unwrap();
+    /// assert_eq!(&*index, "index");
+    /// assert!(routes.project_get("/missing").is_none());
+    /// ```
+    ///
+    /// [`HashMap::get`]: std::collections::HashMap::get
+    #[inline]
+    pub fn project_get<Q>(&self, key: &Q) -> Option<Parc<V>>
+    where
+        K: core::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.try_project(|map| map.get(key).ok_or(())).ok()
+    }
+}
+
+impl<K, V> Parc<BTreeMap<K, V>>
+where
+    K: Ord + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    /// Projects to the value corresponding to `key`, returning `None` if the map
+    /// doesn't contain it.
+    ///
+    /// The key may be any borrowed form of the map's key type, like with [`BTreeMap::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use pared::sync::Parc;
+    ///
+    /// let ids = Parc::new(BTreeMap::from([(1, "one".to_owned()), (2, "two".to_owned())]));
+    /// let two = ids.project_get(&2).unwrap();
+    /// assert_eq!(&*two, "two");
+    /// assert!(ids.project_get(&3).is_none());
+    /// ```
+    #[inline]
+    pub fn project_get<Q>(&self, key: &Q) -> Option<Parc<V>>
+    where
+        K: core::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.try_project(|map| map.get(key).ok_or(())).ok()
+    }
+
+    /// Returns an iterator over the entries in `range`, projecting to each key and value.
+    ///
+    /// Each returned `Parc` shares ownership of the whole map, so the entries can outlive
+    /// both the iterator and `self`.
+    ///
+    /// # Panics
+    /// Panics in the same cases as [`BTreeMap::range`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use pared::sync::Parc;
+    ///
+    /// let index = Parc::new(BTreeMap::from([(1, "a"), (2, "b"), (3, "c"), (4, "d")]));
+    /// let entries: Vec<_> = index.project_range(2..4).collect();
+    /// drop(index);
+    ///
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!((*entries[0].0, *entries[0].1), (2, "b"));
+    /// assert_eq!((*entries[1].0, *entries[1].1), (3, "c"));
+    /// ```
+    pub fn project_range<Q, R>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = (Parc<K>, Parc<V>)> + '_
+    where
+        K: core::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: core::ops::RangeBounds<Q>,
+    {
+        self.range(range).map(move |(key, value)| {
+            // SAFETY: both references point into the map owned by this `Parc`'s allocation
+            unsafe { (self.project_ref(key), self.project_ref(value)) }
+        })
+    }
+}
+
+impl Parc<dyn core::any::Any + Send + Sync> {
+    /// Attempts to downcast the `Parc<dyn Any + Send + Sync>` to a concrete type,
+    /// returning the original `Parc` if the projected value isn't a `T`.
+    ///
+    /// Only the type of the projected value is checked; the root `Arc` can be of any type.
+    ///
+    /// # Example
+    /// ```
+    /// use std::any::Any;
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::new((1u8, String::from("plugin")));
+    /// let any: Parc<dyn Any + Send + Sync> = parc.project(|x| &x.1 as _);
+    ///
+    /// let any = any.downcast::<u8>().unwrap_err();
+    /// let string = any.downcast::<String>().unwrap();
+    /// assert_eq!(&*string, "plugin");
+    /// ```
+    #[inline]
+    pub fn downcast<T>(self) -> Result<Parc<T>, Parc<dyn core::any::Any + Send + Sync>>
+    where
+        T: core::any::Any,
+    {
+        if !(*self).is::<T>() {
+            return Err(self);
+        }
+        let Parc { arc, projected } = self;
+        Ok(Parc {
+            arc,
+            projected: projected.cast::<T>(),
+        })
+    }
+}
+
+impl Parc<dyn core::error::Error + Send + Sync> {
+    /// Attempts to downcast the `Parc<dyn Error + Send + Sync>` to a concrete error type,
+    /// returning the original `Parc` if the projected error isn't an `E`.
+    ///
+    /// Only the type of the projected error is checked; the root `Arc` can be of any type.
+    ///
+    /// # Example
+    /// ```
+    /// use std::{error::Error, fmt, num::ParseIntError};
+    /// use pared::sync::Parc;
+    ///
+    /// let parsed: Result<u8, ParseIntError> = "x".parse();
+    /// let report = Parc::new(("config.toml", parsed.unwrap_err()));
+    /// let error: Parc<dyn Error + Send + Sync> = report.project(|r| &r.1 as _);
+    ///
+    /// let error = error.downcast::<fmt::Error>().unwrap_err();
+    /// let parse_error = error.downcast::<ParseIntError>().unwrap();
+    /// assert_eq!(parse_error.to_string(), "invalid digit found in string");
+    /// ```
+    #[inline]
+    pub fn downcast<E>(self) -> Result<Parc<E>, Parc<dyn core::error::Error + Send + Sync>>
+    where
+        E: core::error::Error + 'static,
+    {
+        if !(*self).is::<E>() {
+            return Err(self);
+        }
+        let Parc { arc, projected } = self;
+        Ok(Parc {
+            arc,
+            projected: projected.cast::<E>(),
+        })
+    }
+
+    /// Returns a reference to the projected error if it's of type `E`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::{error::Error, num::ParseIntError};
+    /// use pared::sync::Parc;
+    ///
+    /// let error = "x".parse::<u8>().unwrap_err();
+    /// let error: Parc<dyn Error + Send + Sync> = Parc::new(error).project(|e| e as _);
+    ///
+    /// assert!(error.downcast_ref::<ParseIntError>().is_some());
+    /// assert!(error.downcast_ref::<std::fmt::Error>().is_none());
+    /// ```
+    #[inline]
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: core::error::Error + 'static,
+    {
+        (**self).downcast_ref()
+    }
+}
 
 impl<T: ?Sized> AsRef<T> for Parc<T> {
     #[inline]
@@ -513,17 +1954,54 @@ impl<T: ?Sized> Deref for Parc<T> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<T> std::error::Error for Parc<T>
+impl<T> core::error::Error for Parc<T>
 where
-    T: std::error::Error + ?Sized,
+    T: core::error::Error + ?Sized,
 {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         self.deref().source()
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Parc<T>
+where
+    T: defmt::Format + ?Sized,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.deref().format(f)
+    }
+}
+
+/// Serializes the projected value, like `Arc<T>` with serde's `rc` feature.
+///
+/// Projections sharing an allocation are serialized separately, so sharing isn't preserved.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Parc<T>
+where
+    T: serde::Serialize + ?Sized,
+{
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
+    }
+}
+
+/// Deserializes a new `Parc` pointing to its own allocation,
+/// like `Arc<T>` with serde's `rc` feature.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Parc<T>
+where
+    T: ?Sized + Send + Sync + 'static,
+    alloc::boxed::Box<T>: serde::Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        alloc::boxed::Box::<T>::deserialize(deserializer).map(Parc::from)
+    }
+}
+
 impl<T, F> From<F> for Parc<T>
 where
     T: ?Sized + Send + Sync + 'static,
@@ -531,7 +2009,70 @@ where
 {
     #[inline]
     fn from(value: F) -> Self {
-        Parc::from_arc(&value.into(), |x| x)
+        Parc::from_root(value.into())
+    }
+}
+
+impl<T> From<Parc<Vec<T>>> for Parc<[T]>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn from(parc: Parc<Vec<T>>) -> Self {
+        parc.map_owned(Vec::as_slice)
+    }
+}
+
+impl From<Parc<String>> for Parc<str> {
+    #[inline]
+    fn from(parc: Parc<String>) -> Self {
+        parc.map_owned(String::as_str)
+    }
+}
+
+impl From<Parc<str>> for Parc<[u8]> {
+    /// Converts the string slice into its bytes without touching the reference counts.
+    #[inline]
+    fn from(parc: Parc<str>) -> Self {
+        let Parc { arc, projected } = parc;
+        // SAFETY: `str` has the same layout as `[u8]`, and the pointer came from a `NonNull`
+        let projected = unsafe { NonNull::new_unchecked(projected.as_ptr() as *mut [u8]) };
+        Parc { arc, projected }
+    }
+}
+
+impl<T, const N: usize> From<Parc<[T; N]>> for Parc<[T]>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn from(parc: Parc<[T; N]>) -> Self {
+        let Parc { arc, projected } = parc;
+        Parc {
+            arc,
+            projected: NonNull::slice_from_raw_parts(projected.cast::<T>(), N),
+        }
+    }
+}
+
+impl<T, const N: usize> core::convert::TryFrom<Parc<[T]>> for Parc<[T; N]>
+where
+    T: Send + Sync + 'static,
+{
+    type Error = Parc<[T]>;
+
+    /// Converts the slice into an array of length `N`,
+    /// returning the original `Parc` if the slice's length isn't `N`.
+    #[inline]
+    fn try_from(parc: Parc<[T]>) -> Result<Self, Self::Error> {
+        if parc.len() != N {
+            return Err(parc);
+        }
+        let Parc { arc, projected } = parc;
+        Ok(Parc {
+            arc,
+            projected: projected.cast::<[T; N]>(),
+        })
     }
 }
 
@@ -618,6 +2159,130 @@ unsafe impl<T> Sync for Parc<T> where T: Sync + Send + ?Sized {}
 impl<T> Unpin for Parc<T> where T: ?Sized {}
 impl<T> core::panic::UnwindSafe for Parc<T> where T: core::panic::RefUnwindSafe + ?Sized {}
 
+/// The raw parts of a [`Parc`].
+///
+/// `RawParc` is an opaque `Copy` value that doesn't own anything by itself;
+/// it's created by [`Parc::into_raw`] and turned back into a `Parc` by [`Parc::from_raw`].
+/// The strong count of the backing allocation can be adjusted while it's stored elsewhere
+/// using [`Parc::increment_strong_count`] and [`Parc::decrement_strong_count`].
+///
+/// Its layout is unspecified and it's larger than a single pointer, so it can't be handed
+/// to foreign code as is. To pass a `Parc` through an API taking a `*mut c_void`, box the
+/// `RawParc` and pass the pointer returned by [`Box::into_raw`] instead.
+///
+/// [`Box::into_raw`]: alloc::boxed::Box::into_raw
+pub struct RawParc<T: ?Sized> {
+    projected: NonNull<T>,
+    root: TypeErasedPtr,
+    vtable: &'static RcVTable,
+}
+
+impl<T: ?Sized> RawParc<T> {
+    /// Returns a raw pointer to the projected data.
+    ///
+    /// The pointer is valid for as long as the allocation backing the `Parc` is alive.
+    #[inline]
+    pub fn as_ptr(self) -> *const T {
+        self.projected.as_ptr()
+    }
+}
+
+impl<T: ?Sized> Clone for RawParc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for RawParc<T> {}
+
+impl<T: ?Sized> core::fmt::Debug for RawParc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawParc")
+            .field("projected", &self.projected)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A borrowed [`Parc`] that doesn't perform any reference counting.
+///
+/// `ParcRef` is `Copy` and dereferences directly to the projected value, which makes it
+/// suitable for hot read paths where the borrowed `Parc` provably outlives the access.
+/// Use [`ParcRef::clone_parc`] to obtain an owned `Parc` when the value needs to be kept around.
+///
+/// Created by [`Parc::borrow_parc`].
+///
+/// # Example
+/// ```
+/// use pared::sync::{Parc, ParcRef};
+///
+/// let parc = Parc::new(String::from("hello"));
+/// let borrowed: ParcRef<'_, String> = Parc::borrow_parc(&parc);
+/// assert_eq!(borrowed.len(), 5);
+///
+/// let owned: Parc<String> = borrowed.clone_parc();
+/// assert!(Parc::ptr_eq(&owned, &parc));
+/// ```
+pub struct ParcRef<'a, T: ?Sized> {
+    parc: &'a Parc<T>,
+}
+
+impl<'a, T: ?Sized> ParcRef<'a, T> {
+    /// Returns a reference to the projected value with the lifetime of the borrowed `Parc`.
+    #[inline]
+    pub fn get(self) -> &'a T {
+        self.parc
+    }
+
+    /// Creates a new owned [`Parc`] pointing to the same value, incrementing the strong count.
+    #[inline]
+    pub fn clone_parc(self) -> Parc<T> {
+        self.parc.clone()
+    }
+}
+
+impl<T: ?Sized> Clone for ParcRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ParcRef<'_, T> {}
+
+impl<T: ?Sized> Deref for ParcRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.parc
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for ParcRef<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.parc
+    }
+}
+
+impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for ParcRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParcRef")
+            .field("projected", &self.deref())
+            .finish()
+    }
+}
+
+impl<T> core::fmt::Display for ParcRef<'_, T>
+where
+    T: core::fmt::Display + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
 /// Weak is a version of [`Parc`] that holds a non-owning reference to the managed allocation.
 /// The allocation is accessed by calling [`upgrade`], which returns `Option<Parc<T>>`.
 ///
@@ -647,6 +2312,7 @@ impl<T> core::panic::UnwindSafe for Parc<T> where T: core::panic::RefUnwindSafe
 ///
 /// [`upgrade`]: Weak::upgrade
 /// [`std::sync::Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+#[cfg(feature = "weak")]
 pub struct Weak<T: ?Sized> {
     weak: TypeErasedWeak,
     projected: NonNull<T>,
@@ -660,6 +2326,7 @@ pub struct Weak<T: ?Sized> {
 // which guarantees that as long as our projected T is also Send + Sync,
 // we can safely send Weak<T> between threads.
 
+#[cfg(feature = "weak")]
 unsafe impl<T: ?Sized + Sync + Send> Send for Weak<T> {}
 // SAFETY: We can only create Parc from either
 // Arc<T> where T: Send + Sync
@@ -668,13 +2335,42 @@ unsafe impl<T: ?Sized + Sync + Send> Send for Weak<T> {}
 // and Weak<T> is only ever constructed from Parc<T>,
 // which guarantees that as long as our projected T is also Send + Sync,
 // we can safely send Weak<T> between threads.
+#[cfg(feature = "weak")]
 unsafe impl<T: ?Sized + Sync + Send> Sync for Weak<T> {}
 
+#[cfg(feature = "weak")]
+impl<T> Weak<T> {
+    /// Constructs a new `Weak<T>`, without allocating any memory.
+    /// Calling [`upgrade`] on the return value always gives [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Weak;
+    ///
+    /// let empty: Weak<i64> = Weak::new();
+    /// assert!(empty.upgrade().is_none());
+    /// assert!(empty.is_dangling());
+    /// ```
+    ///
+    /// [`upgrade`]: Weak::upgrade
+    #[inline]
+    pub fn new() -> Weak<T> {
+        Weak {
+            weak: TypeErasedWeak::dangling(),
+            projected: NonNull::dangling(),
+        }
+    }
+}
+
+#[cfg(feature = "weak")]
 impl<T: ?Sized> Weak<T> {
     /// Returns a raw pointer to the object `T` pointed to by this `Weak<T>`.
     ///
     /// The pointer is valid only if there are some strong references.
     ///
+    /// The returned pointer is the same as [`Parc::as_ptr`] of the `Parc` this `Weak` was
+    /// created from, and it doesn't change when the `Weak` is cloned or moved.
+    ///
     /// # Examples
     ///
     /// ```
@@ -700,6 +2396,25 @@ impl<T: ?Sized> Weak<T> {
         NonNull::as_ptr(self.projected)
     }
 
+    /// Returns the address of the projected data.
+    ///
+    /// This is the address of the pointer returned by [`Weak::as_ptr`] without any metadata.
+    /// The address might be dangling if there are no strong references left.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let strong = Parc::new(5);
+    /// let weak = Parc::downgrade(&strong);
+    /// assert_eq!(weak.addr(), Parc::addr(&strong));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn addr(&self) -> usize {
+        self.as_ptr() as *const () as usize
+    }
+
     /// Attempts to upgrade the `Weak` pointer to a [`Parc`], delaying dropping of the inner value
     /// if successful.
     ///
@@ -730,6 +2445,56 @@ impl<T: ?Sized> Weak<T> {
         })
     }
 
+    /// Returns `true` if this `Weak` was created with [`Weak::new`] (or [`Default`])
+    /// and never pointed to an allocation.
+    ///
+    /// Note that this is different from being unable to [`upgrade`]: a `Weak` to an allocation
+    /// whose value has been dropped isn't dangling.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::{Parc, Weak};
+    ///
+    /// struct Node {
+    ///     parent: Weak<Node>,
+    /// }
+    ///
+    /// let root = Parc::new(Node { parent: Weak::default() });
+    /// assert!(root.parent.is_dangling());
+    ///
+    /// let weak_root = Parc::downgrade(&root);
+    /// drop(root);
+    /// assert!(!weak_root.is_dangling());
+    /// ```
+    ///
+    /// [`upgrade`]: Weak::upgrade
+    #[inline]
+    pub fn is_dangling(&self) -> bool {
+        self.weak.is_dangling()
+    }
+
+    /// Returns `true` if the value this `Weak` points to hasn't been dropped yet,
+    /// without creating a strong pointer like [`upgrade`](Weak::upgrade) does.
+    ///
+    /// This is only advisory: another thread may drop the last `Parc` right after this returns
+    /// `true`, so use [`upgrade`](Weak::upgrade) to actually access the value.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::{Parc, Weak};
+    ///
+    /// let root = Parc::new((1, 2));
+    /// let weak = Parc::downgrade(&root.project(|pair| &pair.0));
+    /// assert!(weak.is_alive());
+    /// drop(root);
+    /// assert!(!weak.is_alive());
+    /// assert!(!Weak::<u32>::new().is_alive());
+    /// ```
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.weak.strong_count() > 0
+    }
+
     /// Returns the number of strong pointers pointing to this allocation.
     #[inline]
     pub fn strong_count(&self) -> usize {
@@ -746,6 +2511,28 @@ impl<T: ?Sized> Weak<T> {
         self.weak.weak_count()
     }
 
+    /// Gets both the number of strong and `Weak` pointers to this allocation.
+    ///
+    /// See [`Weak::strong_count`] and [`Weak::weak_count`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, Counts};
+    /// let six = Parc::new(6);
+    /// let weak_six = Parc::downgrade(&six);
+    ///
+    /// assert_eq!(weak_six.counts(), Counts { strong: 1, weak: 1 });
+    /// drop(six);
+    /// assert_eq!(weak_six.counts(), Counts { strong: 0, weak: 0 });
+    /// ```
+    #[inline]
+    pub fn counts(&self) -> Counts {
+        Counts {
+            strong: self.strong_count(),
+            weak: self.weak_count(),
+        }
+    }
+
     /// Returns `true` if the two `Weak`s point to the same data, using [`core::ptr::eq`].
     /// See that function for caveats when comparing `dyn Trait` pointers.
     ///
@@ -755,8 +2542,61 @@ impl<T: ?Sized> Weak<T> {
     pub fn ptr_eq(&self, other: &Weak<T>) -> bool {
         core::ptr::eq(self.projected.as_ptr(), other.projected.as_ptr())
     }
+
+    /// Returns `true` if the two `Weak`s are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// Like [`Weak::ptr_eq`], this can compare `Weak` pointers even when either or both of them
+    /// can't successfully `upgrade` anymore.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let tuple = Parc::new((5u8, 6u16));
+    /// let first = Parc::downgrade(&tuple.project(|t| &t.0));
+    /// let second = Parc::downgrade(&tuple.project(|t| &t.1));
+    /// let other = Parc::downgrade(&Parc::new(5u8));
+    ///
+    /// assert!(first.same_root(&second));
+    /// assert!(!first.same_root(&other));
+    /// ```
+    #[inline]
+    pub fn same_root<U: ?Sized>(&self, other: &Weak<U>) -> bool {
+        core::ptr::eq(self.weak.as_ptr(), other.weak.as_ptr())
+    }
+
+    /// Returns `true` if this `Weak` and the `Parc` are backed by the same allocation, regardless
+    /// of which parts of it they point to.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let tuple = Parc::new((5u8, 6u16));
+    /// let weak = Parc::downgrade(&tuple.project(|t| &t.0));
+    ///
+    /// assert!(weak.same_root_as(&tuple));
+    /// assert!(!weak.same_root_as(&Parc::new(5u8)));
+    /// ```
+    #[inline]
+    pub fn same_root_as<U: ?Sized>(&self, strong: &Parc<U>) -> bool {
+        core::ptr::eq(self.weak.as_ptr(), Parc::root_ptr(strong))
+    }
+}
+
+#[cfg(feature = "weak")]
+impl<T> Default for Weak<T> {
+    /// Constructs a new `Weak<T>`, without allocating any memory.
+    ///
+    /// See [`Weak::new`].
+    #[inline]
+    fn default() -> Self {
+        Weak::new()
+    }
 }
 
+#[cfg(feature = "weak")]
 impl<T: ?Sized> Clone for Weak<T> {
     #[inline]
     fn clone(&self) -> Self {
@@ -767,8 +2607,54 @@ impl<T: ?Sized> Clone for Weak<T> {
     }
 }
 
+#[cfg(feature = "weak")]
 impl<T: ?Sized> core::fmt::Debug for Weak<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "(Weak)")
     }
 }
+
+#[cfg(all(feature = "defmt", feature = "weak"))]
+impl<T: ?Sized> defmt::Format for Weak<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let counts = self.counts();
+        defmt::write!(
+            f,
+            "(Weak) {{ alive: {=bool}, strong: {=usize}, weak: {=usize} }}",
+            counts.strong > 0,
+            counts.strong,
+            counts.weak
+        )
+    }
+}
+
+/// Serializes the value as an `Option`, which is `None` if it has already been dropped,
+/// like `sync::Weak<T>` with serde's `rc` feature.
+#[cfg(all(feature = "serde", feature = "weak"))]
+impl<T> serde::Serialize for Weak<T>
+where
+    T: serde::Serialize + ?Sized,
+{
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.upgrade().serialize(serializer)
+    }
+}
+
+/// Deserializes an `Option<T>` and discards it, returning a dangling `Weak`,
+/// like `sync::Weak<T>` with serde's `rc` feature.
+///
+/// A deserialized value would have no `Parc` keeping it alive, so the `Weak` couldn't be
+/// upgraded anyway; deserialize the owning `Parc` and [downgrade](Parc::downgrade) it
+/// to restore a live `Weak`.
+#[cfg(all(feature = "serde", feature = "weak"))]
+impl<'de, T> serde::Deserialize<'de> for Weak<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer)?;
+        Ok(Weak::new())
+    }
+}
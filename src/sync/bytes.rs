@@ -0,0 +1,48 @@
+//! Zero-copy conversions between shared byte slices and [`bytes::Bytes`].
+
+use alloc::sync::Arc;
+use bytes::Bytes;
+use core::convert::From;
+
+use super::Parc;
+
+/// Wraps the `Parc` in a [`Bytes`] without copying the bytes.
+///
+/// The `Bytes` keeps the `Parc` alive as its owner; see [`Bytes::from_owner`].
+///
+/// # Example
+/// ```
+/// use bytes::Bytes;
+/// use pared::sync::Parc;
+///
+/// let payload: Parc<[u8]> = Parc::from(b"payload".to_vec());
+/// let bytes = Bytes::from(payload.clone());
+/// assert_eq!(bytes.as_ptr(), payload.as_ptr());
+/// ```
+impl From<Parc<[u8]>> for Bytes {
+    #[inline]
+    fn from(parc: Parc<[u8]>) -> Self {
+        Bytes::from_owner(parc)
+    }
+}
+
+impl Parc<[u8]> {
+    /// Moves the [`Bytes`] into the root allocation of a `Parc` without copying the bytes.
+    ///
+    /// This is a method rather than a `From` implementation, which would conflict with
+    /// `Parc`'s blanket `From<F: Into<Arc<T>>>` implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use bytes::Bytes;
+    /// use pared::sync::Parc;
+    ///
+    /// let bytes = Bytes::from_static(b"payload");
+    /// let payload = Parc::from_bytes(bytes.clone());
+    /// assert_eq!(payload.as_ptr(), bytes.as_ptr());
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: Bytes) -> Parc<[u8]> {
+        Parc::from_arc(&Arc::new(bytes), |bytes| &bytes[..])
+    }
+}
@@ -0,0 +1,172 @@
+//! A small cache of projections, for hot paths projecting the same root over and over.
+
+use core::{
+    fmt,
+    marker::{Send, Sync},
+};
+
+use super::Parc;
+use crate::Projection;
+
+/// A fixed-capacity cache of projected `Parc`s, used with [`Parc::project_cached`].
+///
+/// Entries are looked up by the `Parc` they were projected from and a key, such as an enum
+/// discriminant, naming the projection. The cache holds at most `N` entries, searched
+/// linearly, and replaces the oldest entry once it's full, so it's meant for a handful
+/// of projections that are looked up far more often than they change.
+///
+/// Cached entries keep their root alive until they're replaced or the cache is cleared.
+pub struct SmallCache<K, U: ?Sized, const N: usize = 4> {
+    // The key, the address of the source `Parc` and the projection
+    entries: [Option<(K, usize, Parc<U>)>; N],
+    // The entry to replace next
+    next: usize,
+}
+
+impl<K, U: ?Sized, const N: usize> SmallCache<K, U, N> {
+    /// Creates an empty cache.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: [const { None }; N],
+            next: 0,
+        }
+    }
+
+    /// Returns the number of cached projections.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_some()).count()
+    }
+
+    /// Returns `true` if no projections are cached.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Removes all cached projections, releasing their roots.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries = [const { None }; N];
+        self.next = 0;
+    }
+}
+
+impl<K, U: ?Sized, const N: usize> Default for SmallCache<K, U, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, U: ?Sized, const N: usize> fmt::Debug for SmallCache<K, U, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallCache")
+            .field("len", &self.len())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Parc<T> {
+    /// Returns the projection of this `Parc` cached in `cache` under `key`, or projects it
+    /// with `project` and caches the result.
+    ///
+    /// A cached projection is only returned for the same `key` and a `Parc` pointing to
+    /// the same value in the same root; `project` must always return the same projection
+    /// for the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::{Parc, SmallCache};
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Eq)]
+    /// enum Field {
+    ///     Name,
+    ///     Host,
+    /// }
+    ///
+    /// let config = Parc::new((String::from("server"), String::from("localhost")));
+    /// let mut cache = SmallCache::<Field, str>::new();
+    ///
+    /// let host = config.project_cached(&mut cache, Field::Host, |config| config.1.as_str());
+    /// // The projection isn't run again for the same key
+    /// let again = config.project_cached(&mut cache, Field::Host, |_| unreachable!());
+    /// assert!(Parc::ptr_eq(&host, &again));
+    /// assert_eq!(&*again, "localhost");
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn project_cached<K, U, F, const N: usize>(
+        &self,
+        cache: &mut SmallCache<K, U, N>,
+        key: K,
+        project: F,
+    ) -> Parc<U>
+    where
+        T: Send + Sync,
+        U: ?Sized + 'static,
+        K: Eq,
+        F: Projection<T, U>,
+    {
+        let source = Parc::as_ptr(self) as *const () as usize;
+        // The cached projection keeps its root alive, so no other value can be at `source`
+        // in the same root
+        let cached = cache
+            .entries
+            .iter()
+            .flatten()
+            .find(|(cached_key, cached_source, parc)| {
+                *cached_key == key && *cached_source == source && Parc::same_root(self, parc)
+            });
+        if let Some((_, _, parc)) = cached {
+            return parc.clone();
+        }
+
+        let parc = self.project(project);
+        if N > 0 {
+            cache.entries[cache.next] = Some((key, source, parc.clone()));
+            cache.next = (cache.next + 1) % N;
+        }
+        parc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn replaces_the_oldest_entry() {
+        let first = Parc::new([1, 2, 3]);
+        let second = Parc::new([1, 2, 3]);
+        let mut cache = SmallCache::<usize, i32, 2>::new();
+
+        let a = first.project_cached(&mut cache, 0, |array| &array[0]);
+        let b = second.project_cached(&mut cache, 0, |array| &array[0]);
+        assert!(!Parc::ptr_eq(&a, &b));
+        assert_eq!(Parc::strong_count(&first), 3);
+        assert_eq!(format!("{:?}", cache), "SmallCache { len: 2, capacity: 2 }");
+
+        let c = first.project_cached(&mut cache, 2, |array| &array[2]);
+        assert_eq!(*c, 3);
+        assert_eq!(cache.len(), 2);
+        // The entry for `first` and key 0 was replaced, so it's projected again
+        let mut projected = false;
+        let a2 = first.project_cached(&mut cache, 0, |array| {
+            projected = true;
+            &array[0]
+        });
+        assert!(projected && Parc::ptr_eq(&a, &a2));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(Parc::strong_count(&second), 2);
+
+        let mut none = SmallCache::<(), i32, 0>::default();
+        assert_eq!(*first.project_cached(&mut none, (), |array| &array[1]), 2);
+        assert!(none.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Projections into shared document trees, such as [`serde_json::Value`].
+//!
+//! [`serde_json::Value`]: https://docs.rs/serde_json/latest/serde_json/enum.Value.html
+
+use core::marker::{Send, Sync};
+
+use crate::dom::{lookup, Index};
+
+use super::Parc;
+
+impl<D> Parc<D>
+where
+    D: Index + Send + Sync + 'static,
+{
+    /// Projects to the value at `path`, returning `None` if there is no such value.
+    ///
+    /// `path` consists of object keys separated by `.`, each optionally followed by
+    /// any number of `[index]` array accesses, e.g. `a.b[2].c` or `[0][1]`.
+    /// An empty `path` projects to the whole document.
+    ///
+    /// This works for any document type implementing [`Index`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "json")] {
+    /// use pared::sync::Parc;
+    /// use serde_json::json;
+    ///
+    /// let doc = Parc::new(json!({ "a": { "b": [1, 2, { "c": true }] } }));
+    /// let c = doc.project_path("a.b[2].c").unwrap();
+    /// assert_eq!(*c, json!(true));
+    /// assert!(doc.project_path("a.b[3]").is_none());
+    /// # }
+    /// ```
+    pub fn project_path(&self, path: &str) -> Option<Parc<D>> {
+        self.try_project(|value| lookup(value, path).ok_or(())).ok()
+    }
+
+    /// Projects to the string at `path`, returning `None` if there is no such value
+    /// or it's not a string.
+    ///
+    /// See [`Parc::project_path`] for the syntax of `path`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "json")] {
+    /// use pared::sync::Parc;
+    /// use serde_json::json;
+    ///
+    /// let doc = Parc::new(json!({ "users": [{ "name": "Alice" }] }));
+    /// let name = doc.project_str("users[0].name").unwrap();
+    /// assert_eq!(&*name, "Alice");
+    /// assert!(doc.project_str("users").is_none());
+    /// # }
+    /// ```
+    pub fn project_str(&self, path: &str) -> Option<Parc<str>> {
+        self.try_project(|value| lookup(value, path).and_then(D::as_str).ok_or(()))
+            .ok()
+    }
+
+    /// Projects to the elements of the array at `path`, returning `None` if there is no such
+    /// value or it's not an array.
+    ///
+    /// See [`Parc::project_path`] for the syntax of `path`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "json")] {
+    /// use pared::sync::Parc;
+    /// use serde_json::json;
+    ///
+    /// let doc = Parc::new(json!({ "scores": [3, 1, 4] }));
+    /// let scores = doc.project_array("scores").unwrap();
+    /// assert_eq!(scores.len(), 3);
+    /// assert!(doc.project_array("scores[0]").is_none());
+    /// # }
+    /// ```
+    pub fn project_array(&self, path: &str) -> Option<Parc<[D]>> {
+        self.try_project(|value| lookup(value, path).and_then(D::as_slice).ok_or(()))
+            .ok()
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn projections_share_root() {
+        let doc = Parc::new(json!({ "a": { "s": "text", "v": [true] } }));
+        let a = doc.project_path("a").unwrap();
+        let s = a.project_str("s").unwrap();
+        let v = doc.project_array("a.v").unwrap();
+        assert_eq!(&*s, "text");
+        assert_eq!(&*v, &[json!(true)]);
+        assert!(Parc::same_root(&doc, &s));
+        assert!(Parc::same_root(&a, &v));
+        assert!(a.project_array("s").is_none());
+        assert!(a.project_str("v").is_none());
+    }
+}
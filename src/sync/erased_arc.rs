@@ -1,5 +1,9 @@
-use alloc::sync::{Arc, Weak};
+use alloc::sync::Arc;
+#[cfg(feature = "weak")]
+use alloc::sync::Weak;
 use core::{
+    alloc::Layout,
+    any::{Any, TypeId},
     clone::Clone,
     marker::{PhantomData, Send, Sized, Sync},
     mem::ManuallyDrop,
@@ -7,22 +11,88 @@ use core::{
     option::{Option, Option::Some},
 };
 
-use crate::{erased_ptr::TypeErasedPtr, vtable::RcVTable};
+#[cfg(feature = "weak")]
+use crate::vtable::DanglingErased;
+use crate::{
+    erased_ptr::TypeErasedPtr,
+    vtable::{rc_allocation_layout, RcVTable, StaticErased},
+};
 
 pub struct TypeErasedArc {
     ptr: TypeErasedPtr,
     vtable: &'static RcVTable,
 }
 
+// SAFETY: TypeErasedArc can only be created from Arc<T> where T: Send + Sync,
+// which makes it safe to clone and drop from any thread
+unsafe impl Send for TypeErasedArc {}
+// SAFETY: TypeErasedArc can only be created from Arc<T> where T: Send + Sync,
+// which makes it safe to clone and drop from any thread
+unsafe impl Sync for TypeErasedArc {}
+
 impl TypeErasedArc {
     #[inline]
-    pub(crate) fn new<T: ?Sized + Send + Sync>(arc: Arc<T>) -> Self {
+    pub(crate) fn new<T: ?Sized + Send + Sync + 'static>(arc: Arc<T>) -> Self {
         Self {
             ptr: TypeErasedPtr::new(Arc::into_raw(arc)),
             vtable: &ArcErased::<T>::VTABLE,
         }
+        .registered()
+    }
+
+    /// Like [`TypeErasedArc::new`], but also allows cloning the root as
+    /// `Arc<dyn Any + Send + Sync>`.
+    #[inline]
+    pub(crate) fn new_any<T: Send + Sync + 'static>(arc: Arc<T>) -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(Arc::into_raw(arc)),
+            vtable: &ArcErased::<T>::VTABLE_ANY,
+        }
+        .registered()
+    }
+
+    /// Records the new root for `debug::dump_graph` with the `debug-cycles` feature.
+    #[inline]
+    fn registered(self) -> Self {
+        #[cfg(feature = "debug-cycles")]
+        crate::debug::register(&self);
+        self
+    }
+
+    /// Creates a `TypeErasedArc` borrowing `'static` data without any reference counting.
+    #[inline]
+    pub(crate) const fn from_static<T: ?Sized + Sync>(value: &'static T) -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(value as *const T),
+            vtable: &StaticErased::<T>::VTABLE,
+        }
+    }
+
+    /// Returns `true` if this was created with [`TypeErasedArc::from_static`].
+    #[inline]
+    pub(crate) fn is_static(&self) -> bool {
+        // Arc aborts long before a real strong count could reach usize::MAX
+        self.strong_count() == usize::MAX
+    }
+
+    /// Consumes `self` without decrementing the strong count, returning its raw parts.
+    #[inline]
+    pub(crate) fn into_raw(self) -> (TypeErasedPtr, &'static RcVTable) {
+        let this = ManuallyDrop::new(self);
+        (this.ptr, this.vtable)
     }
 
+    /// Reconstructs `Self` from the parts returned by [`TypeErasedArc::into_raw`].
+    ///
+    /// # Safety
+    /// `ptr` and `vtable` must come from a call to [`TypeErasedArc::into_raw`] and
+    /// the strong count they own must not have been released since.
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: TypeErasedPtr, vtable: &'static RcVTable) -> Self {
+        Self { ptr, vtable }
+    }
+
+    #[cfg(feature = "weak")]
     #[inline]
     pub(crate) fn downgrade(&self) -> TypeErasedWeak {
         TypeErasedWeak {
@@ -45,6 +115,61 @@ impl TypeErasedArc {
         // which guarantees that self.vtable and self.ptr match
         unsafe { (self.vtable.weak_count)(self.ptr) }
     }
+
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        // SAFETY: once set in TypeErasedArc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.is_unique)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const () {
+        // SAFETY: once set in TypeErasedArc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.as_ptr)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn root_layout(&self) -> Layout {
+        // SAFETY: once set in TypeErasedArc::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.root_layout)(self.ptr) }
+    }
+
+    #[inline]
+    pub(crate) fn any_root(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        let any_root = self.vtable.any_root?;
+        // SAFETY: once set in TypeErasedArc::new_any, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match;
+        // any_root is guaranteed to return an erased pointer to Arc<dyn Any + Send + Sync>
+        unsafe {
+            let ptr = any_root(self.ptr);
+            Some(Arc::from_raw(ptr.as_ptr::<dyn Any + Send + Sync>()))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn type_id(&self) -> Option<TypeId> {
+        self.vtable.type_id.map(|type_id| type_id())
+    }
+
+    /// Returns the pointer to the root value if it's known to be an `R`.
+    #[inline]
+    pub(crate) fn root_ptr_as<R: ?Sized + 'static>(&self) -> Option<*const R> {
+        if self.type_id()? == TypeId::of::<R>() {
+            // SAFETY: the vtable only knows the type of T when self.ptr is an erased Arc<T>
+            Some(unsafe { self.ptr.as_ptr::<R>() })
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "type-names")]
+    #[inline]
+    pub(crate) fn type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
 }
 
 impl Clone for TypeErasedArc {
@@ -62,18 +187,49 @@ impl Clone for TypeErasedArc {
 impl Drop for TypeErasedArc {
     #[inline]
     fn drop(&mut self) {
+        #[cfg(feature = "debug-cycles")]
+        if self.strong_count() == 1 {
+            crate::debug::unregister(self);
+        }
         // SAFETY: once set in TypeErasedArc::new, self.vtable is never modified,
         // which guarantees that self.vtable and self.ptr match
         unsafe { (self.vtable.drop)(self.ptr) }
     }
 }
 
+#[cfg(feature = "weak")]
 pub(crate) struct TypeErasedWeak {
     ptr: TypeErasedPtr,
     vtable: &'static RcVTable,
 }
 
+#[cfg(feature = "weak")]
 impl TypeErasedWeak {
+    /// Like [`TypeErasedArc::new_any`], but for `Weak<T>`.
+    #[cfg(feature = "unique-rc-arc")]
+    #[inline]
+    pub(crate) fn new_any<T: Send + Sync + 'static>(weak: Weak<T>) -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(Weak::into_raw(weak)),
+            vtable: &ArcErased::<T>::VTABLE_ANY,
+        }
+    }
+
+    /// Creates a `TypeErasedWeak` that doesn't point to any allocation.
+    #[inline]
+    pub(crate) fn dangling() -> Self {
+        Self {
+            ptr: TypeErasedPtr::new(core::ptr::null::<()>()),
+            vtable: &DanglingErased::VTABLE,
+        }
+    }
+
+    /// Returns `true` if this was created with [`TypeErasedWeak::dangling`].
+    #[inline]
+    pub(crate) fn is_dangling(&self) -> bool {
+        self.as_ptr().is_null()
+    }
+
     #[inline]
     pub(crate) fn upgrade(&self) -> Option<TypeErasedArc> {
         Some(TypeErasedArc {
@@ -96,8 +252,16 @@ impl TypeErasedWeak {
         // which guarantees that self.vtable and self.ptr match
         unsafe { (self.vtable.weak_count_weak)(self.ptr) }
     }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const () {
+        // SAFETY: once set in TypeErasedWeak::new, self.vtable is never modified,
+        // which guarantees that self.vtable and self.ptr match
+        unsafe { (self.vtable.as_ptr)(self.ptr) }
+    }
 }
 
+#[cfg(feature = "weak")]
 impl Clone for TypeErasedWeak {
     #[inline]
     fn clone(&self) -> Self {
@@ -108,6 +272,7 @@ impl Clone for TypeErasedWeak {
     }
 }
 
+#[cfg(feature = "weak")]
 impl Drop for TypeErasedWeak {
     #[inline]
     fn drop(&mut self) {
@@ -119,18 +284,46 @@ impl Drop for TypeErasedWeak {
 
 pub(crate) struct ArcErased<T: ?Sized>(PhantomData<*const T>);
 
-impl<T: ?Sized> ArcErased<T> {
-    // A "vtable" for Arc<T> and sync::Weak<T> where T: ?Sized
-    const VTABLE: RcVTable = RcVTable {
+impl<T: Send + Sync + 'static> ArcErased<T> {
+    // A "vtable" for Arc<T> that can also clone the root as Arc<dyn Any + Send + Sync>
+    const VTABLE_ANY: RcVTable = RcVTable {
+        any_root: Some(Self::any_root),
+        ..Self::VTABLE
+    };
+
+    // Must be called with an erased pointer to Arc<T>
+    unsafe fn any_root(ptr: TypeErasedPtr) -> TypeErasedPtr {
+        let arc = Self::as_manually_drop_arc(ptr);
+        let any: Arc<dyn Any + Send + Sync> = Arc::<T>::clone(&arc);
+        TypeErasedPtr::new(Arc::into_raw(any))
+    }
+}
+
+impl<T: ?Sized + 'static> ArcErased<T> {
+    // A "vtable" for Arc<T> and sync::Weak<T> where T: ?Sized, which also knows the type of T
+    pub(crate) const VTABLE: RcVTable = RcVTable {
         clone: Self::clone,
         drop: Self::drop,
+        #[cfg(feature = "weak")]
         downgrade: Self::downgrade,
         strong_count: Self::strong_count,
         weak_count: Self::weak_count,
+        is_unique: Self::is_unique,
+        as_ptr: Self::as_ptr,
+        root_layout: Self::root_layout,
+        type_id: Some(TypeId::of::<T>),
+        #[cfg(feature = "type-names")]
+        type_name: core::any::type_name::<T>,
+        any_root: None,
+        #[cfg(feature = "weak")]
         clone_weak: Self::clone_weak,
+        #[cfg(feature = "weak")]
         drop_weak: Self::drop_weak,
+        #[cfg(feature = "weak")]
         upgrade_weak: Self::upgrade_weak,
+        #[cfg(feature = "weak")]
         strong_count_weak: Self::strong_count_weak,
+        #[cfg(feature = "weak")]
         weak_count_weak: Self::weak_count_weak,
     };
 
@@ -147,6 +340,7 @@ impl<T: ?Sized> ArcErased<T> {
     }
 
     // Must be called with an erased pointer to Arc<T>
+    #[cfg(feature = "weak")]
     unsafe fn downgrade(ptr: TypeErasedPtr) -> TypeErasedPtr {
         let arc = Self::as_manually_drop_arc(ptr);
         let weak = Arc::downgrade(&arc);
@@ -163,28 +357,48 @@ impl<T: ?Sized> ArcErased<T> {
         let arc = Self::as_manually_drop_arc(ptr);
         Arc::weak_count(&arc)
     }
+    // Must be called with an erased pointer to Arc<T>
+    unsafe fn is_unique(ptr: TypeErasedPtr) -> bool {
+        let mut arc = Self::as_manually_drop_arc(ptr);
+        // Reading both counts separately would race with a weak pointer being upgraded and
+        // then dropped in between; `Arc::get_mut` locks the weak count while checking
+        Arc::get_mut(&mut arc).is_some()
+    }
+    // Must be called with an erased pointer to Arc<T> or sync::Weak<T>
+    unsafe fn as_ptr(ptr: TypeErasedPtr) -> *const () {
+        ptr.as_ptr::<T>() as *const ()
+    }
+    // Must be called with an erased pointer to Arc<T>
+    unsafe fn root_layout(ptr: TypeErasedPtr) -> Layout {
+        rc_allocation_layout(Layout::for_value::<T>(&*ptr.as_ptr()))
+    }
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn clone_weak(ptr: TypeErasedPtr) {
         let weak = Self::as_manually_drop_weak(ptr);
         let _cloned = weak.clone();
     }
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn drop_weak(ptr: TypeErasedPtr) {
         let weak: Weak<T> = Weak::from_raw(ptr.as_ptr());
         core::mem::drop(weak);
     }
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn upgrade_weak(ptr: TypeErasedPtr) -> Option<TypeErasedPtr> {
         let weak = Self::as_manually_drop_weak(ptr);
         let arc = weak.upgrade();
         arc.map(|arc| TypeErasedPtr::new(Arc::into_raw(arc)))
     }
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn strong_count_weak(ptr: TypeErasedPtr) -> usize {
         let weak = Self::as_manually_drop_weak(ptr);
         Weak::strong_count(&weak)
     }
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     unsafe fn weak_count_weak(ptr: TypeErasedPtr) -> usize {
         let weak = Self::as_manually_drop_weak(ptr);
         Weak::weak_count(&weak)
@@ -197,6 +411,7 @@ impl<T: ?Sized> ArcErased<T> {
     }
 
     // Must be called with an erased pointer to sync::Weak<T>
+    #[cfg(feature = "weak")]
     #[inline]
     unsafe fn as_manually_drop_weak(ptr: TypeErasedPtr) -> ManuallyDrop<Weak<T>> {
         ManuallyDrop::new(Weak::from_raw(ptr.as_ptr()))
@@ -238,6 +453,7 @@ mod tests {
         assert_eq!(unsafe { DROPPED_COUNT }, 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_drops_object_when_last_instance_drops_with_weak() {
@@ -268,6 +484,7 @@ mod tests {
         assert_eq!(unsafe { DROPPED_COUNT }, 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_strong_count_tracks_instances() {
@@ -289,6 +506,7 @@ mod tests {
         assert_eq!(erased2.strong_count(), 1);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn erased_arc_weak_count() {
@@ -316,6 +534,7 @@ mod tests {
         assert_eq!(weak.weak_count(), 0);
     }
 
+    #[cfg(feature = "weak")]
     #[test]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn weak_can_upgrade_when_there_are_instances() {
@@ -0,0 +1,133 @@
+//! Shared handles over memory managed outside of Rust, such as shared memory segments.
+
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    marker::{Send, Sync},
+    ptr::NonNull,
+};
+
+use super::{erased_arc::TypeErasedArc, Parc};
+
+/// The callback releasing an external segment.
+type Release<T> = Box<dyn FnOnce(NonNull<T>) + Send>;
+
+/// The root of `Parc`s created with [`Parc::from_external`].
+///
+/// Dropping the root, which happens when the last `Parc` sharing it is dropped,
+/// hands the segment back to its owner.
+struct ExternalSegment<T: ?Sized> {
+    ptr: NonNull<T>,
+    release: Option<Release<T>>,
+}
+
+// SAFETY: the segment is only ever read through shared references, which requires `T: Sync`
+// for them to be used from other threads, and `release` can be called from any thread
+unsafe impl<T: ?Sized + Sync> Send for ExternalSegment<T> {}
+// SAFETY: see above
+unsafe impl<T: ?Sized + Sync> Sync for ExternalSegment<T> {}
+
+impl<T: ?Sized> Drop for ExternalSegment<T> {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release(self.ptr);
+        }
+    }
+}
+
+impl<T> Parc<T>
+where
+    T: ?Sized + Sync + 'static,
+{
+    /// Constructs a `Parc<T>` over a value whose memory is managed outside of Rust,
+    /// such as a read-only memory-mapped segment shared between processes.
+    ///
+    /// `release` is called with `ptr` exactly once, when the last `Parc` sharing this root
+    /// is dropped, and should hand the memory back to its owner, e.g. by unmapping it.
+    /// It may be called from any thread. The returned `Parc` can be projected and cloned
+    /// like any other; [`Weak`](super::Weak) pointers to it stop upgrading once `release`
+    /// has been called.
+    ///
+    /// If the last `Parc` is leaked, e.g. with [`core::mem::forget`], `release` is never called.
+    ///
+    /// # Safety
+    /// Until `release` is called:
+    /// - `ptr` must be valid for reads and point to a properly initialized value of type `T`.
+    /// - The value must not be mutated, other than through `UnsafeCell`s within `T`,
+    ///   by this or any other process.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ptr::NonNull;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use pared::sync::Parc;
+    ///
+    /// static RELEASED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// // Stands in for a segment mapped by another library
+    /// let segment: &'static mut [u8] = Box::leak(Box::new(*b"header:body"));
+    /// let ptr = NonNull::from(segment);
+    ///
+    /// let release = |ptr: NonNull<[u8]>| {
+    ///     // SAFETY: the segment was leaked from a `Box` above, and is no longer used
+    ///     drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+    ///     RELEASED.store(true, Ordering::Relaxed);
+    /// };
+    /// // SAFETY: the segment stays valid and unmodified until it's released
+    /// let bytes = unsafe { Parc::from_external(ptr, release) };
+    ///
+    /// let body = bytes.project(|bytes| &bytes[7..]);
+    /// drop(bytes);
+    /// assert_eq!(&*body, b"body");
+    /// assert!(!RELEASED.load(Ordering::Relaxed));
+    /// drop(body);
+    /// assert!(RELEASED.load(Ordering::Relaxed));
+    /// ```
+    pub unsafe fn from_external<F>(ptr: NonNull<T>, release: F) -> Parc<T>
+    where
+        F: FnOnce(NonNull<T>) + Send + 'static,
+    {
+        let segment = Arc::new(ExternalSegment {
+            ptr,
+            release: Some(Box::new(release)),
+        });
+        // SAFETY: the caller guarantees that `ptr` stays valid for reads until `release`
+        // is called, which only happens once the root is dropped along with the last `Parc`
+        Parc {
+            arc: TypeErasedArc::new(segment),
+            projected: ptr,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "weak"))]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn releases_once_after_last_parc() {
+        static RELEASES: AtomicUsize = AtomicUsize::new(0);
+        let segment: &'static mut [u32] = Box::leak(Box::new([1, 2, 3]));
+
+        let release = |ptr: NonNull<[u32]>| {
+            // SAFETY: the segment was leaked from a `Box`
+            drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+            RELEASES.fetch_add(1, Ordering::Relaxed);
+        };
+        // SAFETY: the segment is leaked and never modified until it's released
+        let parc = unsafe { Parc::from_external(NonNull::from(segment), release) };
+        let weak = Parc::downgrade(&parc);
+        let clones: Vec<_> = (0..3).map(|i| parc.project(|slice| &slice[i])).collect();
+        drop(parc);
+        assert_eq!(clones.iter().map(|x| **x).sum::<u32>(), 6);
+        assert_eq!(RELEASES.load(Ordering::Relaxed), 0);
+
+        drop(clones);
+        assert_eq!(RELEASES.load(Ordering::Relaxed), 1);
+        assert!(weak.upgrade().is_none());
+        drop(weak);
+        assert_eq!(RELEASES.load(Ordering::Relaxed), 1);
+    }
+}
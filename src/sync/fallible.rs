@@ -0,0 +1,159 @@
+//! Fallible constructors for `Parc`.
+
+use alloc::{alloc::AllocError, boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{iter::IntoIterator, ptr::NonNull};
+
+use super::{Parc, TypeErasedArc};
+
+impl<T> Parc<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Constructs a new `Parc<T>`, returning an error if the allocation fails.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::try_new(6)?;
+    /// assert_eq!(*parc, 6);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_new(value: T) -> Result<Parc<T>, AllocError> {
+        let arc = Arc::try_new(value)?;
+        let projected = NonNull::from(&*arc);
+        Ok(Parc {
+            arc: TypeErasedArc::new_any(arc),
+            projected,
+        })
+    }
+}
+
+impl<T> Parc<T>
+where
+    T: ?Sized + Send + Sync + 'static,
+{
+    /// Constructs a new `Parc<T>` from a boxed value, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<Box<T>>`, the value isn't moved into a new allocation: the `Box` itself
+    /// is stored in the root `Arc`, and the `Parc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::<[u8]>::try_from_box(Box::from([1, 2, 3]))?;
+    /// assert_eq!(&*parc, &[1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_box(boxed: Box<T>) -> Result<Parc<T>, AllocError> {
+        let root = Parc::from_root(Arc::try_new(boxed)?);
+        Ok(root.project(|boxed| &**boxed))
+    }
+}
+
+impl<T> Parc<[T]>
+where
+    T: Send + Sync + 'static,
+{
+    /// Constructs a new `Parc<[T]>` from a `Vec<T>`, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<Vec<T>>`, the elements aren't moved into a new allocation: the `Vec` itself
+    /// is stored in the root `Arc`, and the `Parc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::try_from_vec(vec![1, 2, 3])?;
+    /// assert_eq!(&*parc, &[1, 2, 3]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Parc<[T]>, AllocError> {
+        Ok(Parc::from_root(Arc::try_new(vec)?).into())
+    }
+
+    /// Constructs a new `Parc<[T]>` from the items of an iterator,
+    /// returning an error if any allocation fails.
+    ///
+    /// This is the fallible version of `FromIterator`.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::try_from_iter((0..4).map(|x| x * 2))?;
+    /// assert_eq!(&*parc, &[0, 2, 4, 6]);
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Parc<[T]>, AllocError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let mut vec = Vec::new();
+        vec.try_reserve(iter.size_hint().0)
+            .map_err(|_| AllocError)?;
+        for item in iter {
+            if vec.len() == vec.capacity() {
+                vec.try_reserve(1).map_err(|_| AllocError)?;
+            }
+            vec.push(item);
+        }
+        Parc::try_from_vec(vec)
+    }
+}
+
+impl Parc<str> {
+    /// Constructs a new `Parc<str>` from a `String`, returning an error if the allocation fails.
+    ///
+    /// Unlike `From<String>`, the text isn't moved into a new allocation: the `String` itself
+    /// is stored in the root `Arc`, and the `Parc` projects into it.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pared::sync::Parc;
+    ///
+    /// let parc = Parc::try_from_string(String::from("pared"))?;
+    /// assert_eq!(&*parc, "pared");
+    /// # Ok::<(), std::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_from_string(string: String) -> Result<Parc<str>, AllocError> {
+        Ok(Parc::from_root(Arc::try_new(string)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fallible_constructors() {
+        let value = Parc::try_new((1u8, 2u16)).unwrap();
+        assert_eq!(*value.project(|x| &x.1), 2);
+
+        let boxed = Parc::<str>::try_from_box(Box::from("boxed")).unwrap();
+        assert_eq!(&*boxed, "boxed");
+
+        let collected = Parc::try_from_iter((0..100).filter(|x| x % 2 == 0)).unwrap();
+        assert_eq!(collected.len(), 50);
+        assert_eq!(collected[49], 98);
+
+        let string = Parc::try_from_string(String::from("string")).unwrap();
+        assert_eq!(&*string, "string");
+        assert_eq!(
+            Parc::root_type_id(&string),
+            Some(core::any::TypeId::of::<String>())
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Conversions and conveniences for handing shared strings to foreign code.
+
+use alloc::ffi::CString;
+use core::{
+    convert::From,
+    ffi::{c_char, CStr},
+};
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
+
+use super::Parc;
+
+impl Parc<CStr> {
+    /// Returns a pointer to the nul-terminated string, for passing it to foreign code.
+    ///
+    /// The pointer stays valid for as long as any `Parc` sharing this one's root allocation
+    /// is alive, so a clone of this `Parc` can be stored next to whatever foreign object
+    /// borrows the string instead of leaking it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::{CStr, CString};
+    /// use pared::sync::Parc;
+    ///
+    /// let name: Parc<CStr> = Parc::from(CString::new("pared").unwrap());
+    /// let ptr = name.as_c_ptr();
+    ///
+    /// let kept_alive = name.clone();
+    /// drop(name);
+    /// // SAFETY: `kept_alive` keeps the string alive
+    /// assert_eq!(unsafe { CStr::from_ptr(ptr) }, c"pared");
+    /// # drop(kept_alive);
+    /// ```
+    #[inline]
+    pub fn as_c_ptr(&self) -> *const c_char {
+        self.as_ptr()
+    }
+}
+
+/// Converts the `Parc<CString>` into a `Parc<CStr>` without touching the reference counts.
+///
+/// Note that converting an owned `CString` with `Parc::from` copies it into a new allocation.
+///
+/// # Example
+/// ```
+/// use std::ffi::{CStr, CString};
+/// use pared::sync::Parc;
+///
+/// let name: Parc<CStr> = Parc::new(CString::new("pared").unwrap()).into();
+/// assert_eq!(&*name, c"pared");
+/// ```
+impl From<Parc<CString>> for Parc<CStr> {
+    #[inline]
+    fn from(parc: Parc<CString>) -> Self {
+        parc.map_owned(CString::as_c_str)
+    }
+}
+
+/// Converts the `Parc<OsString>` into a `Parc<OsStr>` without touching the reference counts.
+///
+/// Note that converting an owned `OsString` with `Parc::from` copies it into a new allocation.
+///
+/// # Example
+/// ```
+/// use std::ffi::{OsStr, OsString};
+/// use pared::sync::Parc;
+///
+/// let name: Parc<OsStr> = Parc::new(OsString::from("pared")).into();
+/// assert_eq!(&*name, "pared");
+/// ```
+#[cfg(feature = "std")]
+impl From<Parc<OsString>> for Parc<OsStr> {
+    #[inline]
+    fn from(parc: Parc<OsString>) -> Self {
+        parc.map_owned(OsString::as_os_str)
+    }
+}
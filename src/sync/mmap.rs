@@ -0,0 +1,40 @@
+//! Shared byte slices backed by memory-mapped files using [`memmap2`].
+
+use alloc::sync::Arc;
+use memmap2::Mmap;
+
+use super::Parc;
+
+impl Parc<[u8]> {
+    /// Adopts a memory-mapped file as a `Parc<[u8]>`.
+    ///
+    /// The [`Mmap`] is moved into the root allocation, and the file stays mapped until
+    /// the last `Parc` projected from it is dropped. This lets parsers share file-backed bytes
+    /// using the same type they use for heap buffers.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Write;
+    /// use memmap2::Mmap;
+    /// use pared::sync::Parc;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// # let path = std::env::temp_dir().join("pared-from-mmap-doctest");
+    /// std::fs::File::create(&path)?.write_all(b"header:body")?;
+    /// let file = std::fs::File::open(&path)?;
+    /// // SAFETY: the file isn't modified while it's mapped
+    /// let bytes = Parc::from_mmap(unsafe { Mmap::map(&file)? });
+    ///
+    /// let body = bytes.project(|bytes| &bytes[7..]);
+    /// drop(bytes);
+    /// assert_eq!(&*body, b"body");
+    /// # drop(body);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_mmap(map: Mmap) -> Parc<[u8]> {
+        Parc::from_arc(&Arc::new(map), |map| &map[..])
+    }
+}
@@ -0,0 +1,166 @@
+//! Projections into shared paths.
+
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+use super::Parc;
+
+impl Parc<Path> {
+    /// Projects to the final component of the path, if there is one.
+    ///
+    /// See [`Path::file_name`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use pared::sync::Parc;
+    ///
+    /// let path: Parc<Path> = Parc::from(PathBuf::from("/assets/textures/grass.png"));
+    /// assert_eq!(&*path.file_name_parc().unwrap(), "grass.png");
+    /// assert!(Parc::<Path>::from(Path::new("/")).file_name_parc().is_none());
+    /// ```
+    #[inline]
+    pub fn file_name_parc(&self) -> Option<Parc<OsStr>> {
+        self.try_project(|path| path.file_name().ok_or(())).ok()
+    }
+
+    /// Projects to the file name without its extension, if there is one.
+    ///
+    /// See [`Path::file_stem`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use pared::sync::Parc;
+    ///
+    /// let path: Parc<Path> = Parc::from(Path::new("shaders/water.frag.glsl"));
+    /// assert_eq!(&*path.file_stem_parc().unwrap(), "water.frag");
+    /// ```
+    #[inline]
+    pub fn file_stem_parc(&self) -> Option<Parc<OsStr>> {
+        self.try_project(|path| path.file_stem().ok_or(())).ok()
+    }
+
+    /// Projects to the extension of the file name, if there is one.
+    ///
+    /// See [`Path::extension`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use pared::sync::Parc;
+    ///
+    /// let path: Parc<Path> = Parc::from(Path::new("shaders/water.frag.glsl"));
+    /// assert_eq!(&*path.extension_parc().unwrap(), "glsl");
+    /// assert!(Parc::<Path>::from(Path::new("Makefile")).extension_parc().is_none());
+    /// ```
+    #[inline]
+    pub fn extension_parc(&self) -> Option<Parc<OsStr>> {
+        self.try_project(|path| path.extension().ok_or(())).ok()
+    }
+
+    /// Projects to the path without its final component, if there is one.
+    ///
+    /// See [`Path::parent`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use pared::sync::Parc;
+    ///
+    /// let path: Parc<Path> = Parc::from(Path::new("/assets/grass.png"));
+    /// assert_eq!(&*path.parent_parc().unwrap(), Path::new("/assets"));
+    /// ```
+    #[inline]
+    pub fn parent_parc(&self) -> Option<Parc<Path>> {
+        self.try_project(|path| path.parent().ok_or(())).ok()
+    }
+
+    /// Projects to the path as an [`OsStr`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::Path;
+    /// use pared::sync::Parc;
+    ///
+    /// let path: Parc<Path> = Parc::from(Path::new("/assets"));
+    /// assert_eq!(&*path.as_os_str_parc(), "/assets");
+    /// ```
+    #[inline]
+    pub fn as_os_str_parc(&self) -> Parc<OsStr> {
+        self.project(Path::as_os_str)
+    }
+}
+
+impl Parc<PathBuf> {
+    /// Projects this `Parc<PathBuf>` to a path slice.
+    ///
+    /// To convert the `Parc` without cloning it, use the `From<Parc<PathBuf>>` implementation
+    /// for `Parc<Path>`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use pared::sync::Parc;
+    ///
+    /// let path = Parc::new(PathBuf::from("/assets"));
+    /// let path: Parc<Path> = path.as_path_parc();
+    /// assert_eq!(&*path, Path::new("/assets"));
+    /// ```
+    #[inline]
+    pub fn as_path_parc(&self) -> Parc<Path> {
+        self.project(PathBuf::as_path)
+    }
+}
+
+/// Converts the `Parc<PathBuf>` into a `Parc<Path>` without touching the reference counts.
+///
+/// Note that converting an owned `PathBuf` with `Parc::from` copies it into a new allocation;
+/// to reuse the `PathBuf`'s buffer instead, wrap it in a `Parc` first.
+///
+/// # Example
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use pared::sync::Parc;
+///
+/// let path: Parc<Path> = Parc::new(PathBuf::from("/assets")).into();
+/// assert_eq!(&*path, Path::new("/assets"));
+/// ```
+impl From<Parc<PathBuf>> for Parc<Path> {
+    #[inline]
+    fn from(parc: Parc<PathBuf>) -> Self {
+        parc.map_owned(PathBuf::as_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn components_share_root() {
+        let buf = Parc::new(PathBuf::from("/a/b.tar.gz"));
+        let path: Parc<Path> = buf.clone().into();
+        assert!(core::ptr::eq(&*path, buf.as_path()));
+
+        let name = path.file_name_parc().unwrap();
+        let stem = path.file_stem_parc().unwrap();
+        let extension = path.extension_parc().unwrap();
+        let parent = path.parent_parc().unwrap();
+        assert_eq!(&*name, "b.tar.gz");
+        assert_eq!(&*stem, "b.tar");
+        assert_eq!(&*extension, "gz");
+        assert_eq!(&*parent, Path::new("/a"));
+        assert_eq!(&*buf.as_path_parc().as_os_str_parc(), "/a/b.tar.gz");
+        assert_eq!(Parc::strong_count(&buf), 6);
+
+        let root: Parc<Path> = Parc::from(Path::new("/"));
+        assert!(root.file_name_parc().is_none());
+        assert!(root.extension_parc().is_none());
+        assert!(root.file_stem_parc().is_none());
+        assert!(root.parent_parc().is_none());
+    }
+}
@@ -0,0 +1,79 @@
+//! Zero-copy reinterpretation of shared byte slices using [`bytemuck`].
+
+use bytemuck::{Pod, PodCastError};
+
+use super::Parc;
+
+impl Parc<[u8]> {
+    /// Reinterprets the bytes as a slice of `T`, sharing the same root allocation.
+    ///
+    /// This fails if the bytes aren't aligned for `T`, or if their length isn't a multiple of
+    /// the size of `T`; see [`bytemuck::try_cast_slice`].
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let column: Parc<[u8]> = Parc::<[u32]>::from(vec![0, 1, 2]).as_bytes_parc();
+    /// let values = column.try_cast_slice::<u32>().unwrap();
+    /// assert_eq!(&*values, &[0, 1, 2]);
+    ///
+    /// let odd = column.project(|bytes| &bytes[..5]);
+    /// assert!(odd.try_cast_slice::<u32>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_cast_slice<T>(&self) -> Result<Parc<[T]>, PodCastError>
+    where
+        T: Pod + Send + Sync,
+    {
+        self.try_project(bytemuck::try_cast_slice)
+    }
+}
+
+impl<T> Parc<[T]>
+where
+    T: Pod + Send + Sync,
+{
+    /// Reinterprets the slice as its underlying bytes, sharing the same root allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// let values: Parc<[u16]> = Parc::from(vec![0x0102u16, 0x0304]);
+    /// let bytes = values.as_bytes_parc();
+    /// assert_eq!(bytes.len(), 4);
+    /// assert_eq!(&*bytes.try_cast_slice::<u16>().unwrap(), &*values);
+    /// ```
+    #[inline]
+    pub fn as_bytes_parc(&self) -> Parc<[u8]> {
+        self.project(bytemuck::cast_slice::<T, u8>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn casts_share_root() {
+        let values: Parc<[u32]> = Parc::from(vec![1u32, 2, 3]);
+        let bytes = values.as_bytes_parc();
+        let cast = bytes.try_cast_slice::<u32>().unwrap();
+        assert!(Parc::ptr_eq(&cast, &values));
+        assert_eq!(Parc::strong_count(&values), 3);
+
+        let misaligned = bytes.project(|bytes| &bytes[1..5]);
+        assert_eq!(
+            misaligned.try_cast_slice::<u32>().unwrap_err(),
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned
+        );
+        let short = bytes.project(|bytes| &bytes[..6]);
+        assert_eq!(
+            short.try_cast_slice::<u32>().unwrap_err(),
+            PodCastError::OutputSliceWouldHaveSlop
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Mutable projections of uniquely owned `Parc`s.
+
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    marker::{PhantomData, Send, Sync},
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use super::{erased_arc::TypeErasedArc, Parc};
+
+impl<T: 'static> Parc<T> {
+    /// Projects a mutable reference out of this `Parc`, if it's the only pointer to its
+    /// allocation and points at the root value itself, which must have been created
+    /// with [`Parc::new`] or from an `Arc<T>`.
+    ///
+    /// The returned guard has exclusive access to the projected value until it's dropped or
+    /// turned back into a shared `Parc` with [`ParcMutGuard::into_parc`], which makes it easy
+    /// to initialize a value in place before sharing it.
+    ///
+    /// # Errors
+    /// Like [`Prc::get_mut`](crate::prc::Prc::get_mut), this fails if other `Parc`s or [`Weak`]
+    /// pointers to the allocation exist, or if `this` is itself a projection, since
+    /// the projecting function only promised shared access. This includes projections to
+    /// a field that happens to start at the root, such as the only field of a newtype.
+    /// In that case, `this` is returned unchanged and `project` isn't called.
+    ///
+    /// [`Weak`]: super::Weak
+    ///
+    /// # Example
+    /// ```
+    /// use pared::sync::Parc;
+    ///
+    /// struct Table {
+    ///     name: String,
+    ///     rows: Vec<u32>,
+    /// }
+    ///
+    /// let table = Parc::new(Table { name: String::from("primes"), rows: Vec::new() });
+    /// let mut rows = Parc::try_project_mut(table, |table| &mut table.rows).ok().unwrap();
+    /// rows.extend([2, 3, 5, 7]);
+    ///
+    /// let rows = rows.into_parc();
+    /// let shared = rows.clone();
+    /// assert_eq!(&shared[..], [2, 3, 5, 7]);
+    ///
+    /// // The rows are shared now, so they can't be mutated anymore
+    /// assert!(Parc::try_project_mut(rows, |rows| rows).is_err());
+    /// ```
+    pub fn try_project_mut<U, F>(this: Parc<T>, project: F) -> Result<ParcMutGuard<U>, Parc<T>>
+    where
+        T: Send + Sync,
+        U: ?Sized + 'static,
+        F: for<'x> FnOnce(&'x mut T) -> &'x mut U,
+    {
+        let root = match this.arc.root_ptr_as::<T>() {
+            Some(root) if core::ptr::eq(root, this.projected.as_ptr()) => root,
+            _ => return Err(this),
+        };
+        // SAFETY: the vtable only knows the root's type when it's an erased `Arc<T>`,
+        // and the `Arc` is never dropped through this copy of it
+        let mut arc = ManuallyDrop::new(unsafe { Arc::from_raw(root) });
+        // `Arc::get_mut` locks the weak count while checking the strong count,
+        // so a concurrent `Weak::upgrade` can't slip in between the two checks
+        let root: *mut T = match Arc::get_mut(&mut arc) {
+            Some(root) => root,
+            None => return Err(this),
+        };
+        // SAFETY: this is the only pointer to the allocation, and it points at the root
+        // value itself, which the `Arc` owns and nothing else can be borrowing.
+        // The root pointer is used (rather than `projected`) since it came from the `Arc`
+        // and allows writes. The reference returned by `project` must borrow from it,
+        // or be 'static, so it stays valid for as long as the allocation.
+        let projected = NonNull::from(project(unsafe { &mut *root }));
+        Ok(ParcMutGuard {
+            arc: this.arc,
+            projected,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Exclusive access to a value projected out of a uniquely owned [`Parc`],
+/// created by [`Parc::try_project_mut`].
+///
+/// While the guard is alive, it's the only pointer to its allocation. It can be turned into
+/// a `Parc` to share the value with [`ParcMutGuard::into_parc`].
+pub struct ParcMutGuard<T: ?Sized> {
+    arc: TypeErasedArc,
+    projected: NonNull<T>,
+    // Mutable access makes the guard invariant in `T`, like `&mut T`
+    _marker: PhantomData<*mut T>,
+}
+
+// SAFETY: the guard owns the only strong count of its allocation, and becomes a `Parc`,
+// so it has the same bounds
+unsafe impl<T> Send for ParcMutGuard<T> where T: Sync + Send + ?Sized {}
+// SAFETY: a shared guard only gives shared access to the projected value
+unsafe impl<T> Sync for ParcMutGuard<T> where T: Sync + Send + ?Sized {}
+
+impl<T: ?Sized> ParcMutGuard<T> {
+    /// Gives up exclusive access, returning a `Parc` to the projected value
+    /// that can be shared.
+    #[inline]
+    pub fn into_parc(self) -> Parc<T> {
+        let ParcMutGuard { arc, projected, .. } = self;
+        Parc { arc, projected }
+    }
+}
+
+impl<T: ?Sized> Deref for ParcMutGuard<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: the guard has exclusive access to the projected value,
+        // which the allocation it keeps alive owns
+        unsafe { self.projected.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for ParcMutGuard<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: see above
+        unsafe { self.projected.as_mut() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ParcMutGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParcMutGuard")
+            .field("projected", &self.deref())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, vec, vec::Vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn only_projects_unique_roots() {
+        let pair = Parc::new((String::from("a"), 1u64));
+        let first = pair.project(|pair| &pair.0);
+        let pair = Parc::try_project_mut(pair, |pair| &mut pair.1).unwrap_err();
+        drop(first);
+
+        // Projections can't be mutated, even if they start at the root
+        let first = pair.project(|pair| &pair.0);
+        drop(pair);
+        let first = Parc::try_project_mut(first, |first| first).unwrap_err();
+        assert!(Parc::is_unique(&first));
+
+        let mut name =
+            Parc::try_project_mut(Parc::new(String::from("b")), |name| name.as_mut_str()).unwrap();
+        name.make_ascii_uppercase();
+        assert_eq!(format!("{:?}", name), "ParcMutGuard { projected: \"B\" }");
+        assert_eq!(&*name.into_parc(), "B");
+
+        assert!(Parc::try_project_mut(Parc::from_static(&5), |x: &mut i32| x).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn rejects_newtype_projections() {
+        // The field is private, so `Sorted` only lends it out as shared
+        struct Sorted(Vec<u32>);
+
+        impl Sorted {
+            fn as_vec(&self) -> &Vec<u32> {
+                &self.0
+            }
+        }
+
+        let sorted = Parc::new(Sorted(vec![1, 2, 3]));
+        let vec = sorted.project(Sorted::as_vec);
+        drop(sorted);
+        assert!(Parc::is_unique(&vec));
+        assert!(Parc::try_project_mut(vec, |vec| vec).is_err());
+
+        // The root has to be the projected type itself
+        let arc = alloc::sync::Arc::new(Sorted(vec![4]));
+        let from_arc = Parc::from_arc(&arc, Sorted::as_vec);
+        drop(arc);
+        assert!(Parc::try_project_mut(from_arc, |vec| vec).is_err());
+
+        let vec = Parc::new(vec![4u32]);
+        let mut guard = Parc::try_project_mut(vec, |vec| vec).unwrap();
+        guard.push(5);
+        assert_eq!(&**guard, [4, 5]);
+
+        let arc = alloc::sync::Arc::new(vec![6u32]);
+        let from_arc = Parc::from_arc(&arc, |vec| vec);
+        drop(arc);
+        let mut guard = Parc::try_project_mut(from_arc, |vec| vec).unwrap();
+        guard.push(7);
+        assert_eq!(&**guard, [6, 7]);
+    }
+}
@@ -0,0 +1,169 @@
+//! A compact set of keep-alives for the roots a value depends on.
+
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    marker::{Send, Sync},
+};
+use smallvec::SmallVec;
+
+use super::{erased_arc::TypeErasedArc, Parc};
+
+/// A set of roots kept alive on behalf of a value derived from them.
+///
+/// A value computed from several shared resources, such as a text layout depending on fonts,
+/// textures and a config, can hold a `RootSet` to make those dependencies explicit and keep
+/// them alive without caring about their types. Only the roots are kept: adopting several
+/// `Parc`s backed by the same allocation keeps a single strong pointer to it.
+///
+/// Up to four roots are stored inline, without allocating.
+///
+/// This type is only available with the `smallvec` feature.
+///
+/// # Example
+/// ```
+/// use pared::sync::{Parc, RootSet};
+///
+/// let fonts = Parc::new(vec![String::from("serif"), String::from("mono")]);
+/// let config = Parc::new((12u32, String::from("dark")));
+///
+/// let mut keep_alive = RootSet::new();
+/// keep_alive.adopt(fonts.project(|fonts| &fonts[1]));
+/// keep_alive.adopt(config.project(|config| &config.0));
+/// keep_alive.adopt(config.project(|config| &config.1));
+///
+/// assert_eq!(keep_alive.len(), 2);
+/// assert!(keep_alive.contains(&fonts));
+/// assert_eq!(Parc::strong_count(&config), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct RootSet {
+    roots: SmallVec<[TypeErasedArc; 4]>,
+}
+
+impl RootSet {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            roots: SmallVec::new_const(),
+        }
+    }
+
+    /// Keeps the root of `parc` alive for as long as this set, dropping `parc`
+    /// if its root is already in the set.
+    #[inline]
+    pub fn adopt<T: ?Sized>(&mut self, parc: Parc<T>) {
+        let Parc { arc, .. } = parc;
+        self.insert(arc);
+    }
+
+    /// Keeps `arc` alive for as long as this set, dropping it if it's already in the set.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use pared::sync::{Parc, RootSet};
+    ///
+    /// let texture = Arc::new([0u8; 64]);
+    /// let mut keep_alive = RootSet::new();
+    /// keep_alive.adopt_arc(texture.clone());
+    /// keep_alive.adopt(Parc::from_arc(&texture, |texture| &texture[..16]));
+    /// assert_eq!(Arc::strong_count(&texture), 2);
+    /// ```
+    #[inline]
+    pub fn adopt_arc<T>(&mut self, arc: Arc<T>)
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.insert(TypeErasedArc::new(arc));
+    }
+
+    fn insert(&mut self, arc: TypeErasedArc) {
+        if !self.roots.iter().any(|root| root.as_ptr() == arc.as_ptr()) {
+            self.roots.push(arc);
+        }
+    }
+
+    /// Returns `true` if the root of `parc` is kept alive by this set.
+    #[inline]
+    pub fn contains<T: ?Sized>(&self, parc: &Parc<T>) -> bool {
+        self.roots
+            .iter()
+            .any(|root| root.as_ptr() == Parc::root_ptr(parc))
+    }
+
+    /// Returns the number of distinct roots in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns `true` if the set doesn't keep any roots alive.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Releases all roots in the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.roots.clear();
+    }
+}
+
+impl fmt::Debug for RootSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RootSet")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Extend<Parc<T>> for RootSet {
+    fn extend<I: IntoIterator<Item = Parc<T>>>(&mut self, iter: I) {
+        for parc in iter {
+            self.adopt(parc);
+        }
+    }
+}
+
+impl<T: ?Sized> FromIterator<Parc<T>> for RootSet {
+    fn from_iter<I: IntoIterator<Item = Parc<T>>>(iter: I) -> Self {
+        let mut set = RootSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn keeps_distinct_roots_alive() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<RootSet>();
+
+        let first = Parc::new(vec![1, 2, 3]);
+        let second = Parc::new(5);
+        let mut set: RootSet = (0..3)
+            .map(|i| first.project(move |first| &first[i]))
+            .collect();
+        assert_eq!(set.len(), 1);
+        assert_eq!(Parc::strong_count(&first), 2);
+
+        set.extend([second.clone()]);
+        let clone = set.clone();
+        assert_eq!(Parc::strong_count(&second), 3);
+        assert_eq!(format!("{:?}", clone), "RootSet { len: 2, .. }");
+
+        set.clear();
+        assert!(set.is_empty() && !set.contains(&first));
+        assert!(clone.contains(&second));
+        drop(clone);
+        assert_eq!(Parc::strong_count(&first), 1);
+    }
+}
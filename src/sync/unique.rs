@@ -0,0 +1,159 @@
+//! Uniquely owned `Parc`s that can be mutated before they're shared.
+
+use alloc::sync::UniqueArc;
+use core::{
+    marker::{Send, Sync},
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use super::{
+    erased_arc::{TypeErasedArc, TypeErasedWeak},
+    Parc, Weak,
+};
+
+/// A uniquely owned, mutable value that's later frozen into a [`Parc`].
+///
+/// This is the `Parc` version of [`UniqueArc`]: [`Weak`] pointers can be created with
+/// [`UniqueParc::downgrade`] while the value is still being built, and they can only be
+/// upgraded once it's frozen with [`UniqueParc::into_parc`].
+/// This allows building cyclic structures step by step, rather than all at once inside
+/// a closure.
+///
+/// This type is only available with the `unique-rc-arc` feature, which requires nightly Rust.
+///
+/// # Example
+/// ```
+/// #![feature(unique_rc_arc)]
+/// use pared::sync::{Parc, UniqueParc, Weak};
+///
+/// struct Parent {
+///     children: Vec<Child>,
+/// }
+///
+/// struct Child {
+///     name: &'static str,
+///     parent: Weak<Parent>,
+/// }
+///
+/// let mut parent = UniqueParc::new(Parent { children: Vec::new() });
+/// for name in ["first", "second"] {
+///     let weak = UniqueParc::downgrade(&parent);
+///     // The parent can't be reached until it's frozen
+///     assert!(weak.upgrade().is_none());
+///     parent.children.push(Child { name, parent: weak });
+/// }
+///
+/// let parent = UniqueParc::into_parc(parent);
+/// let child = parent.project(|parent| &parent.children[1]);
+/// assert!(Parc::ptr_eq(&child.parent.upgrade().unwrap(), &parent));
+/// assert_eq!(child.name, "second");
+/// ```
+///
+/// [`UniqueArc`]: https://doc.rust-lang.org/alloc/sync/struct.UniqueArc.html
+pub struct UniqueParc<T> {
+    unique: UniqueArc<T>,
+}
+
+impl<T> UniqueParc<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Constructs a new `UniqueParc<T>`.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self {
+            unique: UniqueArc::new(value),
+        }
+    }
+
+    /// Creates a new [`Weak`] pointer to this allocation.
+    ///
+    /// The `Weak` pointer can't be upgraded until this `UniqueParc` is frozen with
+    /// [`UniqueParc::into_parc`]. If it's dropped instead, the `Weak` pointer never
+    /// becomes upgradable.
+    #[inline]
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        Weak {
+            weak: TypeErasedWeak::new_any(UniqueArc::downgrade(&this.unique)),
+            projected: NonNull::from(&*this.unique),
+        }
+    }
+
+    /// Freezes this `UniqueParc` into a shared `Parc<T>`.
+    ///
+    /// Any [`Weak`] pointers created with [`UniqueParc::downgrade`] can be upgraded afterwards.
+    #[inline]
+    pub fn into_parc(this: Self) -> Parc<T> {
+        let arc = UniqueArc::into_arc(this.unique);
+        let projected = NonNull::from(&*arc);
+        Parc {
+            arc: TypeErasedArc::new_any(arc),
+            projected,
+        }
+    }
+}
+
+impl<T> Deref for UniqueParc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.unique
+    }
+}
+
+impl<T> DerefMut for UniqueParc<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.unique
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for UniqueParc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> From<UniqueParc<T>> for Parc<T>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn from(unique: UniqueParc<T>) -> Self {
+        UniqueParc::into_parc(unique)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn weak_pointers_upgrade_once_frozen() {
+        let mut unique = UniqueParc::new((1u8, 2u16));
+        let weak = UniqueParc::downgrade(&unique);
+        assert!(weak.upgrade().is_none());
+        assert_eq!(weak.strong_count(), 0);
+
+        unique.1 = 5;
+        assert_eq!(format!("{:?}", unique), "(1, 5)");
+
+        let parc = Parc::from(unique);
+        assert_eq!(*weak.upgrade().unwrap(), (1, 5));
+        assert_eq!(weak.as_ptr(), Parc::as_ptr(&parc));
+        assert_eq!(Parc::weak_count(&parc), 1);
+        assert_eq!(
+            Parc::root_type_id(&parc),
+            Some(core::any::TypeId::of::<(u8, u16)>())
+        );
+
+        let dropped = UniqueParc::new(3u8);
+        let weak = UniqueParc::downgrade(&dropped);
+        drop(dropped);
+        assert!(weak.upgrade().is_none());
+    }
+}
@@ -0,0 +1,137 @@
+//! Wakers for single-threaded executors backed by [`Prc`]s.
+//!
+//! This module is only available with the `local-waker` feature, which requires nightly Rust.
+//!
+//! # Example
+//! ```
+//! #![feature(local_waker)]
+//! use std::cell::Cell;
+//! use pared::{prc::Prc, task::{local_waker, PrcWake}};
+//!
+//! struct Woken(Cell<bool>);
+//!
+//! impl PrcWake for Woken {
+//!     fn wake_by_ref(this: &Prc<Self>) {
+//!         this.0.set(true);
+//!     }
+//! }
+//!
+//! struct Task {
+//!     name: &'static str,
+//!     woken: Woken,
+//! }
+//!
+//! let task = Prc::new(Task { name: "render", woken: Woken(Cell::new(false)) });
+//! let waker = local_waker(task.project(|task| &task.woken));
+//!
+//! waker.wake_by_ref();
+//! assert!(task.woken.0.get());
+//! ```
+
+use alloc::rc::Rc;
+use core::{
+    marker::Sized,
+    task::{LocalWaker, RawWaker, RawWakerVTable},
+};
+
+use crate::prc::Prc;
+
+/// The implementation of waking a task through a [`Prc`].
+///
+/// This is the `Prc` version of [`Wake`], allowing the waker to point to any part of a task.
+///
+/// [`Wake`]: https://doc.rust-lang.org/alloc/task/trait.Wake.html
+pub trait PrcWake {
+    /// Wake this task without consuming the `Prc`.
+    fn wake_by_ref(this: &Prc<Self>);
+
+    /// Wake this task.
+    ///
+    /// By default, this calls [`PrcWake::wake_by_ref`] and drops the `Prc` afterwards.
+    fn wake(this: Prc<Self>)
+    where
+        Self: Sized,
+    {
+        Self::wake_by_ref(&this);
+    }
+}
+
+/// Creates a [`LocalWaker`] from a [`Prc`].
+///
+/// The waker keeps the task alive for as long as any of its clones exists.
+///
+/// Note that the `Prc` doesn't fit into a [`RawWaker`], so this allocates an `Rc` to hold it,
+/// which is shared by all clones of the returned `LocalWaker`. Cloning and dropping the waker
+/// only updates the count of that `Rc`, without allocating.
+pub fn local_waker<W>(prc: Prc<W>) -> LocalWaker
+where
+    W: PrcWake + 'static,
+{
+    let data = Rc::into_raw(Rc::new(prc)) as *const ();
+    // SAFETY: the vtable functions all expect a pointer to an Rc<Prc<W>>
+    unsafe { LocalWaker::from_raw(RawWaker::new(data, &PrcWaker::<W>::VTABLE)) }
+}
+
+struct PrcWaker<W>(W);
+
+impl<W> PrcWaker<W>
+where
+    W: PrcWake + 'static,
+{
+    const VTABLE: RawWakerVTable =
+        RawWakerVTable::new(Self::clone, Self::wake, Self::wake_by_ref, Self::drop);
+
+    // Must be called with a pointer created by `local_waker::<W>`
+    unsafe fn clone(data: *const ()) -> RawWaker {
+        Rc::increment_strong_count(data as *const Prc<W>);
+        RawWaker::new(data, &Self::VTABLE)
+    }
+    // Must be called with a pointer created by `local_waker::<W>`
+    unsafe fn wake(data: *const ()) {
+        let rc = Rc::from_raw(data as *const Prc<W>);
+        W::wake(Rc::unwrap_or_clone(rc));
+    }
+    // Must be called with a pointer created by `local_waker::<W>`
+    unsafe fn wake_by_ref(data: *const ()) {
+        let prc = &*(data as *const Prc<W>);
+        W::wake_by_ref(prc);
+    }
+    // Must be called with a pointer created by `local_waker::<W>`
+    unsafe fn drop(data: *const ()) {
+        core::mem::drop(Rc::from_raw(data as *const Prc<W>));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    impl PrcWake for Cell<usize> {
+        fn wake_by_ref(this: &Prc<Self>) {
+            this.set(this.get() + 1);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn wake_routes_through_prc() {
+        let counter = Prc::new((0u8, Cell::new(0usize)));
+        let waker = local_waker(counter.project(|pair| &pair.1));
+        assert_eq!(Prc::strong_count(&counter), 2);
+
+        // Clones share the waker's allocation instead of cloning the Prc
+        let cloned = waker.clone();
+        assert_eq!(Prc::strong_count(&counter), 2);
+
+        waker.wake_by_ref();
+        // Waking a shared waker wakes a clone of its Prc
+        waker.wake();
+        assert_eq!(counter.1.get(), 2);
+        assert_eq!(Prc::strong_count(&counter), 2);
+
+        cloned.wake();
+        assert_eq!(counter.1.get(), 3);
+        assert_eq!(Prc::strong_count(&counter), 1);
+    }
+}
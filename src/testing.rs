@@ -0,0 +1,166 @@
+//! Property testing support for `Parc` and `Prc`.
+//!
+//! The generated pointers either own their value directly, or are projected into one
+//! of several values stored in a generated `Vec` root, so code under test also sees pointers
+//! that don't start at their root allocation and share it with other values.
+
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "sync")]
+use core::marker::{Send, Sync};
+
+use proptest::{
+    arbitrary::{any, any_with},
+    collection::vec,
+    prop_oneof,
+    sample::Index,
+    strategy::{BoxedStrategy, Strategy},
+};
+use quickcheck::{Arbitrary as _, Gen};
+
+use crate::prc::Prc;
+#[cfg(feature = "sync")]
+use crate::sync::Parc;
+
+// The maximum number of values in a generated root `Vec`
+const MAX_ROOT_LEN: usize = 4;
+
+#[cfg(feature = "sync")]
+impl<T> proptest::arbitrary::Arbitrary for Parc<T>
+where
+    T: proptest::arbitrary::Arbitrary + Send + Sync + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any_with::<T>(args.clone()).prop_map(Parc::new),
+            (vec(any_with::<T>(args), 1..=MAX_ROOT_LEN), any::<Index>()).prop_map(
+                |(values, index)| {
+                    let index = index.index(values.len());
+                    Parc::new(values).project(|values| &values[index])
+                }
+            ),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> proptest::arbitrary::Arbitrary for Prc<T>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any_with::<T>(args.clone()).prop_map(Prc::new),
+            (vec(any_with::<T>(args), 1..=MAX_ROOT_LEN), any::<Index>()).prop_map(
+                |(values, index)| {
+                    let index = index.index(values.len());
+                    Prc::new(values).project(|values| &values[index])
+                }
+            ),
+        ]
+        .boxed()
+    }
+}
+
+// Generates the root `Vec` and the index of the projected value for a projected pointer
+fn quickcheck_root<T: quickcheck::Arbitrary>(g: &mut Gen) -> (Vec<T>, usize) {
+    let mut values = Vec::<T>::arbitrary(g);
+    values.truncate(MAX_ROOT_LEN - 1);
+    values.push(T::arbitrary(g));
+    let index = usize::arbitrary(g) % values.len();
+    (values, index)
+}
+
+#[cfg(feature = "sync")]
+impl<T> quickcheck::Arbitrary for Parc<T>
+where
+    T: quickcheck::Arbitrary + Send + Sync,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Parc::new(T::arbitrary(g))
+        } else {
+            let (values, index) = quickcheck_root::<T>(g);
+            Parc::new(values).project(|values| &values[index])
+        }
+    }
+
+    /// Shrinks the projected value, returning `Parc`s that own the shrunk values.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(Parc::new))
+    }
+}
+
+impl<T> quickcheck::Arbitrary for Prc<T>
+where
+    T: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Prc::new(T::arbitrary(g))
+        } else {
+            let (values, index) = quickcheck_root::<T>(g);
+            Prc::new(values).project(|values| &values[index])
+        }
+    }
+
+    /// Shrinks the projected value, returning `Prc`s that own the shrunk values.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(Prc::new))
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use core::any::TypeId;
+    use proptest::{strategy::ValueTree, test_runner::TestRunner};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn proptest_generates_projections() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = any::<Parc<u8>>();
+        let (mut owned, mut projected) = (0, 0);
+        for _ in 0..64 {
+            let parc = strategy.new_tree(&mut runner).unwrap().current();
+            match Parc::root_type_id(&parc) {
+                Some(id) if id == TypeId::of::<u8>() => owned += 1,
+                Some(id) if id == TypeId::of::<Vec<u8>>() => projected += 1,
+                _ => unreachable!(),
+            }
+        }
+        assert!(owned > 0 && projected > 0);
+
+        let prc = any::<Prc<u8>>().new_tree(&mut runner).unwrap().current();
+        assert!(Prc::root_allocation_size(&prc) > 0);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn quickcheck_generates_projections() {
+        use quickcheck::Arbitrary;
+
+        let mut g = Gen::new(8);
+        let (mut owned, mut projected) = (0, 0);
+        for _ in 0..64 {
+            let prc = Prc::<u8>::arbitrary(&mut g);
+            match Prc::root_type_id(&prc) {
+                Some(id) if id == TypeId::of::<u8>() => owned += 1,
+                Some(id) if id == TypeId::of::<Vec<u8>>() => projected += 1,
+                _ => unreachable!(),
+            }
+        }
+        assert!(owned > 0 && projected > 0);
+
+        let parc = Parc::new(10u8).project(|x| x);
+        assert!(parc.shrink().all(|shrunk| *shrunk < 10));
+    }
+}
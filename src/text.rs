@@ -0,0 +1,270 @@
+//! Spans of shared source text.
+
+use core::ops::{Bound, Range, RangeBounds};
+
+use crate::sync::Parc;
+
+/// A byte range of a shared source text.
+///
+/// A `Span` keeps its whole source alive, which makes it a cheaply cloneable, `'static` token
+/// that lexers and parsers can hand out without copying or borrowing the source.
+///
+/// # Example
+/// ```
+/// use pared::{sync::Parc, text::Span};
+///
+/// let source: Parc<str> = Parc::from("let x =\n  42;");
+/// let file = Span::new(source);
+///
+/// let x = file.slice(4..5).unwrap();
+/// let value = file.slice(10..12).unwrap();
+/// assert_eq!(x.as_str(), "x");
+/// assert_eq!(value.line_column(), (2, 3));
+///
+/// let assignment = x.join(&value).unwrap();
+/// assert_eq!(assignment.as_str(), "x =\n  42");
+/// ```
+#[derive(Clone)]
+pub struct Span {
+    source: Parc<str>,
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    /// Creates a new `Span` covering the whole `source`.
+    #[inline]
+    pub fn new(source: Parc<str>) -> Self {
+        let end = source.len();
+        Self {
+            source,
+            start: 0,
+            end,
+        }
+    }
+
+    /// Creates a new `Span` covering `range` of `source`.
+    ///
+    /// Returns `None` if `range` is out of bounds or doesn't lie on `char` boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, text::Span};
+    ///
+    /// let source: Parc<str> = Parc::from("héllo");
+    /// assert_eq!(Span::from_range(source.clone(), 3..6).unwrap().as_str(), "llo");
+    /// assert!(Span::from_range(source, 2..6).is_none());
+    /// ```
+    #[inline]
+    pub fn from_range(source: Parc<str>, range: Range<usize>) -> Option<Self> {
+        Self::new(source).slice(range)
+    }
+
+    /// Returns the text covered by this span.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.source[self.start..self.end]
+    }
+
+    /// Returns the whole source text this span is a part of.
+    #[inline]
+    pub fn source(&self) -> &Parc<str> {
+        &self.source
+    }
+
+    /// Returns the byte range of this span in its source.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the length of this span in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if this span doesn't cover any text.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns a sub-span of this span; `range` is relative to the start of this span.
+    ///
+    /// Returns `None` if `range` is out of bounds or doesn't lie on `char` boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, text::Span};
+    ///
+    /// let words = Span::from_range(Parc::from("one two three"), 4..13).unwrap();
+    /// let three = words.slice(4..).unwrap();
+    /// assert_eq!(three.as_str(), "three");
+    /// assert_eq!(three.range(), 8..13);
+    /// ```
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        // `get` checks both the bounds and the char boundaries
+        self.as_str().get(start..end)?;
+        Some(Self {
+            source: self.source.clone(),
+            start: self.start + start,
+            end: self.start + end,
+        })
+    }
+
+    /// Returns the smallest span covering both `self` and `other`, including any text between them.
+    ///
+    /// Returns `None` if the spans come from different sources.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, text::Span};
+    ///
+    /// let source = Span::new(Parc::from("a + b"));
+    /// let a = source.slice(0..1).unwrap();
+    /// let b = source.slice(4..5).unwrap();
+    /// assert_eq!(b.join(&a).unwrap().as_str(), "a + b");
+    ///
+    /// let other = Span::new(Parc::from("a + b"));
+    /// assert!(a.join(&other).is_none());
+    /// ```
+    pub fn join(&self, other: &Span) -> Option<Self> {
+        if !Parc::ptr_eq(&self.source, &other.source) {
+            return None;
+        }
+        Some(Self {
+            source: self.source.clone(),
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Returns the 1-based line and column of the start of this span.
+    ///
+    /// Lines are separated by `\n`, and columns are counted in `char`s.
+    #[inline]
+    pub fn line_column(&self) -> (usize, usize) {
+        line_column(&self.source[..self.start])
+    }
+
+    /// Returns the 1-based line and column just past the end of this span.
+    ///
+    /// Lines are separated by `\n`, and columns are counted in `char`s.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, text::Span};
+    ///
+    /// let source = Span::new(Parc::from("fn main() {\n}"));
+    /// assert_eq!(source.line_column(), (1, 1));
+    /// assert_eq!(source.end_line_column(), (2, 2));
+    /// ```
+    #[inline]
+    pub fn end_line_column(&self) -> (usize, usize) {
+        line_column(&self.source[..self.end])
+    }
+
+    /// Returns a `Parc<str>` pointing to the text covered by this span.
+    #[inline]
+    pub fn to_parc(&self) -> Parc<str> {
+        self.source.project(|source| &source[self.start..self.end])
+    }
+}
+
+/// Returns the line and column just past the end of `preceding`.
+fn line_column(preceding: &str) -> (usize, usize) {
+    let line = preceding.matches('\n').count() + 1;
+    let line_start = preceding.rfind('\n').map_or(0, |newline| newline + 1);
+    let column = preceding[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+impl AsRef<str> for Span {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Parc<str>> for Span {
+    #[inline]
+    fn from(source: Parc<str>) -> Self {
+        Self::new(source)
+    }
+}
+
+impl core::fmt::Debug for Span {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Span")
+            .field("text", &self.as_str())
+            .field("range", &self.range())
+            .finish()
+    }
+}
+
+impl core::fmt::Display for Span {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn slicing() {
+        let span = Span::from(Parc::<str>::from("αβγ δ"));
+        assert_eq!(span.len(), 9);
+        assert_eq!(span.slice(..2).unwrap().as_str(), "α");
+        assert_eq!(span.slice(2..=3).unwrap().as_str(), "β");
+        assert_eq!(
+            span.slice((Bound::Excluded(5), Bound::Unbounded))
+                .unwrap()
+                .as_str(),
+            " δ"
+        );
+        assert!(span.slice(1..).is_none());
+        assert!(span.slice(..10).is_none());
+        assert!(span.slice(..=usize::MAX).is_none());
+
+        let empty = span.slice(9..).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.line_column(), (1, 6));
+        assert_eq!(format!("{}", span), "αβγ δ");
+        assert_eq!(
+            format!("{:?}", span.slice(..2).unwrap()),
+            "Span { text: \"α\", range: 0..2 }"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn lines_and_parcs() {
+        let source: Parc<str> = Parc::from("a\nbc\n\nd");
+        let span = Span::new(source.clone());
+        let d = span.slice(6..).unwrap();
+        assert_eq!(d.line_column(), (4, 1));
+        assert_eq!(d.end_line_column(), (4, 2));
+        assert_eq!(span.slice(3..4).unwrap().line_column(), (2, 2));
+
+        let parc = d.to_parc();
+        drop(span);
+        drop(d);
+        assert_eq!(&*parc, "d");
+        assert_eq!(Parc::strong_count(&source), 2);
+    }
+}
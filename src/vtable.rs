@@ -1,5 +1,7 @@
 //! A module containing the VTable for reference counted pointers.
 
+use core::{alloc::Layout, any::TypeId, marker::PhantomData};
+
 use crate::erased_ptr::TypeErasedPtr;
 
 /// A shared VTable for both atomic and non-atomic reference counted pointers.
@@ -10,17 +12,165 @@ use crate::erased_ptr::TypeErasedPtr;
 pub(crate) struct RcVTable {
     pub clone: unsafe fn(TypeErasedPtr),
     pub drop: unsafe fn(TypeErasedPtr),
+    #[cfg(feature = "weak")]
     pub downgrade: unsafe fn(TypeErasedPtr) -> TypeErasedPtr,
     pub strong_count: unsafe fn(TypeErasedPtr) -> usize,
     pub weak_count: unsafe fn(TypeErasedPtr) -> usize,
+    // Checks that there are no other strong or weak pointers with a single query
+    pub is_unique: unsafe fn(TypeErasedPtr) -> bool,
+    pub as_ptr: unsafe fn(TypeErasedPtr) -> *const (),
+    pub root_layout: unsafe fn(TypeErasedPtr) -> Layout,
+    // Only available for pointers to `'static` types
+    pub type_id: Option<fn() -> TypeId>,
+    // Returns the name of the root type, for diagnostics
+    #[cfg(feature = "type-names")]
+    pub type_name: fn() -> &'static str,
+    // Clones the root as an erased `Arc<dyn Any + Send + Sync>`;
+    // only available for atomic pointers to `Sized` types constructed with this capability
+    #[cfg_attr(not(feature = "sync"), allow(dead_code))]
+    pub any_root: Option<unsafe fn(TypeErasedPtr) -> TypeErasedPtr>,
 
+    #[cfg(feature = "weak")]
     pub clone_weak: unsafe fn(TypeErasedPtr),
+    #[cfg(feature = "weak")]
     pub drop_weak: unsafe fn(TypeErasedPtr),
+    #[cfg(feature = "weak")]
     pub upgrade_weak: unsafe fn(TypeErasedPtr) -> Option<TypeErasedPtr>,
+    #[cfg(feature = "weak")]
     pub strong_count_weak: unsafe fn(TypeErasedPtr) -> usize,
+    #[cfg(feature = "weak")]
     pub weak_count_weak: unsafe fn(TypeErasedPtr) -> usize,
 }
 
+/// Returns the layout of the allocation of an `Arc` or `Rc` storing a value with `value_layout`.
+///
+/// Both `Arc` and `Rc` store their strong and weak counts in two `usize`s in front of the value.
+/// Their layout isn't guaranteed by the standard library, so the result is an estimate
+/// that matches its current implementation.
+pub(crate) fn rc_allocation_layout(value_layout: Layout) -> Layout {
+    let counts = Layout::new::<[usize; 2]>();
+    match counts.extend(value_layout) {
+        Ok((layout, _)) => layout.pad_to_align(),
+        // The value already exists, so its allocation can't have overflowed
+        Err(_) => unreachable!(),
+    }
+}
+
+#[cfg(feature = "weak")]
+pub(crate) struct DanglingErased;
+
+#[cfg(feature = "weak")]
+impl DanglingErased {
+    // A "vtable" for weak pointers without an allocation; no strong pointers can exist for it.
+    // It never touches memory, so it's shared by atomic and non-atomic pointers
+    pub(crate) const VTABLE: RcVTable = RcVTable {
+        clone: Self::noop,
+        drop: Self::noop,
+        #[cfg(feature = "weak")]
+        downgrade: Self::same,
+        strong_count: Self::zero,
+        weak_count: Self::zero,
+        is_unique: Self::never,
+        as_ptr: Self::as_ptr,
+        root_layout: Self::root_layout,
+        type_id: None,
+        #[cfg(feature = "type-names")]
+        type_name: core::any::type_name::<()>,
+        any_root: None,
+        #[cfg(feature = "weak")]
+        clone_weak: Self::noop,
+        #[cfg(feature = "weak")]
+        drop_weak: Self::noop,
+        #[cfg(feature = "weak")]
+        upgrade_weak: Self::upgrade_weak,
+        #[cfg(feature = "weak")]
+        strong_count_weak: Self::zero,
+        #[cfg(feature = "weak")]
+        weak_count_weak: Self::zero,
+    };
+
+    unsafe fn noop(_: TypeErasedPtr) {}
+    unsafe fn same(ptr: TypeErasedPtr) -> TypeErasedPtr {
+        ptr
+    }
+    unsafe fn zero(_: TypeErasedPtr) -> usize {
+        0
+    }
+    unsafe fn never(_: TypeErasedPtr) -> bool {
+        false
+    }
+    // No allocation ever lives at the null address
+    unsafe fn as_ptr(_: TypeErasedPtr) -> *const () {
+        core::ptr::null()
+    }
+    unsafe fn root_layout(_: TypeErasedPtr) -> Layout {
+        Layout::new::<()>()
+    }
+    unsafe fn upgrade_weak(_: TypeErasedPtr) -> Option<TypeErasedPtr> {
+        None
+    }
+}
+
+pub(crate) struct StaticErased<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: ?Sized> StaticErased<T> {
+    // A "vtable" for &'static T, which is never freed, used for both strong and weak pointers.
+    // It never touches memory, so it's shared by atomic and non-atomic pointers
+    pub(crate) const VTABLE: RcVTable = RcVTable {
+        clone: Self::noop,
+        drop: Self::noop,
+        #[cfg(feature = "weak")]
+        downgrade: Self::same,
+        strong_count: Self::strong_count,
+        weak_count: Self::weak_count,
+        is_unique: Self::is_unique,
+        as_ptr: Self::as_ptr,
+        root_layout: Self::root_layout,
+        type_id: None,
+        #[cfg(feature = "type-names")]
+        type_name: core::any::type_name::<T>,
+        any_root: None,
+        #[cfg(feature = "weak")]
+        clone_weak: Self::noop,
+        #[cfg(feature = "weak")]
+        drop_weak: Self::noop,
+        #[cfg(feature = "weak")]
+        upgrade_weak: Self::upgrade_weak,
+        #[cfg(feature = "weak")]
+        strong_count_weak: Self::strong_count,
+        #[cfg(feature = "weak")]
+        weak_count_weak: Self::weak_count,
+    };
+
+    unsafe fn noop(_: TypeErasedPtr) {}
+    #[cfg(feature = "weak")]
+    unsafe fn same(ptr: TypeErasedPtr) -> TypeErasedPtr {
+        ptr
+    }
+    // Static data is never freed, so it can't be uniquely owned either
+    unsafe fn strong_count(_: TypeErasedPtr) -> usize {
+        usize::MAX
+    }
+    unsafe fn weak_count(_: TypeErasedPtr) -> usize {
+        0
+    }
+    unsafe fn is_unique(_: TypeErasedPtr) -> bool {
+        false
+    }
+    // Must be called with an erased pointer to &'static T
+    unsafe fn as_ptr(ptr: TypeErasedPtr) -> *const () {
+        ptr.as_ptr::<T>() as *const ()
+    }
+    // Nothing is allocated for static data
+    unsafe fn root_layout(_: TypeErasedPtr) -> Layout {
+        Layout::new::<()>()
+    }
+    #[cfg(feature = "weak")]
+    unsafe fn upgrade_weak(ptr: TypeErasedPtr) -> Option<TypeErasedPtr> {
+        Some(ptr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,6 +180,7 @@ mod tests {
     fn debug() {
         #[cfg_attr(coverage_nightly, coverage(off))]
         fn a(_: TypeErasedPtr) {}
+        #[cfg(feature = "weak")]
         #[cfg_attr(coverage_nightly, coverage(off))]
         fn b(ptr: TypeErasedPtr) -> TypeErasedPtr {
             ptr
@@ -38,23 +189,66 @@ mod tests {
         fn c(_: TypeErasedPtr) -> usize {
             0
         }
+        #[cfg(feature = "weak")]
         #[cfg_attr(coverage_nightly, coverage(off))]
         fn d(_: TypeErasedPtr) -> Option<TypeErasedPtr> {
             None
         }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn g(_: TypeErasedPtr) -> bool {
+            false
+        }
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn e(_: TypeErasedPtr) -> *const () {
+            core::ptr::null()
+        }
+
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        fn f(_: TypeErasedPtr) -> Layout {
+            Layout::new::<()>()
+        }
 
         let vtable = RcVTable {
             clone: a,
             drop: a,
+            #[cfg(feature = "weak")]
             downgrade: b,
             strong_count: c,
             weak_count: c,
+            is_unique: g,
+            as_ptr: e,
+            root_layout: f,
+            type_id: None,
+            #[cfg(feature = "type-names")]
+            type_name: core::any::type_name::<()>,
+            any_root: None,
+            #[cfg(feature = "weak")]
             clone_weak: a,
+            #[cfg(feature = "weak")]
             drop_weak: a,
+            #[cfg(feature = "weak")]
             upgrade_weak: d,
+            #[cfg(feature = "weak")]
             strong_count_weak: c,
+            #[cfg(feature = "weak")]
             weak_count_weak: c,
         };
-        format!("{:?}", vtable);
+        let _ = format!("{:?}", vtable);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn allocation_layout() {
+        let word = core::mem::size_of::<usize>();
+        let layout = rc_allocation_layout(Layout::new::<u8>());
+        assert_eq!(layout.size(), 3 * word);
+        assert_eq!(layout.align(), word);
+
+        let layout = rc_allocation_layout(Layout::new::<u128>());
+        assert_eq!(layout.size() % 16, 0);
+        assert!(layout.size() >= 2 * word + 16);
+
+        let layout = rc_allocation_layout(Layout::for_value::<[u8]>(&[0; 5]));
+        assert_eq!(layout.size(), 3 * word);
     }
 }
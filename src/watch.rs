@@ -0,0 +1,504 @@
+//! A cell publishing new roots to subscribers.
+//!
+//! [`WatchParc`] holds the latest published [`Parc`]. Readers either [`load`] it directly,
+//! or [`subscribe`] to get a [`Watcher`] that gets notified whenever a new `Parc` is published,
+//! e.g. to re-project into a freshly loaded configuration.
+//!
+//! # Example
+//! ```
+//! use pared::{sync::Parc, watch::WatchParc};
+//!
+//! struct Config {
+//!     name: String,
+//! }
+//!
+//! let config = WatchParc::new(Parc::new(Config { name: "first".to_owned() }));
+//! let mut watcher = config.subscribe();
+//!
+//! let worker = std::thread::spawn(move || {
+//!     let mut names = Vec::new();
+//!     // Re-project every time a new configuration is published
+//!     while let Some(config) = watcher.changed() {
+//!         let name = config.project(|c| c.name.as_str());
+//!         names.push(name);
+//!     }
+//!     names
+//! });
+//!
+//! config.publish(Parc::new(Config { name: "second".to_owned() }));
+//! // Dropping the last `WatchParc` stops the watchers
+//! drop(config);
+//!
+//! let names = worker.join().unwrap();
+//! assert_eq!(names.last().map(|name| &**name), Some("second"));
+//! ```
+//!
+//! [`VersionedParc`] instead keeps a bounded history of published `Parc`s, so readers can look
+//! up the snapshot they started from by its version.
+//!
+//! [`load`]: WatchParc::load
+//! [`subscribe`]: WatchParc::subscribe
+
+use alloc::{collections::VecDeque, sync::Arc};
+use core::time::Duration;
+use std::sync::{Condvar, Mutex};
+
+use crate::{
+    poison::{lock_unpoisoned, unpoisoned},
+    sync::Parc,
+};
+
+/// A shared cell holding the latest published [`Parc`], notifying [`Watcher`]s of new values.
+///
+/// Cloning a `WatchParc` creates another handle to the same cell. Once all handles are dropped,
+/// no new values can be published, and waiting watchers are woken up.
+///
+/// See the [module documentation](crate::watch) for more details.
+pub struct WatchParc<T: ?Sized> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A subscription to a [`WatchParc`], created with [`WatchParc::subscribe`].
+///
+/// Each watcher keeps track of the latest version it has seen, so it's only notified
+/// about values published after that.
+pub struct Watcher<T: ?Sized> {
+    shared: Arc<Shared<T>>,
+    seen: u64,
+}
+
+struct Shared<T: ?Sized> {
+    state: Mutex<State<T>>,
+    changed: Condvar,
+}
+
+struct State<T: ?Sized> {
+    current: Parc<T>,
+    version: u64,
+    publishers: usize,
+}
+
+impl<T: ?Sized> State<T> {
+    /// Returns the current value if its version is newer than `seen`, updating `seen`.
+    #[inline]
+    fn take_unseen(&self, seen: &mut u64) -> Option<Parc<T>> {
+        if self.version == *seen {
+            return None;
+        }
+        *seen = self.version;
+        Some(self.current.clone())
+    }
+}
+
+impl<T: ?Sized> WatchParc<T> {
+    /// Creates a new `WatchParc` holding `initial`.
+    #[inline]
+    pub fn new(initial: Parc<T>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    current: initial,
+                    version: 0,
+                    publishers: 1,
+                }),
+                changed: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Returns the latest published `Parc`.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, watch::WatchParc};
+    ///
+    /// let watch = WatchParc::new(Parc::new(1));
+    /// assert_eq!(*watch.load(), 1);
+    /// ```
+    #[inline]
+    pub fn load(&self) -> Parc<T> {
+        lock_unpoisoned(&self.shared.state).current.clone()
+    }
+
+    /// Publishes `value`, waking up all watchers, and returns the previously published `Parc`.
+    ///
+    /// The previous `Parc` is returned rather than dropped, so that its root can be dropped
+    /// outside of the cell's lock (or elsewhere entirely).
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, watch::WatchParc};
+    ///
+    /// let watch = WatchParc::new(Parc::new(1));
+    /// let previous = watch.publish(Parc::new(2));
+    /// assert_eq!((*previous, *watch.load()), (1, 2));
+    /// ```
+    pub fn publish(&self, value: Parc<T>) -> Parc<T> {
+        let previous = {
+            let mut state = lock_unpoisoned(&self.shared.state);
+            state.version += 1;
+            core::mem::replace(&mut state.current, value)
+        };
+        self.shared.changed.notify_all();
+        previous
+    }
+
+    /// Creates a new [`Watcher`] that gets notified about values published after this call.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, watch::WatchParc};
+    ///
+    /// let watch = WatchParc::new(Parc::new(1));
+    /// let watcher = watch.subscribe();
+    /// assert!(!watcher.has_changed());
+    ///
+    /// watch.publish(Parc::new(2));
+    /// assert!(watcher.has_changed());
+    /// ```
+    #[inline]
+    pub fn subscribe(&self) -> Watcher<T> {
+        let seen = lock_unpoisoned(&self.shared.state).version;
+        Watcher {
+            shared: self.shared.clone(),
+            seen,
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for WatchParc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        lock_unpoisoned(&self.shared.state).publishers += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for WatchParc<T> {
+    fn drop(&mut self) {
+        let closed = {
+            let mut state = lock_unpoisoned(&self.shared.state);
+            state.publishers -= 1;
+            state.publishers == 0
+        };
+        if closed {
+            self.shared.changed.notify_all();
+        }
+    }
+}
+
+impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for WatchParc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = lock_unpoisoned(&self.shared.state);
+        f.debug_struct("WatchParc")
+            .field("current", &state.current)
+            .field("version", &state.version)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Watcher<T> {
+    /// Returns `true` if a value was published since this watcher last saw one.
+    #[inline]
+    pub fn has_changed(&self) -> bool {
+        lock_unpoisoned(&self.shared.state).version != self.seen
+    }
+
+    /// Returns the latest published `Parc`, marking it as seen.
+    ///
+    /// # Example
+    /// ```
+    /// use pared::{sync::Parc, watch::WatchParc};
+    ///
+    /// let watch = WatchParc::new(Parc::new(1));
+    /// let mut watcher = watch.subscribe();
+    /// watch.publish(Parc::new(2));
+    ///
+    /// assert_eq!(*watcher.latest(), 2);
+    /// assert!(!watcher.has_changed());
+    /// ```
+    #[inline]
+    pub fn latest(&mut self) -> Parc<T> {
+        let state = lock_unpoisoned(&self.shared.state);
+        self.seen = state.version;
+        state.current.clone()
+    }
+
+    /// Blocks until a value this watcher hasn't seen yet is published, and returns it.
+    ///
+    /// Returns right away if such a value was already published. Returns `None` once all
+    /// [`WatchParc`] handles are dropped and this watcher has seen the last published value.
+    pub fn changed(&mut self) -> Option<Parc<T>> {
+        let seen = self.seen;
+        let state = unpoisoned(
+            self.shared
+                .changed
+                .wait_while(lock_unpoisoned(&self.shared.state), |state| {
+                    state.version == seen && state.publishers > 0
+                }),
+        );
+        state.take_unseen(&mut self.seen)
+    }
+
+    /// Like [`Watcher::changed`], but gives up and returns `None` after `timeout`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use pared::{sync::Parc, watch::WatchParc};
+    ///
+    /// let watch = WatchParc::new(Parc::new(1));
+    /// let mut watcher = watch.subscribe();
+    /// assert!(watcher.changed_timeout(Duration::from_millis(1)).is_none());
+    ///
+    /// watch.publish(Parc::new(2));
+    /// assert_eq!(watcher.changed_timeout(Duration::from_millis(1)).as_deref(), Some(&2));
+    /// ```
+    pub fn changed_timeout(&mut self, timeout: Duration) -> Option<Parc<T>> {
+        let seen = self.seen;
+        let (state, _) = unpoisoned(self.shared.changed.wait_timeout_while(
+            lock_unpoisoned(&self.shared.state),
+            timeout,
+            |state| state.version == seen && state.publishers > 0,
+        ));
+        state.take_unseen(&mut self.seen)
+    }
+}
+
+impl<T: ?Sized> Clone for Watcher<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            seen: self.seen,
+        }
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for Watcher<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("seen", &self.seen)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A shared cell holding the latest published [`Parc`] along with a bounded history
+/// of previously published ones.
+///
+/// Every published `Parc` is assigned a version, starting at `0` for the initial value and
+/// increasing by one with each [`publish`](VersionedParc::publish). The cell keeps up to
+/// `capacity` of the most recent snapshots, so long-running readers can check whether their
+/// snapshot is stale with [`changed_since`](VersionedParc::changed_since), and reload the exact
+/// snapshot they started from with [`load_at`](VersionedParc::load_at) as long as it's
+/// still retained.
+///
+/// # Example
+/// ```
+/// use pared::{sync::Parc, watch::VersionedParc};
+///
+/// let schema = VersionedParc::new(Parc::<str>::from("v0"), 2);
+/// let (version, snapshot) = schema.load_versioned();
+///
+/// schema.publish(Parc::from("v1"));
+/// assert!(schema.changed_since(version));
+/// assert_eq!(schema.load_at(version).as_deref(), Some(&*snapshot));
+///
+/// // Only the two latest snapshots are retained
+/// schema.publish(Parc::from("v2"));
+/// assert!(schema.load_at(version).is_none());
+/// assert_eq!(schema.oldest_version(), 1);
+/// ```
+pub struct VersionedParc<T: ?Sized> {
+    history: Mutex<History<T>>,
+}
+
+struct History<T: ?Sized> {
+    snapshots: VecDeque<Parc<T>>,
+    // The version of the last snapshot
+    version: u64,
+    capacity: usize,
+}
+
+impl<T: ?Sized> History<T> {
+    #[inline]
+    fn oldest_version(&self) -> u64 {
+        self.version - (self.snapshots.len() as u64 - 1)
+    }
+
+    #[inline]
+    fn current(&self) -> &Parc<T> {
+        // There's always at least one snapshot
+        &self.snapshots[self.snapshots.len() - 1]
+    }
+}
+
+impl<T: ?Sized> VersionedParc<T> {
+    /// Creates a new `VersionedParc` holding `initial` as version `0`,
+    /// retaining up to `capacity` snapshots.
+    ///
+    /// The latest snapshot is always retained, so a `capacity` of `0` behaves like `1`.
+    #[inline]
+    pub fn new(initial: Parc<T>, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let mut snapshots = VecDeque::with_capacity(capacity);
+        snapshots.push_back(initial);
+        Self {
+            history: Mutex::new(History {
+                snapshots,
+                version: 0,
+                capacity,
+            }),
+        }
+    }
+
+    /// Returns the latest published `Parc`.
+    #[inline]
+    pub fn load(&self) -> Parc<T> {
+        lock_unpoisoned(&self.history).current().clone()
+    }
+
+    /// Returns the latest published `Parc` along with its version.
+    #[inline]
+    pub fn load_versioned(&self) -> (u64, Parc<T>) {
+        let history = lock_unpoisoned(&self.history);
+        (history.version, history.current().clone())
+    }
+
+    /// Returns the `Parc` published as `version`, or `None` if it's no longer retained
+    /// or hasn't been published yet.
+    #[inline]
+    pub fn load_at(&self, version: u64) -> Option<Parc<T>> {
+        let history = lock_unpoisoned(&self.history);
+        let index = version.checked_sub(history.oldest_version())?;
+        history.snapshots.get(usize::try_from(index).ok()?).cloned()
+    }
+
+    /// Returns the version of the latest published `Parc`.
+    #[inline]
+    pub fn version(&self) -> u64 {
+        lock_unpoisoned(&self.history).version
+    }
+
+    /// Returns the version of the oldest retained `Parc`.
+    #[inline]
+    pub fn oldest_version(&self) -> u64 {
+        lock_unpoisoned(&self.history).oldest_version()
+    }
+
+    /// Returns `true` if a newer `Parc` was published after `version`.
+    #[inline]
+    pub fn changed_since(&self, version: u64) -> bool {
+        lock_unpoisoned(&self.history).version > version
+    }
+
+    /// Publishes `value` as the next version and returns that version.
+    ///
+    /// If the history is full, the oldest snapshot is evicted; it's dropped outside
+    /// of the cell's lock.
+    pub fn publish(&self, value: Parc<T>) -> u64 {
+        let (version, _evicted) = {
+            let mut history = lock_unpoisoned(&self.history);
+            let evicted = if history.snapshots.len() == history.capacity {
+                history.snapshots.pop_front()
+            } else {
+                None
+            };
+            history.snapshots.push_back(value);
+            history.version += 1;
+            (history.version, evicted)
+        };
+        version
+    }
+}
+
+impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for VersionedParc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let history = lock_unpoisoned(&self.history);
+        f.debug_struct("VersionedParc")
+            .field("current", history.current())
+            .field("version", &history.version)
+            .field("retained", &history.snapshots.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn watchers_see_new_roots() {
+        let watch = WatchParc::new(Parc::new((1, String::from("one"))).project(|x| x.1.as_str()));
+        let mut watcher = watch.subscribe();
+        let mut late = watcher.clone();
+        assert!(!watcher.has_changed());
+
+        let previous = watch.publish(Parc::from("two"));
+        assert_eq!(&*previous, "one");
+        assert_eq!(&*watcher.changed().unwrap(), "two");
+        assert!(watcher.changed_timeout(Duration::from_millis(1)).is_none());
+
+        watch.publish(Parc::from("three"));
+        assert_eq!(&*late.latest(), "three");
+        assert!(!late.has_changed());
+        assert_eq!(
+            format!("{:?}", watch),
+            "WatchParc { current: Parc { projected: \"three\" }, version: 2 }"
+        );
+        let _ = format!("{:?}", watcher);
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn closes_when_publishers_drop() {
+        let watch = WatchParc::new(Parc::new(0));
+        let publisher = watch.clone();
+        let mut watcher = watch.subscribe();
+        drop(watch);
+
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                publisher.publish(Parc::new(1));
+            });
+            let mut seen = 0;
+            while let Some(value) = watcher.changed() {
+                seen = *value;
+            }
+            assert_eq!(seen, 1);
+        });
+        assert!(watcher.changed().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn versioned_history() {
+        let cell = VersionedParc::new(Parc::new((0, String::from("zero"))).project(|x| &x.0), 3);
+        assert_eq!(cell.load_versioned().0, 0);
+        assert!(!cell.changed_since(0));
+
+        for i in 1..=4 {
+            assert_eq!(cell.publish(Parc::new(i)), i as u64);
+        }
+        assert!(cell.changed_since(3));
+        assert_eq!(cell.version(), 4);
+        assert_eq!(cell.oldest_version(), 2);
+        assert_eq!(*cell.load(), 4);
+        assert!(cell.load_at(1).is_none());
+        assert_eq!(cell.load_at(2).as_deref(), Some(&2));
+        assert!(cell.load_at(5).is_none());
+        assert_eq!(
+            format!("{:?}", cell),
+            "VersionedParc { current: Parc { projected: 4 }, version: 4, retained: 3 }"
+        );
+
+        let single = VersionedParc::new(Parc::new(0), 0);
+        single.publish(Parc::new(1));
+        assert_eq!(single.oldest_version(), 1);
+        assert!(single.load_at(0).is_none());
+    }
+}
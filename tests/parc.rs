@@ -1,12 +1,19 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
-
-use pared::sync::{Parc, Weak};
+#![cfg(feature = "sync")]
+
+#[cfg(feature = "weak")]
+use pared::sync::Weak;
+use pared::sync::{Parc, ParcRef};
+#[cfg(feature = "weak")]
+use pared::Counts;
+#[cfg(feature = "weak")]
 use std::any::Any;
 use std::cmp::PartialEq;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn slice() {
     let a: Parc<[u32; 3]> = Arc::new([3, 2, 1]).into();
@@ -20,6 +27,7 @@ fn slice() {
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn trait_object() {
     let a: Parc<u32> = Parc::new(4);
@@ -60,9 +68,11 @@ fn partial_eq() {
     assert_eq!(*x.0.lock().unwrap(), 4);
 }
 
+#[cfg(feature = "weak")]
 const SHARED_ITER_MAX: u16 = 100;
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn shared_from_iter_normal() {
     #![allow(clippy::redundant_clone)]
@@ -156,6 +166,29 @@ fn fallible_projections() {
 
 #[test]
 #[cfg_attr(coverage_nightly, coverage(off))]
+fn capturing_projections() {
+    let parc = Parc::new(vec![
+        String::from("a"),
+        String::from("bc"),
+        String::from("def"),
+    ]);
+    let index = parc.iter().position(|s| s.len() == 2).unwrap();
+    let projected = parc.project(|v| &v[index]);
+    assert_eq!(&*projected, "bc");
+
+    let name = String::from("def");
+    let found = parc.try_project(|v| v.iter().find(|s| **s == name).ok_or(()));
+    assert!(matches!(found, Ok(p) if *p == name));
+
+    let len = 3;
+    let arc = Arc::new(vec![1u8, 2, 3, 4]);
+    let prefix = Parc::try_from_arc(&arc, |v| v.get(..len).ok_or(()));
+    assert!(matches!(prefix, Ok(p) if *p == [1, 2, 3]));
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
 fn as_ptr() {
     #[repr(C)]
     struct Test {
@@ -166,11 +199,22 @@ fn as_ptr() {
     let parc = Parc::from_arc(&rc, |x| &x.a);
     let weak = Parc::downgrade(&parc);
 
-    assert!(Parc::as_ptr(&parc) == &rc.a as *const i32);
-    assert!(Weak::as_ptr(&weak) == &rc.a as *const i32);
+    assert!(core::ptr::eq(Parc::as_ptr(&parc), &rc.a));
+    assert!(core::ptr::eq(Weak::as_ptr(&weak), &rc.a));
+
+    let addr = &rc.a as *const i32 as usize;
+    assert_eq!(Parc::addr(&parc), addr);
+    assert_eq!(weak.addr(), addr);
+    let moved = Box::new(parc.clone());
+    assert_eq!(Parc::addr(&moved), addr);
+    drop(moved);
+    drop(parc);
+    drop(rc);
+    assert_eq!(weak.addr(), addr);
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn counts() {
     let parc = Parc::new(5);
@@ -179,13 +223,26 @@ fn counts() {
     assert_eq!(Parc::weak_count(&parc), 0);
     assert_eq!(Parc::strong_count(&parc), 2);
     assert_eq!(Parc::strong_count(&parc2), 2);
+    assert!(!Parc::is_unique(&parc));
 
     let weak = Parc::downgrade(&parc);
     assert_eq!(Weak::weak_count(&weak), 1);
     assert_eq!(Weak::strong_count(&weak), 2);
+
+    assert_eq!(Parc::counts(&parc), Counts { strong: 2, weak: 1 });
+    assert_eq!(weak.counts(), Counts { strong: 2, weak: 1 });
+    drop(parc);
+    assert!(!Parc::is_unique(&parc2));
+    drop(weak);
+    assert!(Parc::is_unique(&parc2));
+
+    let weak = Parc::downgrade(&parc2);
+    drop(parc2);
+    assert_eq!(weak.counts(), Counts { strong: 0, weak: 0 });
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn ptr_eq() {
     let parc = Parc::new(5);
@@ -203,6 +260,46 @@ fn ptr_eq() {
     assert!(!Weak::ptr_eq(&weak, &weak2));
 }
 
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn weak_same_root() {
+    let tuple = Parc::new((5, String::from("five")));
+    let weak = Parc::downgrade(&tuple.project(|x| &x.0));
+    let weak_str = Parc::downgrade(&tuple.project(|x| x.1.as_str()));
+    let other = Parc::new(5);
+    let weak_other = Parc::downgrade(&other);
+
+    assert!(weak.same_root(&weak_str));
+    assert!(!weak.same_root(&weak_other));
+    assert!(weak_str.same_root_as(&tuple));
+    assert!(!weak_str.same_root_as(&other));
+
+    // Identity is preserved after the allocation is gone
+    drop(tuple);
+    assert!(weak.upgrade().is_none());
+    assert!(weak.same_root(&weak_str));
+    assert!(!weak.same_root(&weak_other));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn same_root() {
+    let arc = Arc::new((5, String::from("five")));
+    let parc = Parc::from_arc(&arc, |x| &x.0);
+    let parc_str = Parc::from_arc(&arc, |x| x.1.as_str());
+    let parc2 = Parc::new((5, String::from("five")));
+
+    assert!(Parc::same_root(&parc, &parc_str));
+    assert!(!Parc::ptr_eq(&parc, &parc.project(|_| &5)));
+    assert!(Parc::same_root(&parc, &parc.project(|_| &5)));
+    assert!(!Parc::same_root(&parc, &parc2));
+
+    assert!(Parc::is_rooted_in(&parc, &arc));
+    assert!(Parc::is_rooted_in(&parc_str, &arc));
+    assert!(!Parc::is_rooted_in(&parc2, &arc));
+}
+
 #[test]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn borrows() {
@@ -213,15 +310,16 @@ fn borrows() {
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn fmt() {
     let parc = Parc::new(5);
 
-    format!("{} {:?} {:p}", parc, parc, parc);
+    let _ = format!("{} {:?} {:p}", parc, parc, parc);
 
     let weak = Parc::downgrade(&parc);
 
-    format!("{:?}", weak);
+    let _ = format!("{:?}", weak);
 }
 
 #[test]
@@ -254,3 +352,644 @@ fn cmp() {
     assert_eq!(five.cmp(&six), std::cmp::Ordering::Less);
     assert_eq!(five.partial_cmp(&six), Some(std::cmp::Ordering::Less));
 }
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn borrowed() {
+    let parc = Parc::new((5, String::from("five"))).project(|x| &x.1);
+
+    assert_eq!(Parc::with(&parc, |s| s.len()), 4);
+    assert_eq!(Parc::strong_count(&parc), 1);
+
+    let borrowed: ParcRef<'_, String> = Parc::borrow_parc(&parc);
+    let copied = borrowed;
+    assert_eq!(borrowed.get(), "five");
+    assert_eq!(copied.as_ref(), "five");
+    assert_eq!(Parc::strong_count(&parc), 1);
+    let _ = format!("{} {:?} {:?}", borrowed, borrowed, borrowed.clone());
+
+    let owned = copied.clone_parc();
+    assert!(Parc::ptr_eq(&owned, &parc));
+    assert_eq!(Parc::strong_count(&parc), 2);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn raw() {
+    let arc = Arc::new((5, String::from("five")));
+    let parc = Parc::from_arc(&arc, |x| x.1.as_str());
+    let raw = Parc::into_raw(parc);
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert_eq!(unsafe { &*raw.as_ptr() }, "five");
+    let _ = format!("{:?}", raw);
+
+    unsafe {
+        Parc::increment_strong_count(raw);
+        Parc::increment_strong_count(raw);
+    }
+    assert_eq!(Arc::strong_count(&arc), 4);
+    unsafe { Parc::decrement_strong_count(raw) };
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    let first = unsafe { Parc::from_raw(raw) };
+    let second = unsafe { Parc::from_raw(raw) };
+    assert_eq!(&*first, "five");
+    assert!(Parc::ptr_eq(&first, &second));
+    drop(first);
+    drop(second);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn transpose_option() {
+    let arc = Arc::new((Some(String::from("some")), None::<String>));
+    let some = Parc::from_arc(&arc, |x| &x.0);
+    let none = Parc::from_arc(&arc, |x| &x.1);
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    assert!(none.transpose().is_none());
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let inner = some.transpose().unwrap();
+    assert_eq!(&*inner, "some");
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert!(core::ptr::eq(&*inner, arc.0.as_ref().unwrap()));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn transpose_result() {
+    type Shared = Result<String, u32>;
+    let arc: Arc<(Shared, Shared)> = Arc::new((Ok(String::from("ok")), Err(5)));
+    let ok = Parc::from_arc(&arc, |x| &x.0).transpose().unwrap();
+    let err = Parc::from_arc(&arc, |x| &x.1).transpose().unwrap_err();
+    assert_eq!(&*ok, "ok");
+    assert_eq!(*err, 5);
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    let shared = Parc::from_arc(&arc, |x| &x.1);
+    assert_eq!(*shared.split_result().unwrap_err(), 5);
+    assert_eq!(Parc::strong_count(&shared), 4);
+    assert!(Parc::same_root(&ok, &err));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn slice_and_str_conversions() {
+    let vec = Parc::new(vec![1, 2, 3]);
+    let slice = vec.as_slice_parc();
+    assert_eq!(&*slice, &[1, 2, 3]);
+    assert_eq!(Parc::strong_count(&vec), 2);
+    let converted: Parc<[i32]> = vec.into();
+    assert!(Parc::ptr_eq(&slice, &converted));
+    assert_eq!(Parc::strong_count(&slice), 2);
+
+    let string = Parc::new(String::from("pared"));
+    let str = string.as_str_parc();
+    let converted = Parc::<str>::from(string);
+    assert_eq!(&*str, "pared");
+    assert!(Parc::ptr_eq(&str, &converted));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn copy_out() {
+    let root = Parc::new((3u16, String::from("three")));
+    let id = root.project(|root| &root.0);
+    let name = root.project(|root| &root.1);
+
+    assert_eq!(Parc::get(&id), 3);
+    assert_eq!([id.clone(), id].iter().map(Parc::copied).sum::<u16>(), 6);
+    let owned: String = Parc::cloned(&name);
+    assert_eq!(owned, "three");
+    assert_eq!(Parc::strong_count(&root), 2);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn project_get() {
+    use std::collections::BTreeMap;
+
+    #[cfg(feature = "std")]
+    {
+        use std::collections::HashMap;
+
+        let hash_map = Parc::new(HashMap::from([(String::from("a"), vec![1])]));
+        let a = hash_map.project_get("a").unwrap();
+        assert_eq!(&*a, &[1]);
+        assert!(hash_map.project_get("b").is_none());
+        assert_eq!(Parc::strong_count(&hash_map), 2);
+    }
+
+    let btree_map = Parc::new(BTreeMap::from([(String::from("a"), vec![1])]));
+    let a = btree_map.project_get("a").unwrap();
+    assert!(core::ptr::eq(&*a, &btree_map["a"]));
+    assert!(btree_map.project_get("b").is_none());
+    assert_eq!(Parc::strong_count(&btree_map), 2);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn project_range() {
+    use core::ops::Bound;
+    use std::collections::BTreeMap;
+
+    let map = Parc::new(BTreeMap::from([
+        (String::from("a"), 1),
+        (String::from("b"), 2),
+        (String::from("c"), 3),
+    ]));
+    let mut range = map.project_range::<str, _>((Bound::Included("a"), Bound::Excluded("c")));
+    let (b_key, b_value) = range.next_back().unwrap();
+    let (a_key, a_value) = range.next().unwrap();
+    assert!(range.next().is_none());
+    drop(range);
+
+    assert_eq!((a_key.as_str(), *a_value), ("a", 1));
+    assert_eq!((b_key.as_str(), *b_value), ("b", 2));
+    assert_eq!(Parc::strong_count(&map), 5);
+    drop(map);
+    assert_eq!(Parc::strong_count(&b_value), 4);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn project_iter() {
+    let names = Parc::new([String::from("a"), String::from("b"), String::from("c")]);
+    let [a, b, c] = names.clone().project_iter();
+    assert_eq!((a.as_str(), b.as_str(), c.as_str()), ("a", "b", "c"));
+    assert!(Parc::same_root(&names, &c));
+    assert_eq!(Parc::strong_count(&names), 4);
+
+    let empty: [Parc<u8>; 0] = Parc::new([]).project_iter();
+    assert!(empty.is_empty());
+    drop((a, b, c));
+    assert_eq!(Parc::strong_count(&names), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn array_conversions() {
+    let array = Parc::new([1, 2, 3]);
+    let slice = Parc::<[i32]>::from(array.clone());
+    assert_eq!(&*slice, &[1, 2, 3]);
+    assert_eq!(
+        Parc::as_ptr(&slice) as *const i32,
+        Parc::as_ptr(&array) as *const i32
+    );
+
+    let wrong = Parc::<[i32; 2]>::try_from(slice.clone()).unwrap_err();
+    assert!(Parc::ptr_eq(&wrong, &slice));
+    let tail = Parc::<[i32; 2]>::try_from(slice.project(|s| &s[1..])).unwrap();
+    assert_eq!(*tail, [2, 3]);
+    assert_eq!(Parc::strong_count(&array), 4);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn root_type_id() {
+    use core::any::TypeId;
+
+    let parc = Parc::new((1u8, String::from("root")));
+    let projected = parc.project(|x| x.1.as_str());
+    assert_eq!(
+        Parc::root_type_id(&projected),
+        Some(TypeId::of::<(u8, String)>())
+    );
+
+    let from_box: Parc<str> = Parc::from(Box::<str>::from("boxed"));
+    assert_eq!(Parc::root_type_id(&from_box), Some(TypeId::of::<str>()));
+    let collected: Parc<[u8]> = (0..4).collect();
+    assert_eq!(Parc::root_type_id(&collected), Some(TypeId::of::<[u8]>()));
+
+    let arc = Arc::new(5u32);
+    assert_eq!(
+        Parc::root_type_id(&Parc::from_arc(&arc, |x| x)),
+        Some(TypeId::of::<u32>())
+    );
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn debug_info() {
+    let root = Parc::new((1u64, Box::new(2u8)));
+    let inline = root.project(|pair| &pair.0);
+    let boxed = root.project(|pair| &*pair.1);
+
+    let info = Parc::debug_info(&inline);
+    assert_eq!(info.root_addr, info.projected_addr);
+    assert_eq!(info.offset, Some(0));
+    assert_eq!(info.counts.strong, 3);
+    assert_eq!(info.root_type_id, Parc::root_type_id(&root));
+    assert!(format!("{info:?}").contains("root_addr"));
+
+    let info = Parc::debug_info(&boxed);
+    assert_eq!(info.offset, None);
+    assert_eq!(info.root_allocation_size, Parc::root_allocation_size(&root));
+    #[cfg(feature = "type-names")]
+    assert_eq!(info.root_type_name, "(u64, alloc::boxed::Box<u8>)");
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn root_allocation_size() {
+    let parc: Parc<[u64]> = (0..16).collect();
+    let first = parc.project(|s| &s[0]);
+    let size = Parc::root_allocation_size(&first);
+    assert_eq!(size, Parc::root_allocation_size(&parc));
+    assert!(size >= 16 * core::mem::size_of::<u64>() + 2 * core::mem::size_of::<usize>());
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn as_any_root() {
+    let arc = Arc::new((1u8, String::from("root")));
+    let parc = Parc::from_arc_any(&arc, |x| x.1.as_str());
+    let root = Parc::as_any_root(&parc).unwrap();
+    assert_eq!(Arc::strong_count(&arc), 3);
+    let root = root.downcast::<(u8, String)>().unwrap();
+    assert!(Arc::ptr_eq(&root, &arc));
+    assert_eq!(
+        Parc::root_type_id(&parc),
+        Some(core::any::TypeId::of::<(u8, String)>())
+    );
+
+    let new = Parc::new(5u32).project(|x| x);
+    assert!(Parc::as_any_root(&new).unwrap().is::<u32>());
+    assert!(Parc::as_any_root(&Parc::from_arc(&arc, |x| x)).is_none());
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn defmt_format() {
+    fn assert_format<T: defmt::Format + ?Sized>() {}
+
+    assert_format::<Parc<u32>>();
+    assert_format::<Parc<str>>();
+    assert_format::<Parc<[u8]>>();
+    #[cfg(feature = "weak")]
+    assert_format::<Weak<str>>();
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn new_zeroizing() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use zeroize::Zeroize;
+
+    struct Secret {
+        bytes: [u8; 4],
+        wiped: Arc<AtomicUsize>,
+    }
+
+    impl Zeroize for Secret {
+        fn zeroize(&mut self) {
+            self.bytes.zeroize();
+            self.wiped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let wiped = Arc::new(AtomicUsize::new(0));
+    let secret = Parc::new_zeroizing(Secret {
+        bytes: [1, 2, 3, 4],
+        wiped: wiped.clone(),
+    });
+    let bytes = secret.project(|secret| &secret.bytes);
+    drop(secret);
+    assert_eq!(wiped.load(Ordering::SeqCst), 0);
+    assert_eq!(*bytes, [1, 2, 3, 4]);
+
+    drop(bytes);
+    assert_eq!(wiped.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn serde() {
+    let parc = Parc::new((String::from("name"), vec![1, 2]));
+    let numbers = parc.project(|pair| &pair.1[..]);
+    assert_eq!(serde_json::to_string(&numbers).unwrap(), "[1,2]");
+
+    let name: Parc<str> = serde_json::from_str("\"name\"").unwrap();
+    assert_eq!(&*name, "name");
+    let numbers: Parc<[u8]> = serde_json::from_str("[1, 2]").unwrap();
+    assert_eq!(&*numbers, &[1, 2]);
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "weak"))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn serde_weak() {
+    let parc = Parc::new((String::from("name"), vec![1, 2]));
+    let weak = Parc::downgrade(&parc.project(|pair| &pair.1[..]));
+    assert_eq!(serde_json::to_string(&weak).unwrap(), "[1,2]");
+    drop(parc);
+    assert_eq!(serde_json::to_string(&weak).unwrap(), "null");
+    assert!(!weak.is_dangling());
+
+    let weak: Weak<u32> = serde_json::from_str("5").unwrap();
+    assert!(weak.is_dangling());
+    assert!(weak.upgrade().is_none());
+    let weak: Weak<u32> = serde_json::from_str("null").unwrap();
+    assert!(weak.is_dangling());
+    assert!(serde_json::from_str::<Weak<u32>>("\"five\"").is_err());
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn downcast_error() {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Custom(u8);
+    impl fmt::Display for Custom {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "custom {}", self.0)
+        }
+    }
+    impl Error for Custom {}
+
+    let errors = Parc::new((Custom(1), Custom(2)));
+    let second: Parc<dyn Error + Send + Sync> = errors.project(|e| &e.1 as _);
+    assert_eq!(second.downcast_ref::<Custom>().unwrap().0, 2);
+    assert!(second.downcast_ref::<fmt::Error>().is_none());
+
+    let second = second.downcast::<fmt::Error>().unwrap_err();
+    let second = second.downcast::<Custom>().unwrap();
+    assert_eq!(second.0, 2);
+    assert_eq!(Parc::strong_count(&errors), 2);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn utf8_conversions() {
+    let bytes: Parc<[u8]> = Parc::from("zero-copy".as_bytes().to_vec());
+    let str = bytes.clone().try_into_str().unwrap();
+    assert_eq!(&*str, "zero-copy");
+    assert_eq!(Parc::strong_count(&bytes), 2);
+
+    let back: Parc<[u8]> = str.into();
+    assert!(Parc::ptr_eq(&back, &bytes));
+
+    let accented: Parc<[u8]> = Parc::from("é".as_bytes());
+    let split = accented.project(|bytes| &bytes[..1]).try_into_str();
+    assert_eq!(split.unwrap_err().valid_up_to(), 0);
+    assert_eq!(Parc::strong_count(&accented), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn ffi_strings() {
+    use std::ffi::{CStr, CString};
+
+    let owned = Parc::new(CString::new("libfoo").unwrap());
+    let c_str: Parc<CStr> = owned.clone().into();
+    assert_eq!(c_str.as_c_ptr(), owned.as_ptr());
+    assert_eq!(Parc::strong_count(&owned), 2);
+
+    let ptr = c_str.as_c_ptr();
+    drop(owned);
+    // SAFETY: `c_str` still keeps the root allocation alive
+    assert_eq!(unsafe { CStr::from_ptr(ptr) }.to_bytes(), b"libfoo");
+
+    let copied: Parc<CStr> = Parc::from(CString::new("copied").unwrap());
+    assert_eq!(&*copied, c"copied");
+
+    #[cfg(feature = "std")]
+    {
+        use std::ffi::{OsStr, OsString};
+
+        let os_string = Parc::new(OsString::from("dir"));
+        let os_str: Parc<OsStr> = os_string.clone().into();
+        assert_eq!(&*os_str, "dir");
+        assert!(std::ptr::eq(&*os_str, os_string.as_os_str()));
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn from_mmap() {
+    use std::io::Write;
+
+    let path = std::env::temp_dir().join(format!("pared-from-mmap-{}", std::process::id()));
+    std::fs::File::create(&path)
+        .unwrap()
+        .write_all(b"magic:payload")
+        .unwrap();
+    let file = std::fs::File::open(&path).unwrap();
+    // SAFETY: nothing modifies the file while it's mapped
+    let bytes = Parc::from_mmap(unsafe { memmap2::Mmap::map(&file).unwrap() });
+    drop(file);
+
+    let payload = bytes.project(|bytes| &bytes[6..]);
+    assert_eq!(Parc::strong_count(&payload), 2);
+    drop(bytes);
+    assert_eq!(&*payload, b"payload");
+    assert_eq!(&*payload.clone().try_into_str().unwrap(), "payload");
+
+    drop(payload);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn bytes_interop() {
+    let parc: Parc<[u8]> = Parc::from(b"header:body".to_vec());
+    let body = bytes::Bytes::from(parc.project(|bytes| &bytes[7..]));
+    assert_eq!(&body[..], b"body");
+    assert_eq!(Parc::strong_count(&parc), 2);
+
+    let sliced = body.slice(1..);
+    drop(body);
+    assert_eq!(Parc::strong_count(&parc), 2);
+    drop(sliced);
+    assert_eq!(Parc::strong_count(&parc), 1);
+
+    let bytes = bytes::Bytes::from(b"shared".to_vec());
+    let back = Parc::from_bytes(bytes.clone());
+    assert_eq!(back.as_ptr(), bytes.as_ptr());
+    assert_eq!(&*back, b"shared");
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn downcast_any() {
+    use std::any::Any;
+
+    let parc = Parc::new((7u32, String::from("seven")));
+    let any: Parc<dyn Any + Send + Sync> = parc.project(|x| &x.0 as _);
+    let any = any.downcast::<String>().unwrap_err();
+    let number = any.downcast::<u32>().unwrap();
+    assert_eq!(*number, 7);
+    assert!(Parc::same_root(&number, &parc));
+    assert_eq!(Parc::strong_count(&parc), 2);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn wait_until_unique() {
+    use std::time::Duration;
+
+    let parc = Parc::new(vec![1, 2, 3]);
+    let view = parc.project(|v| &v[1..]);
+    assert!(!Parc::wait_until_unique(&parc, Duration::from_millis(5)));
+
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(5));
+        drop(view);
+    });
+    assert!(Parc::wait_until_unique(&parc, Duration::MAX));
+    handle.join().unwrap();
+
+    // Static data is never unique, so this mustn't wait forever
+    let parc = Parc::from_static(&[1, 2, 3]);
+    assert!(!Parc::wait_until_unique(&parc, Duration::MAX));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn panicking_projections() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Counted(u8);
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn fail(_: &Counted) -> &u8 {
+        panic!("projection failed")
+    }
+
+    let arc = Arc::new(Counted(1));
+    let parc = Parc::from_arc(&arc, |counted| counted);
+    let panics = [
+        catch_unwind(AssertUnwindSafe(|| drop(parc.project(fail)))),
+        catch_unwind(AssertUnwindSafe(|| {
+            drop(parc.try_project(|counted| Ok::<_, ()>(fail(counted))))
+        })),
+        catch_unwind(AssertUnwindSafe(|| drop(Parc::from_arc(&arc, fail)))),
+        catch_unwind(AssertUnwindSafe(|| {
+            drop(Parc::try_from_arc(&arc, |counted| {
+                Ok::<_, ()>(fail(counted))
+            }))
+        })),
+        catch_unwind(AssertUnwindSafe(|| drop(Parc::from_arc_any(&arc, fail)))),
+    ];
+    assert!(panics.iter().all(Result::is_err));
+
+    // None of the panics leaked or released a strong count
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert_eq!(Parc::counts(&parc), pared::Counts { strong: 2, weak: 0 });
+    assert_eq!(parc.0, 1);
+
+    drop(arc);
+    drop(parc);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn slice_element_projections() {
+    let sorted: Parc<[u32]> = Parc::from(vec![10, 20, 30]);
+    let twenty = sorted.get_project(1).unwrap();
+    assert_eq!(Parc::addr(&twenty), Parc::addr(&sorted) + 4);
+    assert!(sorted.get_project(3).is_none());
+    assert!(Parc::ptr_eq(
+        &sorted.binary_search_project(&20).unwrap(),
+        &twenty
+    ));
+    assert_eq!(sorted.binary_search_project(&35).err(), Some(3));
+    assert_eq!(Parc::strong_count(&sorted), 2);
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn from_static() {
+    static PAIR: (u8, &str) = (1, "static");
+    const NAME: Parc<str> = pared::parc_str!("name");
+
+    let parc = Parc::from_static(&PAIR);
+    let name = parc.project(|pair| pair.1);
+    assert_eq!(Parc::strong_count(&parc), usize::MAX);
+    assert_eq!(Parc::weak_count(&parc), 0);
+    assert!(!Parc::is_unique(&parc));
+    assert_eq!(Parc::root_type_id(&parc), None);
+
+    let weak = Parc::downgrade(&name);
+    drop(parc);
+    drop(name);
+    assert_eq!(&*weak.upgrade().unwrap(), "static");
+
+    let names = [NAME.clone(), NAME.clone()];
+    assert!(Parc::ptr_eq(&names[0], &NAME));
+    assert_eq!(&*names[1], "name");
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn map_root() {
+    let parc = Parc::new((1u8, String::from("value")));
+    let weak = Parc::downgrade(&parc);
+    let value = parc.project(|pair| &pair.1);
+    drop(parc);
+
+    // SAFETY: the new root owns `value`
+    let value = unsafe { Parc::map_root(value, |value| Arc::new((value, 2u32))) };
+    assert_eq!(
+        Parc::root_type_id(&value),
+        Some(std::any::TypeId::of::<(Parc<String>, u32)>())
+    );
+    assert_eq!(Parc::strong_count(&value), 1);
+    assert_eq!(weak.strong_count(), 1);
+
+    let clone = value.project(|value| value.as_str());
+    drop(value);
+    assert_eq!(&*clone, "value");
+    drop(clone);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn try_project_mut_races_upgrade() {
+    use std::sync::Barrier;
+
+    // The other thread upgrades its weak pointer before dropping it, so there's always
+    // a second `Parc` alive once the weak count drops to zero
+    let barrier = Barrier::new(2);
+    for _ in 0..1000 {
+        let mut parc = Parc::new(0u64);
+        let weak = Parc::downgrade(&parc);
+        let upgraded = std::thread::scope(|s| {
+            let upgrader = s.spawn(|| {
+                barrier.wait();
+                let upgraded = weak.upgrade();
+                drop(weak);
+                upgraded
+            });
+            barrier.wait();
+            for _ in 0..16 {
+                parc = Parc::try_project_mut(parc, |x| x).unwrap_err();
+            }
+            upgrader.join().unwrap()
+        });
+        assert!(upgraded.is_some());
+    }
+}
@@ -1,14 +1,21 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
-use pared::prc::{Prc, Weak};
+#[cfg(feature = "weak")]
+use pared::prc::Weak;
+use pared::prc::{Prc, SendPrc};
+#[cfg(feature = "weak")]
+use pared::Counts;
+#[cfg(feature = "weak")]
 use std::any::Any;
 use std::cell::RefCell;
 use std::cmp::PartialEq;
 use std::collections::HashMap;
 use std::error::Error;
+use std::ops::Bound;
 use std::rc::Rc;
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn slice() {
     let a: Prc<[u32; 3]> = Rc::new([3, 2, 1]).into();
@@ -22,6 +29,7 @@ fn slice() {
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn trait_object() {
     let a: Prc<u32> = Prc::new(4);
@@ -62,9 +70,11 @@ fn partial_eq() {
     assert_eq!(*x.0.borrow(), 4);
 }
 
+#[cfg(feature = "weak")]
 const SHARED_ITER_MAX: u16 = 100;
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn shared_from_iter_normal() {
     #![allow(clippy::redundant_clone)]
@@ -157,6 +167,29 @@ fn fallible_projections() {
 
 #[test]
 #[cfg_attr(coverage_nightly, coverage(off))]
+fn capturing_projections() {
+    let prc = Prc::new(vec![
+        String::from("a"),
+        String::from("bc"),
+        String::from("def"),
+    ]);
+    let index = prc.iter().position(|s| s.len() == 2).unwrap();
+    let projected = prc.project(|v| &v[index]);
+    assert_eq!(&*projected, "bc");
+
+    let name = String::from("def");
+    let found = prc.try_project(|v| v.iter().find(|s| **s == name).ok_or(()));
+    assert!(matches!(found, Ok(p) if *p == name));
+
+    let len = 3;
+    let rc = Rc::new(vec![1u8, 2, 3, 4]);
+    let prefix = Prc::try_from_rc(&rc, |v| v.get(..len).ok_or(()));
+    assert!(matches!(prefix, Ok(p) if *p == [1, 2, 3]));
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
 fn as_ptr() {
     #[repr(C)]
     struct Test {
@@ -167,11 +200,12 @@ fn as_ptr() {
     let prc = Prc::from_rc(&rc, |x| &x.a);
     let weak = Prc::downgrade(&prc);
 
-    assert!(Prc::as_ptr(&prc) == &rc.a as *const i32);
-    assert!(Weak::as_ptr(&weak) == &rc.a as *const i32);
+    assert!(core::ptr::eq(Prc::as_ptr(&prc), &rc.a));
+    assert!(core::ptr::eq(Weak::as_ptr(&weak), &rc.a));
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn counts() {
     let prc = Prc::new(5);
@@ -180,13 +214,26 @@ fn counts() {
     assert_eq!(Prc::weak_count(&prc), 0);
     assert_eq!(Prc::strong_count(&prc), 2);
     assert_eq!(Prc::strong_count(&prc2), 2);
+    assert!(!Prc::is_unique(&prc));
 
     let weak = Prc::downgrade(&prc);
     assert_eq!(Weak::weak_count(&weak), 1);
     assert_eq!(Weak::strong_count(&weak), 2);
+
+    assert_eq!(Prc::counts(&prc), Counts { strong: 2, weak: 1 });
+    assert_eq!(weak.counts(), Counts { strong: 2, weak: 1 });
+    drop(prc);
+    assert!(!Prc::is_unique(&prc2));
+    drop(weak);
+    assert!(Prc::is_unique(&prc2));
+
+    let weak = Prc::downgrade(&prc2);
+    drop(prc2);
+    assert_eq!(weak.counts(), Counts { strong: 0, weak: 0 });
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn ptr_eq() {
     let prc = Prc::new(5);
@@ -204,6 +251,28 @@ fn ptr_eq() {
     assert!(!Weak::ptr_eq(&weak, &weak2));
 }
 
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn weak_same_root() {
+    let tuple = Prc::new((5, String::from("five")));
+    let weak = Prc::downgrade(&tuple.project(|x| &x.0));
+    let weak_str = Prc::downgrade(&tuple.project(|x| x.1.as_str()));
+    let other = Prc::new(5);
+    let weak_other = Prc::downgrade(&other);
+
+    assert!(weak.same_root(&weak_str));
+    assert!(!weak.same_root(&weak_other));
+    assert!(weak_str.same_root_as(&tuple));
+    assert!(!weak_str.same_root_as(&other));
+
+    // Identity is preserved after the allocation is gone
+    drop(tuple);
+    assert!(weak.upgrade().is_none());
+    assert!(weak.same_root(&weak_str));
+    assert!(!weak.same_root(&weak_other));
+}
+
 #[test]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn borrows() {
@@ -214,15 +283,16 @@ fn borrows() {
 }
 
 #[test]
+#[cfg(feature = "weak")]
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn fmt() {
     let prc = Prc::new(5);
 
-    format!("{} {:?} {:p}", prc, prc, prc);
+    let _ = format!("{} {:?} {:p}", prc, prc, prc);
 
     let weak = Prc::downgrade(&prc);
 
-    format!("{:?}", weak);
+    let _ = format!("{:?}", weak);
 }
 
 #[test]
@@ -253,3 +323,719 @@ fn cmp() {
     assert_eq!(five.cmp(&six), std::cmp::Ordering::Less);
     assert_eq!(five.partial_cmp(&six), Some(std::cmp::Ordering::Less));
 }
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn transpose_option() {
+    let rc = Rc::new((Some(String::from("some")), None::<String>));
+    let some = Prc::from_rc(&rc, |x| &x.0);
+    let none = Prc::from_rc(&rc, |x| &x.1);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    assert!(none.transpose().is_none());
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    let inner = some.transpose().unwrap();
+    assert_eq!(&*inner, "some");
+    assert_eq!(Rc::strong_count(&rc), 2);
+    assert!(core::ptr::eq(&*inner, rc.0.as_ref().unwrap()));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn transpose_result() {
+    type Shared = Result<String, u32>;
+    let rc: Rc<(Shared, Shared)> = Rc::new((Ok(String::from("ok")), Err(5)));
+    let ok = Prc::from_rc(&rc, |x| &x.0).transpose().unwrap();
+    let err = Prc::from_rc(&rc, |x| &x.1).transpose().unwrap_err();
+    assert_eq!(&*ok, "ok");
+    assert_eq!(*err, 5);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let shared = Prc::from_rc(&rc, |x| &x.1);
+    assert_eq!(*shared.split_result().unwrap_err(), 5);
+    assert_eq!(Prc::strong_count(&shared), 4);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn slice_and_str_conversions() {
+    let vec = Prc::new(vec![1, 2, 3]);
+    let slice = vec.as_slice_prc();
+    assert_eq!(&*slice, &[1, 2, 3]);
+    assert_eq!(Prc::strong_count(&vec), 2);
+    let converted: Prc<[i32]> = vec.into();
+    assert!(Prc::ptr_eq(&slice, &converted));
+    assert_eq!(Prc::strong_count(&slice), 2);
+
+    let string = Prc::new(String::from("pared"));
+    let str = string.as_str_prc();
+    let converted = Prc::<str>::from(string);
+    assert_eq!(&*str, "pared");
+    assert!(Prc::ptr_eq(&str, &converted));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn root_type_id() {
+    use core::any::TypeId;
+
+    let prc = Prc::new((1u8, String::from("root")));
+    let projected = prc.project(|x| x.1.as_str());
+    assert_eq!(
+        Prc::root_type_id(&projected),
+        Some(TypeId::of::<(u8, String)>())
+    );
+
+    let collected: Prc<[u8]> = (0..4).collect();
+    assert_eq!(Prc::root_type_id(&collected), Some(TypeId::of::<[u8]>()));
+
+    let rc = Rc::new(5u32);
+    assert_eq!(
+        Prc::root_type_id(&Prc::from_rc(&rc, |x| x)),
+        Some(TypeId::of::<u32>())
+    );
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn debug_info() {
+    let root = Prc::new((1u64, Box::new(2u8)));
+    let inline = root.project(|pair| &pair.0);
+    let boxed = root.project(|pair| &*pair.1);
+
+    let info = Prc::debug_info(&inline);
+    assert_eq!(info.root_addr, info.projected_addr);
+    assert_eq!(info.offset, Some(0));
+    assert_eq!(info.counts.strong, 3);
+    assert_eq!(info.root_type_id, Prc::root_type_id(&root));
+    assert!(format!("{info:?}").contains("root_addr"));
+
+    let info = Prc::debug_info(&boxed);
+    assert_eq!(info.offset, None);
+    assert_eq!(info.root_allocation_size, Prc::root_allocation_size(&root));
+    #[cfg(feature = "type-names")]
+    assert_eq!(info.root_type_name, "(u64, alloc::boxed::Box<u8>)");
+
+    let fixed = Prc::from_static(&7u8);
+    let info = Prc::debug_info(&fixed);
+    assert_eq!(info.offset, Some(0));
+    assert_eq!(info.root_type_id, None);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn root_allocation_size() {
+    let prc = Prc::new((0u8, [0u32; 8]));
+    let array = prc.project(|x| &x.1);
+    let size = Prc::root_allocation_size(&array);
+    assert_eq!(size, Prc::root_allocation_size(&prc));
+    assert!(size >= core::mem::size_of::<(u8, [u32; 8])>() + 2 * core::mem::size_of::<usize>());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn new_cyclic() {
+    struct Node {
+        value: u32,
+        parent: Option<Prc<Node>>,
+        this: pared::prc::Weak<Node>,
+    }
+
+    let root = Prc::new_cyclic(|this| Node {
+        value: 1,
+        parent: None,
+        this: this.clone(),
+    });
+    let child = Prc::new_cyclic(|this| {
+        assert!(this.upgrade().is_none());
+        Node {
+            value: 2,
+            parent: Some(root.this.upgrade().unwrap()),
+            this: this.clone(),
+        }
+    });
+    assert_eq!(child.parent.as_ref().unwrap().value, 1);
+    assert_eq!(Prc::weak_count(&child), 1);
+
+    let value = child.this.upgrade().unwrap().project(|node| &node.value);
+    drop(child);
+    assert_eq!(*value, 2);
+    assert_eq!(Prc::strong_count(&root), 2);
+    drop(value);
+    assert_eq!(Prc::strong_count(&root), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn pinned() {
+    use core::{marker::PhantomPinned, pin::Pin};
+
+    struct Pinned {
+        value: u32,
+        _pinned: PhantomPinned,
+    }
+
+    let pinned = Prc::pin((
+        String::from("outer"),
+        Pinned {
+            value: 5,
+            _pinned: PhantomPinned,
+        },
+    ));
+    let address = &pinned.1 as *const Pinned;
+    // SAFETY: tuples never move their fields
+    let field: Pin<Prc<Pinned>> = unsafe { Prc::project_pinned(pinned.clone(), |x| &x.1) };
+    drop(pinned);
+    assert_eq!(field.value, 5);
+    assert!(core::ptr::eq(&*field, address));
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn from_owned_containers() {
+    let from_vec: Prc<[String]> = Prc::from(vec![String::from("a"), String::from("b")]);
+    assert_eq!(from_vec.len(), 2);
+    let from_string: Prc<str> = String::from("string").into();
+    assert_eq!(&*from_string, "string");
+    let from_boxed_str: Prc<str> = Box::<str>::from("boxed").into();
+    assert_eq!(&*from_boxed_str, "boxed");
+    let from_box: Prc<(u8, u16)> = Box::new((1, 2)).into();
+    assert_eq!(*from_box.project(|x| &x.1), 2);
+    let from_boxed_slice: Prc<[u8]> = Box::<[u8]>::from([1, 2]).into();
+    assert_eq!(&*from_boxed_slice, &[1, 2]);
+    assert_eq!(
+        Prc::root_type_id(&from_vec),
+        Some(core::any::TypeId::of::<[String]>())
+    );
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn slice_and_split_at() {
+    let slice: Prc<[u32]> = (0..6).collect();
+    let middle = slice.slice(2..=3).unwrap();
+    assert_eq!(&*middle, &[2, 3]);
+    let error = slice.slice(..7).unwrap_err();
+    assert_eq!((error.len, error.range.clone()), (6, 0..7));
+    assert!(error.is_out_of_bounds());
+    assert_eq!(
+        slice
+            .slice((Bound::Excluded(4), Bound::Included(2)))
+            .unwrap_err()
+            .to_string(),
+        "range 5..3 is out of bounds for length 6"
+    );
+    let (head, tail) = middle.split_at(2).unwrap();
+    assert_eq!((head.len(), tail.len()), (2, 0));
+    assert!(middle.split_at(3).is_err());
+    assert_eq!(Prc::strong_count(&slice), 4);
+
+    let str: Prc<str> = Prc::from("a→b");
+    let error = str.split_at(2).unwrap_err();
+    assert!(!error.is_out_of_bounds());
+    assert_eq!(
+        error.to_string(),
+        "range 0..2 doesn't lie on char boundaries"
+    );
+    let (a, rest) = str.split_at(1).unwrap();
+    assert_eq!((&*a, &*rest), ("a", "→b"));
+    assert_eq!(&*rest.slice(3..).unwrap(), "b");
+    assert_eq!(rest.slice(1..).unwrap_err().range, 1..4);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn iter_projected() {
+    let vec = Prc::new(vec![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+    ]);
+    let mut iter = vec.iter_projected();
+    assert_eq!(iter.len(), 3);
+    let c = iter.next_back().unwrap();
+    let a = iter.next().unwrap();
+    assert_eq!(format!("{:?}", iter), "IterProjected([\"b\"])");
+    let rest: Vec<_> = iter.clone().collect();
+    assert_eq!(rest.len(), 1);
+    assert!(iter.nth(1).is_none());
+    assert!(iter.next_back().is_none());
+    drop(iter);
+    drop(vec);
+
+    assert_eq!((a.as_str(), rest[0].as_str(), c.as_str()), ("a", "b", "c"));
+    assert_eq!(Prc::strong_count(&a), 3);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn downcast() {
+    use core::any::Any;
+
+    let registry: Vec<Prc<dyn Any>> = vec![
+        Prc::new(5u32).project(|x| x as &dyn Any),
+        Prc::new(String::from("five")).project(|x| x as &dyn Any),
+    ];
+    let mut numbers = 0;
+    let mut strings = Vec::new();
+    for entry in registry {
+        match entry.downcast::<u32>() {
+            Ok(number) => numbers += *number,
+            Err(entry) => strings.push(entry.downcast::<String>().unwrap()),
+        }
+    }
+    assert_eq!(numbers, 5);
+    assert_eq!(&*strings[0], "five");
+    assert_eq!(Prc::strong_count(&strings[0]), 1);
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn raw() {
+    let rc = Rc::new((5, String::from("five")));
+    let prc = Prc::from_rc(&rc, |x| x.1.as_str());
+    let raw = Prc::into_raw(prc);
+    assert_eq!(Rc::strong_count(&rc), 2);
+    assert_eq!(unsafe { &*raw.as_ptr() }, "five");
+    let _ = format!("{:?}", raw);
+
+    unsafe {
+        Prc::increment_strong_count(raw);
+        Prc::increment_strong_count(raw);
+    }
+    assert_eq!(Rc::strong_count(&rc), 4);
+    unsafe { Prc::decrement_strong_count(raw) };
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    let first = unsafe { Prc::from_raw(raw) };
+    let second = unsafe { Prc::from_raw(raw) };
+    assert!(Prc::ptr_eq(&first, &second));
+
+    let raw_weak = Prc::downgrade(&first).into_raw();
+    let _ = format!("{:?}", raw_weak);
+    assert_eq!(Rc::weak_count(&rc), 1);
+    assert_eq!(unsafe { &*raw_weak.as_ptr() }, "five");
+    drop(first);
+    drop(second);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let weak = unsafe { pared::prc::Weak::from_raw(raw_weak) };
+    assert_eq!(&*weak.upgrade().unwrap(), "five");
+    drop(rc);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn get_mut_and_make_mut() {
+    let mut prc = Prc::new(String::from("a"));
+    Prc::get_mut(&mut prc).unwrap().push('b');
+    assert_eq!(&*prc, "ab");
+
+    let weak = Prc::downgrade(&prc);
+    assert!(Prc::get_mut(&mut prc).is_none());
+    drop(weak);
+
+    let shared = prc.clone();
+    Prc::make_mut(&mut prc).push('c');
+    assert_eq!(&*prc, "abc");
+    assert_eq!(&*shared, "ab");
+    assert_eq!(Prc::strong_count(&shared), 1);
+
+    let mut second = Prc::new((1u8, 2u8)).project(|pair| &pair.1);
+    *Prc::make_mut(&mut second) = 5;
+    assert_eq!(*second, 5);
+
+    // A newtype only lends its private field out as shared, even though it starts at the root
+    struct Sorted(Vec<u32>);
+    impl Sorted {
+        fn as_vec(&self) -> &Vec<u32> {
+            &self.0
+        }
+    }
+    let sorted = Prc::new(Sorted(vec![1, 3]));
+    let mut vec = sorted.project(Sorted::as_vec);
+    drop(sorted);
+    assert!(Prc::is_unique(&vec));
+    assert!(Prc::get_mut(&mut vec).is_none());
+    Prc::make_mut(&mut vec).push(2);
+    assert_eq!(*vec, [1, 3, 2]);
+
+    let rc = Rc::new(Sorted(vec![4]));
+    let mut from_rc = Prc::from_rc(&rc, Sorted::as_vec);
+    drop(rc);
+    assert!(Prc::get_mut(&mut from_rc).is_none());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn from_static() {
+    static PAIR: (u8, &str) = (1, "static");
+    let prc = Prc::from_static(&PAIR);
+    let name = prc.project(|pair| pair.1);
+    assert_eq!(Prc::strong_count(&prc), usize::MAX);
+    assert_eq!(Prc::weak_count(&prc), 0);
+    assert!(!Prc::is_unique(&prc));
+    assert_eq!(Prc::root_type_id(&prc), None);
+
+    let weak = Prc::downgrade(&name);
+    drop(prc);
+    drop(name);
+    assert_eq!(&*weak.upgrade().unwrap(), "static");
+
+    let mut copy = Prc::from_static(&5);
+    assert!(Prc::get_mut(&mut copy).is_none());
+    *Prc::make_mut(&mut copy) += 1;
+    assert_eq!(*copy, 6);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn new_uninit_and_zeroed() {
+    use core::mem::MaybeUninit;
+
+    let mut value = Prc::<Vec<u8>>::new_uninit();
+    Prc::get_mut(&mut value).unwrap().write(vec![1, 2]);
+    let value = unsafe { value.assume_init() };
+    assert_eq!(&*value, &[1, 2]);
+    assert_eq!(
+        Prc::root_type_id(&value),
+        Some(core::any::TypeId::of::<Vec<u8>>())
+    );
+
+    let zeroed = unsafe { Prc::<[u64; 64]>::new_zeroed().assume_init() };
+    assert_eq!(*zeroed, [0; 64]);
+
+    // Projected pointers are converted without touching the root
+    let pair = Prc::new((1u8, MaybeUninit::new(2u8)));
+    let second = unsafe { pair.project(|pair| &pair.1).assume_init() };
+    assert_eq!(*second, 2);
+    assert_eq!(
+        Prc::root_type_id(&second),
+        Some(core::any::TypeId::of::<(u8, MaybeUninit<u8>)>())
+    );
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn try_unwrap_and_leak() {
+    let prc = Prc::new(String::from("unique"));
+    let weak = Prc::downgrade(&prc);
+    assert_eq!(Prc::try_unwrap(prc).unwrap(), "unique");
+    assert!(weak.upgrade().is_none());
+
+    // A borrowed Rc can be unwrapped once the original is gone
+    let rc = Rc::new(5);
+    let prc = Prc::from_rc(&rc, |x| x);
+    assert_eq!(*Prc::try_unwrap(prc).unwrap_err(), 5);
+    let prc = Prc::from_rc(&rc, |x| x);
+    drop(rc);
+    assert_eq!(Prc::try_unwrap(prc).unwrap(), 5);
+
+    // The root type of static data isn't known
+    assert_eq!(*Prc::try_unwrap(Prc::from_static(&6)).unwrap_err(), 6);
+
+    let first = Prc::new(1);
+    let second = first.clone();
+    assert_eq!(Prc::into_inner(first), None);
+    assert_eq!(Prc::into_inner(second), Some(1));
+
+    let prc = Prc::new([1u8, 2, 3]);
+    let weak = Prc::downgrade(&prc);
+    let leaked: &'static u8 = Prc::leak(prc.project(|array| &array[2]));
+    drop(prc);
+    assert_eq!(*leaked, 3);
+    assert_eq!(weak.strong_count(), 1);
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn weak_project() {
+    let prc = Prc::new((String::from("label"), 5u8));
+    let weak = Prc::downgrade(&prc);
+
+    let weak_label = weak.project(|pair| pair.0.as_str()).unwrap();
+    assert!(weak_label.same_root(&weak));
+    assert_eq!(Prc::strong_count(&prc), 1);
+    assert_eq!(Prc::weak_count(&prc), 2);
+
+    let number = weak_label.upgrade_project(|_| &7u8);
+    assert_eq!(number.as_deref(), Some(&7));
+    drop(number);
+
+    let label = weak.upgrade_project(|pair| pair.0.as_str()).unwrap();
+    assert_eq!(&*label, "label");
+    assert_eq!(Prc::strong_count(&prc), 2);
+
+    drop(prc);
+    drop(label);
+    assert!(weak_label.upgrade().is_none());
+    assert!(weak.project(|pair| &pair.1).is_none());
+    assert!(weak.upgrade_project(|pair| &pair.1).is_none());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn dangling_weak() {
+    let weak: pared::prc::Weak<String> = Default::default();
+    assert!(weak.is_dangling());
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak.strong_count(), 0);
+    assert_eq!(weak.weak_count(), 0);
+    assert!(weak.same_root(&weak.clone()));
+    assert!(weak.project(|s| s.as_str()).is_none());
+    let _ = format!("{:?}", weak);
+
+    let raw = weak.into_raw();
+    let weak = unsafe { pared::prc::Weak::from_raw(raw) };
+    assert!(weak.is_dangling());
+
+    let prc = Prc::new(String::new());
+    assert!(!weak.same_root_as(&prc));
+    assert!(!Prc::downgrade(&prc).is_dangling());
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn copy_out() {
+    let root = Prc::new((3u16, String::from("three")));
+    let id = root.project(|root| &root.0);
+    let name = root.project(|root| &root.1);
+
+    assert_eq!(Prc::get(&id), 3);
+    assert_eq!([id.clone(), id].iter().map(Prc::copied).sum::<u16>(), 6);
+    let owned: String = Prc::cloned(&name);
+    assert_eq!(owned, "three");
+    assert_eq!(Prc::strong_count(&root), 2);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn project_get() {
+    use std::collections::BTreeMap;
+
+    #[cfg(feature = "std")]
+    {
+        let hash_map = Prc::new(HashMap::from([(String::from("a"), vec![1])]));
+        let a = hash_map.project_get("a").unwrap();
+        assert_eq!(&*a, &[1]);
+        assert!(hash_map.project_get("b").is_none());
+        assert_eq!(Prc::strong_count(&hash_map), 2);
+    }
+
+    let btree_map = Prc::new(BTreeMap::from([(String::from("a"), vec![1])]));
+    let a = btree_map.project_get("a").unwrap();
+    assert!(core::ptr::eq(&*a, &btree_map["a"]));
+    assert!(btree_map.project_get("b").is_none());
+    assert_eq!(Prc::strong_count(&btree_map), 2);
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn defmt_format() {
+    fn assert_format<T: defmt::Format + ?Sized>() {}
+
+    assert_format::<Prc<u32>>();
+    assert_format::<Prc<str>>();
+    assert_format::<Prc<[u8]>>();
+    #[cfg(feature = "weak")]
+    assert_format::<Weak<str>>();
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn new_zeroizing() {
+    use std::cell::Cell;
+    use zeroize::Zeroize;
+
+    struct Secret {
+        bytes: [u8; 4],
+        wiped: Rc<Cell<usize>>,
+    }
+
+    impl Zeroize for Secret {
+        fn zeroize(&mut self) {
+            self.bytes.zeroize();
+            self.wiped.set(self.wiped.get() + 1);
+        }
+    }
+
+    let wiped = Rc::new(Cell::new(0));
+    let secret = Prc::new_zeroizing(Secret {
+        bytes: [1, 2, 3, 4],
+        wiped: wiped.clone(),
+    });
+    let bytes = secret.project(|secret| &secret.bytes);
+    drop(secret);
+    assert_eq!(wiped.get(), 0);
+    assert_eq!(*bytes, [1, 2, 3, 4]);
+
+    drop(bytes);
+    assert_eq!(wiped.get(), 1);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn serde() {
+    let prc = Prc::new((String::from("name"), vec![1, 2]));
+    let numbers = prc.project(|pair| &pair.1[..]);
+    assert_eq!(serde_json::to_string(&numbers).unwrap(), "[1,2]");
+
+    let weak = Prc::downgrade(&numbers);
+    assert_eq!(serde_json::to_string(&weak).unwrap(), "[1,2]");
+    drop(prc);
+    drop(numbers);
+    assert_eq!(serde_json::to_string(&weak).unwrap(), "null");
+
+    let name: Prc<str> = serde_json::from_str("\"name\"").unwrap();
+    assert_eq!(&*name, "name");
+    assert_eq!(Prc::strong_count(&name), 1);
+
+    let weak: Weak<u32> = serde_json::from_str("5").unwrap();
+    assert!(weak.is_dangling());
+    assert!(serde_json::from_str::<Weak<u32>>("\"five\"").is_err());
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn variance_and_niche() {
+    fn shorten_prc<'a>(prc: Prc<&'static str>) -> Prc<&'a str> {
+        prc
+    }
+    fn shorten_weak<'a>(weak: Weak<&'static str>) -> Weak<&'a str> {
+        weak
+    }
+
+    let prc = shorten_prc(Prc::new("covariant"));
+    let weak = shorten_weak(Prc::downgrade(&Prc::new("covariant")));
+    assert_eq!(*prc, "covariant");
+    assert!(weak.upgrade().is_none());
+
+    // Checked at compile time
+    const _: () = {
+        use core::mem::size_of;
+        assert!(size_of::<Option<Prc<u8>>>() == size_of::<Prc<u8>>());
+        assert!(size_of::<Option<Prc<str>>>() == size_of::<Prc<str>>());
+        assert!(size_of::<Option<Weak<u8>>>() == size_of::<Weak<u8>>());
+        assert!(size_of::<Option<Weak<[u8]>>>() == size_of::<Weak<[u8]>>());
+    };
+}
+
+#[test]
+#[cfg(feature = "weak")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn panicking_projections() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Counted(u8);
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn fail(_: &Counted) -> &u8 {
+        panic!("projection failed")
+    }
+
+    let rc = Rc::new(Counted(1));
+    let prc = Prc::from_rc(&rc, |counted| counted);
+    let weak = Prc::downgrade(&prc);
+    let panics = [
+        catch_unwind(AssertUnwindSafe(|| drop(prc.project(fail)))),
+        catch_unwind(AssertUnwindSafe(|| {
+            drop(prc.try_project(|counted| Ok::<_, ()>(fail(counted))))
+        })),
+        catch_unwind(AssertUnwindSafe(|| drop(Prc::from_rc(&rc, fail)))),
+        catch_unwind(AssertUnwindSafe(|| drop(weak.upgrade_project(fail)))),
+        catch_unwind(AssertUnwindSafe(|| drop(weak.project(fail)))),
+    ];
+    assert!(panics.iter().all(Result::is_err));
+
+    // None of the panics leaked or released a count
+    assert_eq!(Rc::strong_count(&rc), 2);
+    assert_eq!(Prc::counts(&prc), Counts { strong: 2, weak: 1 });
+    assert_eq!(prc.0, 1);
+
+    drop((rc, prc, weak));
+    assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn text_projections() {
+    for text in [
+        "",
+        "\n",
+        "a",
+        "a\n",
+        "a\r\nb",
+        "\r\n\n",
+        "a\rb\r",
+        "ä\nö\r\n",
+    ] {
+        let prc: Prc<str> = Prc::from(text);
+        let lines: Vec<Prc<str>> = prc.lines_projected().collect();
+        let lines: Vec<&str> = lines.iter().map(|line| &**line).collect();
+        assert_eq!(lines, text.lines().collect::<Vec<_>>(), "{text:?}");
+        for delimiter in [',', 'ö', '\n'] {
+            let parts: Vec<Prc<str>> = prc.split_projected(delimiter).collect();
+            let parts: Vec<&str> = parts.iter().map(|part| &**part).collect();
+            assert_eq!(parts, text.split(delimiter).collect::<Vec<_>>(), "{text:?}");
+        }
+    }
+
+    let bytes: Prc<[u8]> = Prc::from(Prc::<str>::from("key=value"));
+    let value = bytes.slice(4..).unwrap().try_into_str().unwrap();
+    assert_eq!(&*value, "value");
+    assert_eq!(Prc::strong_count(&value), 2);
+    let mut split = value.split_projected('a');
+    split.next();
+    assert_eq!(format!("{:?}", split), "SplitProjected(\"lue\")");
+    assert!(Prc::<[u8]>::from(vec![0xc3]).try_into_str().is_err());
+}
+
+#[test]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn send_prc() {
+    let pair = Prc::new((String::from("moved"), 5));
+    let name = pair.project(|pair| &pair.0);
+    // SAFETY: the root is a `(String, i32)`
+    let name = unsafe { SendPrc::try_new(name) }.unwrap_err();
+    drop(pair);
+
+    let name = unsafe { SendPrc::new(name) };
+    assert_eq!(format!("{:?}", name), "SendPrc(\"moved\")");
+    let len = std::thread::spawn(move || {
+        let name = name.into_inner();
+        assert!(Prc::is_unique(&name));
+        name.len()
+    });
+    assert_eq!(len.join().unwrap(), 5);
+}
+
+#[test]
+#[should_panic = "SendPrc::new called with a Prc that isn't unique"]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn send_prc_rejects_shared() {
+    let value = Prc::new(1);
+    let _clone = value.clone();
+    // SAFETY: the root is an `i32`
+    let _ = unsafe { SendPrc::new(value) };
+}